@@ -0,0 +1,705 @@
+// src/character/advancement.rs
+// Starting characters above tier 1
+
+use crate::data::{Ability, CharacterType};
+use crate::error::{ChargenError, Result};
+
+use super::sheet::{CharacterSheet, SelectedAbility};
+use super::stats::{Effort, Pools};
+
+/// Highest tier a character can reach
+pub const MAX_TIER: u32 = 6;
+
+/// Pool points gained with each tier after the first
+pub const TIER_POOL_POINTS: i32 = 4;
+
+/// Advances a character takes before moving up a tier
+pub const ADVANCES_PER_TIER: usize = 4;
+
+/// Pool points added by the Increase Capabilities advance
+pub const CAPABILITY_POINTS: i32 = 4;
+
+// ==========================================
+// CHARACTER ADVANCES
+// ==========================================
+
+/// One of the advances a character buys with XP, each taken once per tier
+#[derive(Debug, Clone, PartialEq)]
+pub enum Advance {
+    /// `CAPABILITY_POINTS` pool points, split among the pools
+    IncreaseCapabilities(Pools),
+    /// +1 Edge in the named stat
+    MoveTowardPerfection(String),
+    /// +1 Effort
+    ExtraEffort,
+    /// Training in a skill, or specialization if already trained
+    SkillTraining(String),
+}
+
+impl Advance {
+    /// Whether both are the same kind of advance, whatever their details
+    fn same_kind(&self, other: &Advance) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl std::fmt::Display for Advance {
+    /// The form `FromStr` reads back, e.g. `Increase Capabilities: Might 2, Speed 2`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Advance::IncreaseCapabilities(points) => {
+                let parts: Vec<String> = [
+                    ("Might", points.might),
+                    ("Speed", points.speed),
+                    ("Intellect", points.intellect),
+                ]
+                .iter()
+                .filter(|(_, value)| *value > 0)
+                .map(|(name, value)| format!("{} {}", name, value))
+                .collect();
+                write!(f, "Increase Capabilities: {}", parts.join(", "))
+            }
+            Advance::MoveTowardPerfection(stat) => write!(f, "Move Toward Perfection: {}", stat),
+            Advance::ExtraEffort => write!(f, "Extra Effort"),
+            Advance::SkillTraining(skill) => write!(f, "Skill Training: {}", skill),
+        }
+    }
+}
+
+impl std::str::FromStr for Advance {
+    type Err = ChargenError;
+
+    /// Parse `Increase Capabilities: Might` (all points to one pool) or
+    /// `Increase Capabilities: Might 2, Speed 2`, `Move Toward Perfection: Speed`,
+    /// `Extra Effort` and `Skill Training: Climbing`, ignoring case
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = |reason: &str| ChargenError::InvalidAdvance {
+            advance: s.to_string(),
+            reason: reason.to_string(),
+        };
+        let (kind, detail) = match s.split_once(':') {
+            Some((kind, detail)) => (kind.trim(), detail.trim()),
+            None => (s.trim(), ""),
+        };
+
+        match kind.to_lowercase().as_str() {
+            "increase capabilities" => {
+                let parts: Vec<&str> = detail.split(',').map(str::trim).collect();
+                let mut points = Pools::zero();
+                for part in &parts {
+                    let (pool, value) = match part.rsplit_once(' ') {
+                        Some((pool, value)) => (pool, value.parse().ok()),
+                        None if parts.len() == 1 => (*part, Some(CAPABILITY_POINTS)),
+                        None => (*part, None),
+                    };
+                    let value = value.ok_or_else(|| invalid("give points per pool"))?;
+                    if !points.set_pool(pool, value) {
+                        return Err(invalid("name a pool: Might, Speed or Intellect"));
+                    }
+                }
+                if !points.is_valid() || points.total() != CAPABILITY_POINTS {
+                    return Err(invalid(&format!(
+                        "add exactly {} pool points",
+                        CAPABILITY_POINTS
+                    )));
+                }
+                Ok(Advance::IncreaseCapabilities(points))
+            }
+            "move toward perfection" => match detail.to_lowercase().as_str() {
+                "might" => Ok(Advance::MoveTowardPerfection("Might".to_string())),
+                "speed" => Ok(Advance::MoveTowardPerfection("Speed".to_string())),
+                "intellect" => Ok(Advance::MoveTowardPerfection("Intellect".to_string())),
+                _ => Err(invalid("name the Edge stat: Might, Speed or Intellect")),
+            },
+            "extra effort" if detail.is_empty() => Ok(Advance::ExtraEffort),
+            "extra effort" => Err(invalid("Extra Effort takes no details")),
+            "skill training" if !detail.is_empty() => {
+                Ok(Advance::SkillTraining(detail.to_string()))
+            }
+            "skill training" => Err(invalid("name the skill")),
+            _ => Err(invalid(
+                "expected Increase Capabilities, Move Toward Perfection, Extra Effort or Skill Training",
+            )),
+        }
+    }
+}
+
+// ==========================================
+// TIER ADVANCE
+// ==========================================
+
+/// Choices made when a character gains a tier
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierAdvance {
+    pub tier: u32,
+    pub abilities: Vec<String>,
+    pub pool_points: Pools,
+    pub edge_stat: String,
+}
+
+impl TierAdvance {
+    /// Create an advance that puts pool points and Edge toward the type's primary stat
+    pub fn with_default_boosts(
+        character_type: &CharacterType,
+        tier: u32,
+        abilities: Vec<String>,
+    ) -> Self {
+        let pool_points = match primary_stat(character_type) {
+            "Might" => Pools::new(2, 1, 1),
+            "Speed" => Pools::new(1, 2, 1),
+            _ => Pools::new(1, 1, 2),
+        };
+
+        Self {
+            tier,
+            abilities,
+            pool_points,
+            edge_stat: primary_stat(character_type).to_string(),
+        }
+    }
+}
+
+// ==========================================
+// TIER DATA LOOKUPS
+// ==========================================
+
+/// Infer a type's primary stat from its base pools, breaking ties by Edge
+pub fn primary_stat(character_type: &CharacterType) -> &'static str {
+    let pools = &character_type.stat_pools;
+    let edge = &character_type.edge;
+    let scores = [
+        ("Might", (pools.might, edge.might)),
+        ("Speed", (pools.speed, edge.speed)),
+        ("Intellect", (pools.intellect, edge.intellect)),
+    ];
+
+    // max_by_key returns the last maximum; prefer the first on ties
+    scores
+        .iter()
+        .rev()
+        .max_by_key(|(_, score)| *score)
+        .map(|(name, _)| *name)
+        .unwrap_or("Might")
+}
+
+/// Check that the type has progression and ability data for every tier up to `tier`
+pub fn validate_start_tier(character_type: &CharacterType, tier: u32) -> Result<()> {
+    if !(1..=MAX_TIER).contains(&tier) {
+        return Err(ChargenError::InvalidTier(format!(
+            "Starting tier must be between 1 and {}",
+            MAX_TIER
+        )));
+    }
+
+    for t in 2..=tier {
+        if !character_type.tier_progression.iter().any(|p| p.tier == t) {
+            return Err(ChargenError::InvalidTier(format!(
+                "Type '{}' has no tier {} progression data",
+                character_type.name, t
+            )));
+        }
+        if !character_type.tier_abilities.iter().any(|ta| ta.tier == t) {
+            return Err(ChargenError::InvalidTier(format!(
+                "Type '{}' has no tier {} abilities",
+                character_type.name, t
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of type abilities picked when reaching a tier
+pub fn tier_ability_count(character_type: &CharacterType, tier: u32) -> usize {
+    character_type
+        .tier_abilities
+        .iter()
+        .find(|ta| ta.tier == tier)
+        .map(|ta| ta.count as usize)
+        .unwrap_or(0)
+}
+
+/// Abilities available when reaching a tier (its own list plus lower tiers), without repeats
+pub fn tier_ability_choices(character_type: &CharacterType, tier: u32) -> Vec<&Ability> {
+    let mut choices: Vec<&Ability> = Vec::new();
+    for ability in character_type
+        .tier_abilities
+        .iter()
+        .filter(|ta| ta.tier <= tier)
+        .flat_map(|ta| ta.abilities.iter())
+    {
+        if !choices
+            .iter()
+            .any(|a| a.name.eq_ignore_ascii_case(&ability.name))
+        {
+            choices.push(ability);
+        }
+    }
+    choices
+}
+
+/// Look up chosen ability names in a type's choices up to `tier`, keeping their text
+///
+/// Names the type doesn't offer are kept without details.
+pub fn resolve_tier_abilities(
+    character_type: &CharacterType,
+    tier: u32,
+    names: &[String],
+) -> Vec<SelectedAbility> {
+    let choices = tier_ability_choices(character_type, tier);
+    names
+        .iter()
+        .map(|name| {
+            choices
+                .iter()
+                .find(|a| a.name.eq_ignore_ascii_case(name))
+                .map(|a| SelectedAbility::from(*a))
+                .unwrap_or_else(|| SelectedAbility::named(name.as_str()))
+        })
+        .collect()
+}
+
+/// Tier choices minus abilities already taken at another tier
+pub fn available_tier_abilities<'a>(
+    character_type: &'a CharacterType,
+    tier: u32,
+    taken: &[String],
+) -> Vec<&'a Ability> {
+    tier_ability_choices(character_type, tier)
+        .into_iter()
+        .filter(|a| !taken.iter().any(|t| t.eq_ignore_ascii_case(&a.name)))
+        .collect()
+}
+
+/// Cypher limit for a type at the given tier
+pub fn cypher_limit_at_tier(character_type: &CharacterType, tier: u32) -> u32 {
+    character_type
+        .tier_progression
+        .iter()
+        .find(|p| p.tier == tier)
+        .map(|p| p.cypher_limit)
+        .unwrap_or(character_type.starting_tier.cypher_limit)
+}
+
+// ==========================================
+// APPLYING ADVANCES
+// ==========================================
+
+/// Apply one tier advance to a character sheet
+pub fn apply_tier_advance(
+    sheet: &mut CharacterSheet,
+    character_type: &CharacterType,
+    advance: &TierAdvance,
+) -> Result<()> {
+    if advance.tier != sheet.tier + 1 {
+        return Err(ChargenError::InvalidTier(format!(
+            "Cannot advance from tier {} to tier {}",
+            sheet.tier, advance.tier
+        )));
+    }
+
+    let progression = character_type
+        .tier_progression
+        .iter()
+        .find(|p| p.tier == advance.tier)
+        .ok_or_else(|| {
+            ChargenError::InvalidTier(format!("No tier {} progression data", advance.tier))
+        })?;
+
+    // A type that runs out of untaken abilities asks for only what is left
+    let available =
+        available_tier_abilities(character_type, advance.tier, &sheet.type_ability_names());
+    let required = tier_ability_count(character_type, advance.tier).min(available.len());
+    if advance.abilities.len() != required {
+        return Err(ChargenError::InvalidTier(format!(
+            "Tier {} requires {} abilities, got {}",
+            advance.tier,
+            required,
+            advance.abilities.len()
+        )));
+    }
+
+    let choices = tier_ability_choices(character_type, advance.tier);
+    for (i, ability) in advance.abilities.iter().enumerate() {
+        if !choices.iter().any(|a| a.name.eq_ignore_ascii_case(ability)) {
+            return Err(ChargenError::InvalidTier(format!(
+                "'{}' is not a tier {} ability",
+                ability, advance.tier
+            )));
+        }
+        if sheet.has_type_ability(ability) {
+            return Err(ChargenError::InvalidTier(format!(
+                "Ability '{}' was already chosen",
+                ability
+            )));
+        }
+        if advance.abilities[..i]
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(ability))
+        {
+            return Err(ChargenError::InvalidTier(format!(
+                "Ability '{}' was chosen twice",
+                ability
+            )));
+        }
+    }
+
+    if !advance.pool_points.is_valid() || advance.pool_points.total() != TIER_POOL_POINTS {
+        return Err(ChargenError::InvalidTier(format!(
+            "Tier advances add exactly {} pool points. Current: {}",
+            TIER_POOL_POINTS,
+            advance.pool_points.total()
+        )));
+    }
+
+    match advance.edge_stat.to_lowercase().as_str() {
+        "might" => sheet.edge.might += 1,
+        "speed" => sheet.edge.speed += 1,
+        "intellect" => sheet.edge.intellect += 1,
+        _ => {
+            return Err(ChargenError::InvalidTier(format!(
+                "Unknown Edge stat '{}'",
+                advance.edge_stat
+            )))
+        }
+    }
+
+    sheet.tier_changes.push(format!(
+        "Tier {}: +{} pool points, +1 {} Edge, Effort {}",
+        advance.tier, TIER_POOL_POINTS, advance.edge_stat, progression.effort
+    ));
+    sheet.pools.maximum.add(advance.pool_points);
+    sheet.pools.current.add(advance.pool_points);
+    sheet.update_damage_track();
+    sheet.type_abilities.extend(resolve_tier_abilities(
+        character_type,
+        advance.tier,
+        &advance.abilities,
+    ));
+    sheet.effort = Effort::new(progression.effort);
+    sheet.cypher_limit = progression.cypher_limit;
+    sheet.tier = advance.tier;
+
+    Ok(())
+}
+
+/// Apply an advance bought with XP and record it on the sheet
+///
+/// Each kind of advance can be taken once per tier.
+pub fn apply_advance(sheet: &mut CharacterSheet, advance: &Advance) -> Result<()> {
+    let taken = sheet
+        .advances
+        .iter()
+        .filter_map(|a| a.parse::<Advance>().ok())
+        .any(|a| a.same_kind(advance));
+    if taken {
+        return Err(ChargenError::InvalidAdvance {
+            advance: advance.to_string(),
+            reason: format!("already taken at tier {}", sheet.tier),
+        });
+    }
+
+    match advance {
+        Advance::IncreaseCapabilities(points) => {
+            sheet.pools.maximum.add(*points);
+            sheet.pools.current.add(*points);
+            sheet.update_damage_track();
+        }
+        Advance::MoveTowardPerfection(stat) => match stat.to_lowercase().as_str() {
+            "might" => sheet.edge.might += 1,
+            "speed" => sheet.edge.speed += 1,
+            _ => sheet.edge.intellect += 1,
+        },
+        Advance::ExtraEffort => sheet.effort.max_effort += 1,
+        Advance::SkillTraining(skill) => {
+            sheet.skills.add_trained(skill.clone());
+            sheet.skills.normalize();
+        }
+    }
+    sheet.advances.push(advance.to_string());
+
+    Ok(())
+}
+
+/// Advance a tier 1 sheet using one ability list per additional tier and default boosts
+pub fn advance_to_tier(
+    sheet: &mut CharacterSheet,
+    character_type: &CharacterType,
+    tier_abilities: &[Vec<String>],
+) -> Result<()> {
+    validate_start_tier(character_type, tier_abilities.len() as u32 + 1)?;
+
+    for (i, abilities) in tier_abilities.iter().enumerate() {
+        let advance =
+            TierAdvance::with_default_boosts(character_type, i as u32 + 2, abilities.clone());
+        apply_tier_advance(sheet, character_type, &advance)?;
+    }
+
+    Ok(())
+}
+
+/// Inconsistencies between a sheet's tier and its recorded advances
+pub fn advancement_warnings(sheet: &CharacterSheet) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if sheet.tier > 1 && sheet.advances.is_empty() && sheet.tier_changes.is_empty() {
+        warnings.push(format!(
+            "Tier {} character has no advances or tier changes recorded",
+            sheet.tier
+        ));
+    }
+    if sheet.tier == 1 && sheet.advances.len() >= ADVANCES_PER_TIER {
+        warnings.push(format!(
+            "{} advances recorded but the character is still tier 1",
+            sheet.advances.len()
+        ));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::*;
+
+    fn test_type() -> CharacterType {
+        let ability = |name: &str| Ability {
+            name: name.to_string(),
+            cost: "0".to_string(),
+            ability_type: "Enabler".to_string(),
+            description: String::new(),
+        };
+
+        CharacterType {
+            name: "Glaive".to_string(),
+            source: "Discovery".to_string(),
+            tagline: "Warrior".to_string(),
+            stat_pools: StatPools {
+                might: 11,
+                speed: 10,
+                intellect: 7,
+                bonus_points: 6,
+            },
+            edge: EdgeValues {
+                might: 1,
+                speed: 1,
+                intellect: 0,
+            },
+            starting_tier: StartingTier {
+                effort: 1,
+                cypher_limit: 2,
+            },
+            intrusions: PlayerIntrusions {
+                cost: "1 XP".to_string(),
+                examples: vec![],
+            },
+            equipment: TypeEquipment {
+                weapons: vec![],
+                armor: None,
+                explorer_pack: false,
+                shins: 0,
+                other: vec![],
+                options: vec![],
+            },
+            skills: TypeSkills {
+                trained: vec![],
+                specialized: vec![],
+                inabilities: vec![],
+            },
+            special_abilities: vec![],
+            tier_abilities: vec![
+                TierAbilities {
+                    tier: 1,
+                    count: 2,
+                    abilities: vec![
+                        ability("Fleet of Foot"),
+                        ability("Misdirect"),
+                        ability("Ward"),
+                    ],
+                },
+                TierAbilities {
+                    tier: 2,
+                    count: 1,
+                    abilities: vec![ability("Skill With Defense")],
+                },
+            ],
+            tier_progression: vec![TierProgression {
+                tier: 2,
+                effort: 2,
+                cypher_limit: 3,
+            }],
+        }
+    }
+
+    fn tier_1_sheet() -> CharacterSheet {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.tier = 1;
+        sheet.pools = super::super::sheet::CharacterPools::new(Pools::new(13, 12, 9));
+        sheet.type_abilities = vec![
+            SelectedAbility::named("Fleet of Foot"),
+            SelectedAbility::named("Misdirect"),
+        ];
+        sheet
+    }
+
+    #[test]
+    fn test_validate_start_tier() {
+        let character_type = test_type();
+        assert!(validate_start_tier(&character_type, 1).is_ok());
+        assert!(validate_start_tier(&character_type, 2).is_ok());
+        assert!(validate_start_tier(&character_type, 3).is_err());
+        assert!(validate_start_tier(&character_type, 0).is_err());
+    }
+
+    #[test]
+    fn test_advance_to_tier_two() {
+        let character_type = test_type();
+        let mut sheet = tier_1_sheet();
+
+        advance_to_tier(&mut sheet, &character_type, &[vec!["Ward".to_string()]]).unwrap();
+
+        assert_eq!(sheet.tier, 2);
+        assert_eq!(sheet.effort.max_effort, 2);
+        assert_eq!(sheet.cypher_limit, 3);
+        assert_eq!(sheet.pools.maximum.total(), 34 + TIER_POOL_POINTS);
+        assert_eq!(sheet.edge.might, 1);
+        assert!(sheet.has_type_ability("Ward"));
+        assert_eq!(sheet.tier_changes.len(), 1);
+        assert!(sheet.advances.is_empty());
+        assert!(advancement_warnings(&sheet).is_empty());
+    }
+
+    #[test]
+    fn test_advancement_warnings() {
+        let mut sheet = tier_1_sheet();
+        assert!(advancement_warnings(&sheet).is_empty());
+
+        sheet.tier = 2;
+        assert_eq!(advancement_warnings(&sheet).len(), 1);
+
+        sheet.tier = 1;
+        sheet.advances = vec!["Advance".to_string(); ADVANCES_PER_TIER];
+        assert_eq!(advancement_warnings(&sheet).len(), 1);
+    }
+
+    #[test]
+    fn test_advance_with_no_abilities_left() {
+        let character_type = test_type();
+        let mut sheet = tier_1_sheet();
+        sheet.type_abilities.push(SelectedAbility::named("Ward"));
+        sheet
+            .type_abilities
+            .push(SelectedAbility::named("Skill With Defense"));
+
+        // Nothing is left to pick, so the tier advances without new abilities
+        advance_to_tier(&mut sheet, &character_type, &[vec![]]).unwrap();
+        assert_eq!(sheet.tier, 2);
+        assert_eq!(sheet.type_abilities.len(), 4);
+    }
+
+    #[test]
+    fn test_advance_rejects_repeated_ability() {
+        let character_type = test_type();
+        let mut sheet = tier_1_sheet();
+
+        let result = advance_to_tier(
+            &mut sheet,
+            &character_type,
+            &[vec!["Misdirect".to_string()]],
+        );
+        assert!(result.is_err());
+        assert_eq!(sheet.tier, 1);
+    }
+
+    #[test]
+    fn test_overlapping_tier_lists_offer_no_duplicates() {
+        let mut character_type = test_type();
+        character_type.tier_abilities[1].count = 2;
+        character_type.tier_abilities[1].abilities.push(Ability {
+            name: "ward".to_string(),
+            cost: "0".to_string(),
+            ability_type: "Enabler".to_string(),
+            description: String::new(),
+        });
+
+        let names: Vec<&str> = tier_ability_choices(&character_type, 2)
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["Fleet of Foot", "Misdirect", "Ward", "Skill With Defense"]
+        );
+
+        let mut sheet = tier_1_sheet();
+        let available: Vec<String> =
+            available_tier_abilities(&character_type, 2, &sheet.type_ability_names())
+                .iter()
+                .map(|a| a.name.clone())
+                .collect();
+        assert_eq!(available, vec!["Ward", "Skill With Defense"]);
+
+        // Picking the overlapping ability twice under different casing is rejected
+        let result = advance_to_tier(
+            &mut sheet,
+            &character_type,
+            &[vec!["Ward".to_string(), "ward".to_string()]],
+        );
+        assert!(result.is_err());
+
+        advance_to_tier(&mut sheet, &character_type, &[available]).unwrap();
+        let mut lowered: Vec<String> = sheet
+            .type_abilities
+            .iter()
+            .map(|a| a.name.to_lowercase())
+            .collect();
+        lowered.sort();
+        lowered.dedup();
+        assert_eq!(lowered.len(), sheet.type_abilities.len());
+    }
+
+    #[test]
+    fn test_apply_advances() {
+        let mut sheet = tier_1_sheet();
+        let parse = |s: &str| s.parse::<Advance>().unwrap();
+
+        apply_advance(
+            &mut sheet,
+            &parse("increase capabilities: Might 3, Intellect 1"),
+        )
+        .unwrap();
+        apply_advance(&mut sheet, &parse("Move Toward Perfection: speed")).unwrap();
+        apply_advance(&mut sheet, &parse("Skill Training: Climbing")).unwrap();
+        assert_eq!(sheet.pools.maximum, Pools::new(16, 12, 10));
+        assert_eq!(sheet.edge.speed, 1);
+        assert_eq!(sheet.skills.trained, vec!["Climbing"]);
+        assert_eq!(
+            sheet.advances,
+            vec![
+                "Increase Capabilities: Might 3, Intellect 1",
+                "Move Toward Perfection: Speed",
+                "Skill Training: Climbing",
+            ]
+        );
+
+        // Each kind is taken once per tier
+        let err = apply_advance(&mut sheet, &parse("Skill Training: Swimming")).unwrap_err();
+        assert!(err.to_string().contains("already taken at tier 1"));
+
+        // Names that aren't advances, or that add the wrong points, are rejected
+        for label in [
+            "Learned to juggle",
+            "Increase Capabilities",
+            "Increase Capabilities: Might 2",
+            "Move Toward Perfection: Luck",
+            "Extra Effort: twice",
+        ] {
+            assert!(label.parse::<Advance>().is_err(), "{}", label);
+        }
+        assert_eq!(
+            parse("Increase Capabilities: Speed"),
+            Advance::IncreaseCapabilities(Pools::new(0, CAPABILITY_POINTS, 0))
+        );
+    }
+}
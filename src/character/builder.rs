@@ -1,16 +1,27 @@
 // src/character/builder.rs
 // Character builder - assembles characters from selections
 
-use anyhow::{Context, Result};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::error::{ChargenError, Result};
 
 use crate::character::sheet::Gender;
 use crate::data::{
-    ArtifactInstance, CharacterType, CypherInstance, Descriptor, Focus, GameData, Oddity, Species,
+    create_cypher_instance_with_rng, ArtifactInstance, CharacterType, CypherInstance, Descriptor,
+    Focus, GameData, Oddity, Species,
 };
 
-use super::sheet::{CharacterPools, CharacterSheet, Equipment, Skills};
+use super::advancement::{apply_advance, resolve_tier_abilities, Advance};
+use super::sheet::{CharacterPools, CharacterSheet, Equipment, Skills, SpeciesMode};
 use super::stats::{Edge, Effort, Pools};
 
+/// Oddities every new character starts with
+pub const STARTING_ODDITIES: usize = 1;
+
+/// Starting pool size below which a character is flagged as fragile
+pub const DEFAULT_POOL_FLOOR: i32 = 6;
+
 // ==========================================
 // CHARACTER BUILDER
 // ==========================================
@@ -29,6 +40,10 @@ pub struct CharacterBuilder {
     selected_cyphers: Vec<CypherInstance>,
     selected_artifacts: Vec<ArtifactInstance>,
     selected_oddities: Vec<Oddity>,
+    xp: u32,
+    advances: Vec<Advance>,
+    pool_floor: i32,
+    equipment_option: Option<usize>,
 }
 
 impl CharacterBuilder {
@@ -47,6 +62,10 @@ impl CharacterBuilder {
             selected_cyphers: Vec::new(),
             selected_artifacts: Vec::new(),
             selected_oddities: Vec::new(),
+            xp: 0,
+            advances: Vec::new(),
+            pool_floor: DEFAULT_POOL_FLOOR,
+            equipment_option: None,
         }
     }
 
@@ -141,19 +160,90 @@ impl CharacterBuilder {
         self
     }
 
+    /// Set starting XP
+    pub fn with_xp(mut self, xp: u32) -> Self {
+        self.xp = xp;
+        self
+    }
+
+    /// Set advances already taken (e.g. for experienced NPCs); they are applied on build
+    pub fn with_advances(mut self, advances: Vec<Advance>) -> Self {
+        self.advances = advances;
+        self
+    }
+
+    /// Choose one of the type's equipment options (index into `equipment.options`)
+    pub fn with_equipment_option(mut self, index: usize) -> Self {
+        self.equipment_option = Some(index);
+        self
+    }
+
+    /// Set the pool size below which `build_checked` warns (default 6)
+    pub fn with_pool_floor(mut self, floor: i32) -> Self {
+        self.pool_floor = floor;
+        self
+    }
+
     /// Build the final character sheet
     pub fn build(self, game_data: &GameData) -> Result<CharacterSheet> {
+        self.build_with_rng(game_data, &mut rand::thread_rng())
+    }
+
+    /// Build the final character sheet, drawing any missing starting oddities from `rng`
+    pub fn build_with_rng(
+        self,
+        game_data: &GameData,
+        rng: &mut impl Rng,
+    ) -> Result<CharacterSheet> {
+        let (sheet, warnings) = self.build_unchecked_pools(game_data, rng)?;
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        Ok(sheet)
+    }
+
+    /// Build the final character sheet, returning warnings (e.g. dropped cyphers) instead of printing them
+    ///
+    /// Also warns when a starting pool is below the pool floor; the sheet is still built.
+    pub fn build_checked(self, game_data: &GameData) -> Result<(CharacterSheet, Vec<String>)> {
+        self.build_checked_with_rng(game_data, &mut rand::thread_rng())
+    }
+
+    /// Like `build_checked`, drawing any missing starting oddities from `rng`
+    pub fn build_checked_with_rng(
+        self,
+        game_data: &GameData,
+        rng: &mut impl Rng,
+    ) -> Result<(CharacterSheet, Vec<String>)> {
+        let floor = self.pool_floor;
+        let (sheet, mut warnings) = self.build_unchecked_pools(game_data, rng)?;
+        warnings.extend(low_pool_warnings(&sheet, floor));
+        Ok((sheet, warnings))
+    }
+
+    fn build_unchecked_pools(
+        self,
+        game_data: &GameData,
+        rng: &mut impl Rng,
+    ) -> Result<(CharacterSheet, Vec<String>)> {
+        let mut warnings = Vec::new();
+
         // Validate required fields
-        let name = self.name.context("Character name is required")?;
+        let name = self
+            .name
+            .ok_or_else(|| ChargenError::InvalidCharacter("Character name is required".into()))?;
 
         // Extract values from self before any method calls
         let character_type = self
             .character_type
             .as_ref()
-            .context("Character type is required")?
+            .ok_or_else(|| ChargenError::InvalidCharacter("Character type is required".into()))?
             .clone();
 
-        let focus = self.focus.as_ref().context("Focus is required")?.clone();
+        let focus = self
+            .focus
+            .clone()
+            .ok_or_else(|| ChargenError::InvalidCharacter("Focus is required".into()))?;
 
         let descriptor = self.descriptor.clone();
         let species = self.species.clone();
@@ -163,11 +253,14 @@ impl CharacterBuilder {
 
         // Must have either descriptor OR species (not both)
         if descriptor.is_none() && species.is_none() {
-            anyhow::bail!("Must select either a descriptor or a species");
+            return Err(ChargenError::InvalidCharacter(
+                "Must select either a descriptor or a species".into(),
+            ));
         }
 
         // Start building the sheet
         let mut sheet = CharacterSheet::new(name);
+        sheet.gender = self.gender.clone();
         sheet.character_type = character_type.name.clone();
         sheet.focus = focus.name.clone();
 
@@ -180,6 +273,7 @@ impl CharacterBuilder {
 
         // Calculate stat pools
         sheet.pools = calculate_pools_helper(&character_type, &descriptor, &species, bonus_points)?;
+        sheet.update_damage_track();
 
         // Calculate edge
         sheet.edge = calculate_edge_helper(&character_type, &descriptor);
@@ -190,10 +284,15 @@ impl CharacterBuilder {
 
         // Build skills
         sheet.skills = build_skills_helper(&character_type, &descriptor, &species, &focus);
+        sheet.skills.normalize();
 
         // Build equipment (WITH GAME_DATA)
         sheet.equipment =
             build_equipment_helper(&character_type, &descriptor, &species, &focus, game_data);
+        if let Some(index) = self.equipment_option {
+            apply_equipment_option(&mut sheet, &character_type, index, game_data)?;
+        }
+        sheet.equipment.record_bulk(&game_data.equipment);
 
         // Calculate armor (WITH GAME_DATA)
         sheet.armor = calculate_armor_helper(&character_type, &descriptor, game_data);
@@ -202,8 +301,25 @@ impl CharacterBuilder {
         sheet.special_abilities =
             build_special_abilities_helper(&character_type, &descriptor, &species);
 
+        // Record species modes, starting in the default one
+        if let Some(spec) = &species {
+            let modes: Vec<_> = spec.abilities.iter().filter(|a| a.is_mode()).collect();
+            sheet.species_modes = modes
+                .iter()
+                .map(|a| SpeciesMode {
+                    name: a.name.clone(),
+                    favored_pool: a.favored_pool.clone(),
+                })
+                .collect();
+            sheet.species_mode = modes
+                .iter()
+                .find(|a| a.default_mode)
+                .or(modes.first())
+                .map(|a| a.name.clone());
+        }
+
         // Add selected type abilities
-        sheet.type_abilities = selected_abilities;
+        sheet.type_abilities = resolve_tier_abilities(&character_type, 1, &selected_abilities);
 
         // Add focus tier 1 ability
         sheet.focus_ability = format!(
@@ -226,15 +342,27 @@ impl CharacterBuilder {
             }
         }
 
-        // Add focus link
-        if !focus.connections.is_empty() {
-            sheet.background.focus_link = Some(focus.connections[0].clone());
+        // Add focus link; foci without connections leave it unset
+        sheet.background.focus_link = focus.connections.first().cloned();
+
+        // Add cyphers and oddities granted by the descriptor and focus
+        let (granted_cyphers, granted_oddities) =
+            build_granted_items_helper(&descriptor, &focus, game_data, rng, &mut warnings);
+        for cypher in granted_cyphers {
+            let name = cypher.name.clone();
+            if let Err(e) = sheet.add_cypher(cypher) {
+                warnings.push(format!("Could not add granted cypher '{}': {}", name, e));
+            }
+        }
+        for oddity in granted_oddities {
+            sheet.add_oddity(oddity);
         }
 
         // Add cyphers
         for cypher in self.selected_cyphers {
+            let name = cypher.name.clone();
             if let Err(e) = sheet.add_cypher(cypher) {
-                eprintln!("Warning: Could not add cypher: {}", e);
+                warnings.push(format!("Could not add cypher '{}': {}", name, e));
             }
         }
 
@@ -243,12 +371,26 @@ impl CharacterBuilder {
             sheet.add_artifact(artifact);
         }
 
-        // Add oddities
+        // Starting experience
+        sheet.xp = self.xp;
+        for advance in &self.advances {
+            apply_advance(&mut sheet, advance)?;
+        }
+
+        // Add oddities, topping up to the starting count at random
         for oddity in self.selected_oddities {
             sheet.add_oddity(oddity);
         }
+        while sheet.oddity_count() < STARTING_ODDITIES {
+            match game_data.oddities.choose(rng) {
+                Some(oddity) => sheet.add_oddity(oddity.clone()),
+                None => break,
+            }
+        }
 
-        Ok(sheet)
+        sheet.sources = sheet.required_sources(game_data);
+
+        Ok((sheet, warnings))
     }
 }
 
@@ -299,7 +441,9 @@ fn calculate_pools_helper(
 
     // Validate pools
     if !pools.is_valid() {
-        anyhow::bail!("Invalid stat pools - all pools must be >= 0");
+        return Err(ChargenError::InvalidCharacter(
+            "Invalid stat pools - all pools must be >= 0".into(),
+        ));
     }
 
     // Check bonus points total
@@ -312,11 +456,10 @@ fn calculate_pools_helper(
     };
 
     if bonus_points.total() != bonus_total as i32 {
-        anyhow::bail!(
-            "Bonus points must total {}. Current: {}",
-            bonus_total,
-            bonus_points.total()
-        );
+        return Err(ChargenError::InvalidBonusPoints {
+            expected: bonus_total as i32,
+            got: bonus_points.total(),
+        });
     }
 
     Ok(CharacterPools::new(pools))
@@ -332,6 +475,9 @@ fn calculate_edge_helper(character_type: &CharacterType, _descriptor: &Option<De
 }
 
 /// Build skills list (standalone helper)
+///
+/// Skills from every source are collected as-is; `Skills::normalize` then
+/// applies the stacking rules across them.
 fn build_skills_helper(
     character_type: &CharacterType,
     descriptor: &Option<Descriptor>,
@@ -380,6 +526,93 @@ fn build_skills_helper(
     skills
 }
 
+/// Resolve cyphers and oddities granted by name from the descriptor and focus
+fn build_granted_items_helper(
+    descriptor: &Option<Descriptor>,
+    focus: &Focus,
+    game_data: &GameData,
+    rng: &mut impl Rng,
+    warnings: &mut Vec<String>,
+) -> (Vec<CypherInstance>, Vec<Oddity>) {
+    let mut cypher_names: Vec<&String> = Vec::new();
+    let mut oddity_names: Vec<&String> = Vec::new();
+    if let Some(desc) = descriptor {
+        cypher_names.extend(&desc.equipment.cyphers);
+        oddity_names.extend(&desc.equipment.oddities);
+    }
+    cypher_names.extend(&focus.cyphers);
+    oddity_names.extend(&focus.oddities);
+
+    let mut cyphers = Vec::new();
+    for name in cypher_names {
+        match game_data
+            .cyphers
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+        {
+            Some(cypher) => cyphers.push(create_cypher_instance_with_rng(cypher, rng)),
+            None => warnings.push(format!("Granted cypher '{}' not found", name)),
+        }
+    }
+
+    let mut oddities = Vec::new();
+    for name in oddity_names {
+        match game_data
+            .oddities
+            .iter()
+            .find(|o| o.name.eq_ignore_ascii_case(name))
+        {
+            Some(oddity) => oddities.push(oddity.clone()),
+            None => warnings.push(format!("Granted oddity '{}' not found", name)),
+        }
+    }
+
+    (cyphers, oddities)
+}
+
+/// Full label ("Sword (medium, 4 damage)") for a weapon in equipment.toml
+fn find_weapon_label(name: &str, game_data: &GameData) -> Option<String> {
+    game_data
+        .equipment
+        .weapons
+        .iter()
+        .find(|w| w.name.eq_ignore_ascii_case(name))
+        .map(|w| w.label())
+}
+
+/// Grant the items of one of a type's mutually exclusive equipment options
+///
+/// Types without options are left unchanged. Weapons are resolved from
+/// equipment.toml; anything else is added as gear.
+pub fn apply_equipment_option(
+    sheet: &mut CharacterSheet,
+    character_type: &CharacterType,
+    index: usize,
+    game_data: &GameData,
+) -> Result<()> {
+    let options = &character_type.equipment.options;
+    if options.is_empty() {
+        return Ok(());
+    }
+    let option = options.get(index).ok_or_else(|| {
+        ChargenError::InvalidCharacter(format!(
+            "{} has {} equipment options, not {}",
+            character_type.name,
+            options.len(),
+            index + 1
+        ))
+    })?;
+
+    for item in option {
+        match find_weapon_label(item, game_data) {
+            Some(label) => sheet.equipment.add_weapon(label),
+            None => sheet.equipment.add_gear(item.clone()),
+        }
+    }
+    sheet.equipment.record_bulk(&game_data.equipment);
+    Ok(())
+}
+
 /// Build equipment list with full item resolution
 fn build_equipment_helper(
     character_type: &CharacterType,
@@ -393,18 +626,10 @@ fn build_equipment_helper(
     // ========== TYPE EQUIPMENT ==========
     // Add type weapons (resolve from equipment.toml)
     for weapon_name in &character_type.equipment.weapons {
-        // Try to find the actual weapon
-        if let Some(weapon) = game_data
-            .equipment
-            .weapons
-            .iter()
-            .find(|w| w.name.eq_ignore_ascii_case(weapon_name))
-        {
-            equipment.add_weapon(format!("{} ({} damage)", weapon.name, weapon.damage));
-        } else {
-            // Fallback: just add the name
-            equipment.add_weapon(weapon_name.clone());
-        }
+        // Fall back to the bare name for weapons missing from equipment.toml
+        let label =
+            find_weapon_label(weapon_name, game_data).unwrap_or_else(|| weapon_name.clone());
+        equipment.add_weapon(label);
     }
 
     // Add type armor (resolve from equipment.toml)
@@ -456,7 +681,7 @@ fn build_equipment_helper(
                 .iter()
                 .find(|w| w.name.eq_ignore_ascii_case(weapon_name))
             {
-                equipment.add_weapon(format!("{} ({} damage)", weapon.name, weapon.damage));
+                equipment.add_weapon(weapon.label());
             } else {
                 equipment.add_weapon(weapon_name.clone());
             }
@@ -500,6 +725,9 @@ fn build_equipment_helper(
         equipment.add_gear(item.clone());
     }
 
+    // Stable order regardless of which source granted each item
+    equipment.sort();
+
     equipment
 }
 
@@ -589,12 +817,122 @@ pub fn build_character(
     bonus_intellect: i32,
     selected_abilities: Vec<String>,
 ) -> Result<CharacterSheet> {
+    build_character_with_rng(
+        game_data,
+        name,
+        type_name,
+        descriptor_or_species_name,
+        focus_name,
+        bonus_might,
+        bonus_speed,
+        bonus_intellect,
+        selected_abilities,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Quick build a character from selections, drawing random parts from `rng`
+#[allow(clippy::too_many_arguments)]
+pub fn build_character_with_rng(
+    game_data: &GameData,
+    name: String,
+    type_name: &str,
+    descriptor_or_species_name: &str,
+    focus_name: &str,
+    bonus_might: i32,
+    bonus_speed: i32,
+    bonus_intellect: i32,
+    selected_abilities: Vec<String>,
+    rng: &mut impl Rng,
+) -> Result<CharacterSheet> {
+    builder_from_selections(
+        game_data,
+        name,
+        type_name,
+        descriptor_or_species_name,
+        focus_name,
+        Pools::new(bonus_might, bonus_speed, bonus_intellect),
+        selected_abilities,
+    )?
+    .build_with_rng(game_data, rng)
+}
+
+/// Note for one of a type's player intrusion examples, e.g. "Old Friend: ... (1 XP)"
+pub fn intrusion_note(character_type: &CharacterType, example: &str) -> String {
+    format!("{} ({})", example, character_type.intrusions.cost)
+}
+
+/// Intrusion note for a random example, or `None` when the type lists no examples
+pub fn random_intrusion_note(character_type: &CharacterType, rng: &mut impl Rng) -> Option<String> {
+    character_type
+        .intrusions
+        .examples
+        .choose(rng)
+        .map(|example| intrusion_note(character_type, example))
+}
+
+/// Starting pools below `floor`, which may leave a character too fragile to play
+pub fn low_pool_warnings(sheet: &CharacterSheet, floor: i32) -> Vec<String> {
+    let pools = sheet.pools.maximum;
+    [
+        ("Might", pools.might),
+        ("Speed", pools.speed),
+        ("Intellect", pools.intellect),
+    ]
+    .into_iter()
+    .filter(|(_, value)| *value < floor)
+    .map(|(stat, value)| {
+        format!(
+            "Starting {} pool is {}, below the playable floor of {}",
+            stat, value, floor
+        )
+    })
+    .collect()
+}
+
+/// Quick build a character with starting cyphers, returning warnings for any that were dropped
+#[allow(clippy::too_many_arguments)]
+pub fn build_character_checked(
+    game_data: &GameData,
+    name: String,
+    type_name: &str,
+    descriptor_or_species_name: &str,
+    focus_name: &str,
+    bonus_might: i32,
+    bonus_speed: i32,
+    bonus_intellect: i32,
+    selected_abilities: Vec<String>,
+    cyphers: Vec<CypherInstance>,
+) -> Result<(CharacterSheet, Vec<String>)> {
+    builder_from_selections(
+        game_data,
+        name,
+        type_name,
+        descriptor_or_species_name,
+        focus_name,
+        Pools::new(bonus_might, bonus_speed, bonus_intellect),
+        selected_abilities,
+    )?
+    .with_cyphers(cyphers)
+    .build_checked(game_data)
+}
+
+/// Look up named selections and load them into a builder
+pub(crate) fn builder_from_selections(
+    game_data: &GameData,
+    name: String,
+    type_name: &str,
+    descriptor_or_species_name: &str,
+    focus_name: &str,
+    bonus_points: Pools,
+    selected_abilities: Vec<String>,
+) -> Result<CharacterBuilder> {
     // Find type
     let character_type = game_data
         .types
         .iter()
         .find(|t| t.name.eq_ignore_ascii_case(type_name))
-        .context("Character type not found")?
+        .ok_or_else(|| ChargenError::TypeNotFound(type_name.to_string()))?
         .clone();
 
     // Find focus
@@ -602,7 +940,7 @@ pub fn build_character(
         .foci
         .iter()
         .find(|f| f.name.eq_ignore_ascii_case(focus_name))
-        .context("Focus not found")?
+        .ok_or_else(|| ChargenError::FocusNotFound(focus_name.to_string()))?
         .clone();
 
     // Try to find as species first, then descriptor
@@ -610,7 +948,11 @@ pub fn build_character(
         .with_name(name)
         .with_type(character_type)
         .with_focus(focus)
-        .with_bonus_points(bonus_might, bonus_speed, bonus_intellect);
+        .with_bonus_points(
+            bonus_points.might,
+            bonus_points.speed,
+            bonus_points.intellect,
+        );
 
     // Check if it's a species
     if let Some(species) = game_data
@@ -625,7 +967,9 @@ pub fn build_character(
             .descriptors
             .iter()
             .find(|d| d.name.eq_ignore_ascii_case(descriptor_or_species_name))
-            .context("Descriptor not found")?
+            .ok_or_else(|| {
+                ChargenError::DescriptorNotFound(descriptor_or_species_name.to_string())
+            })?
             .clone();
         builder = builder.with_descriptor(descriptor);
     }
@@ -635,7 +979,7 @@ pub fn build_character(
         builder = builder.add_ability(ability);
     }
 
-    builder.build(game_data)
+    Ok(builder)
 }
 
 // ==========================================
@@ -662,6 +1006,7 @@ mod tests {
             cost: 5,
             range: "immediate".to_string(),
             notes: "Standard blade".to_string(),
+            bulk: 0,
         });
 
         // Add a test armor
@@ -672,6 +1017,7 @@ mod tests {
             speed_effort_cost: 1,
             cost: 2,
             notes: "Basic protection".to_string(),
+            bulk: 0,
         });
 
         game_data
@@ -707,6 +1053,7 @@ mod tests {
                 explorer_pack: false,
                 shins: 5,
                 other: vec![],
+                options: vec![],
             },
             skills: TypeSkills {
                 trained: vec!["Combat".to_string()],
@@ -740,6 +1087,8 @@ mod tests {
                 weapons: vec![],
                 armor: vec![],
                 other: vec![],
+                cyphers: vec![],
+                oddities: vec![],
             },
             initial_links: vec![],
         }
@@ -763,6 +1112,38 @@ mod tests {
         }
     }
 
+    fn create_test_species() -> Species {
+        Species {
+            name: "Varjellen".to_string(),
+            category: "Visitant".to_string(),
+            replaces_descriptor: true,
+            tagline: "Shapers of flesh".to_string(),
+            description: crate::data::SpeciesDescription {
+                appearance: String::new(),
+                culture: String::new(),
+                lifespan: 100,
+            },
+            stat_modifiers: crate::data::SpeciesStatModifiers {
+                might: 0,
+                speed: 0,
+                intellect: 0,
+                initial_bonus_points: Some(3),
+                notes: String::new(),
+            },
+            abilities: vec![],
+            skills: crate::data::SpeciesSkills {
+                trained: vec![],
+                specialized: vec![],
+                hindered: vec![],
+            },
+            equipment: crate::data::SpeciesEquipment {
+                starting_shins: 0,
+                items: vec![],
+            },
+            mutations: None,
+        }
+    }
+
     #[test]
     fn test_character_builder() {
         let game_data = create_test_game_data();
@@ -819,6 +1200,376 @@ mod tests {
             .with_bonus_points(10, 0, 0); // Total 10, but should be 6
 
         let result = builder.build(&game_data);
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(ChargenError::InvalidBonusPoints {
+                expected: 6,
+                got: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn test_build_character_unknown_type() {
+        let game_data = create_test_game_data();
+
+        let result = build_character(
+            &game_data,
+            "Test Hero".to_string(),
+            "Gunslinger",
+            "Strong",
+            "Bears a Halo of Fire",
+            2,
+            2,
+            2,
+            vec![],
+        );
+        assert!(matches!(result, Err(ChargenError::TypeNotFound(name)) if name == "Gunslinger"));
+    }
+
+    #[test]
+    fn test_build_with_starting_xp() {
+        let game_data = create_test_game_data();
+
+        let sheet = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(create_test_descriptor())
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .with_xp(5)
+            .with_advances(vec![
+                "Skill training: Climbing".parse().unwrap(),
+                "Extra Effort".parse().unwrap(),
+            ])
+            .build(&game_data)
+            .unwrap();
+
+        assert_eq!(sheet.tier, 1);
+        assert_eq!(sheet.xp, 5);
+        assert_eq!(
+            sheet.advances,
+            vec![
+                "Skill Training: Climbing".to_string(),
+                "Extra Effort".to_string()
+            ]
+        );
+        assert!(sheet.skills.trained.contains(&"Climbing".to_string()));
+        assert_eq!(sheet.effort.max_effort, 2);
+        assert!(crate::character::advancement_warnings(&sheet).is_empty());
+    }
+
+    #[test]
+    fn test_build_checked_reports_dropped_cyphers() {
+        let game_data = create_test_game_data();
+        let cypher = |name: &str| CypherInstance {
+            instance_id: 0,
+            name: name.to_string(),
+            level: 3,
+            cypher_type: "Subtle".to_string(),
+            effect: String::new(),
+            form: String::new(),
+            duration: String::new(),
+        };
+
+        let (sheet, warnings) = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(create_test_descriptor())
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .with_cyphers(vec![cypher("Detonation"), cypher("Stim"), cypher("Shield")])
+            .build_checked(&game_data)
+            .unwrap();
+
+        assert_eq!(sheet.cypher_limit, 2);
+        assert_eq!(sheet.cyphers.len(), 2);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'Shield'"));
+    }
+
+    #[test]
+    fn test_build_checked_warns_on_low_pool() {
+        let game_data = create_test_game_data();
+        let mut frail = create_test_descriptor();
+        frail.stat_modifiers.might = -6;
+
+        let (sheet, warnings) = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(frail.clone())
+            .with_focus(create_test_focus())
+            .with_bonus_points(0, 0, 6)
+            .build_checked(&game_data)
+            .unwrap();
+
+        assert_eq!(sheet.pools.maximum.might, 4);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Might pool is 4"));
+
+        // A lower floor accepts the same character
+        let (_, warnings) = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(frail)
+            .with_focus(create_test_focus())
+            .with_bonus_points(0, 0, 6)
+            .with_pool_floor(4)
+            .build_checked(&game_data)
+            .unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_random_intrusion_note() {
+        use rand::SeedableRng;
+
+        let mut character_type = create_test_type();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        assert_eq!(random_intrusion_note(&character_type, &mut rng), None);
+
+        character_type.intrusions.examples =
+            vec!["Old Friend: A comrade from your past appears to aid briefly".to_string()];
+        assert_eq!(
+            random_intrusion_note(&character_type, &mut rng).as_deref(),
+            Some("Old Friend: A comrade from your past appears to aid briefly (1 XP)")
+        );
+    }
+
+    #[test]
+    fn test_build_adds_starting_oddities() {
+        let mut game_data = create_test_game_data();
+        game_data.oddities.push(Oddity {
+            id: "glowing-cube".to_string(),
+            name: "Glowing Cube".to_string(),
+            category: "light".to_string(),
+            theme: "light".to_string(),
+            value_shins: 1,
+            tags: vec![],
+            source: "Discovery".to_string(),
+            description: "A cube that glows faintly".to_string(),
+            table_number: None,
+        });
+
+        let sheet = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(create_test_descriptor())
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .build(&game_data)
+            .unwrap();
+        assert!(sheet.oddity_count() >= STARTING_ODDITIES);
+
+        // A chosen oddity is kept rather than topped up again
+        let mut chosen = game_data.oddities[0].clone();
+        chosen.name = "Chosen Trinket".to_string();
+        let sheet = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(create_test_descriptor())
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .add_oddity(chosen)
+            .build(&game_data)
+            .unwrap();
+        assert_eq!(sheet.oddity_count(), STARTING_ODDITIES);
+        assert_eq!(sheet.oddities[0].name, "Chosen Trinket");
+    }
+
+    #[test]
+    fn test_weapon_strings_include_range() {
+        let mut game_data = create_test_game_data();
+        game_data.equipment.weapons.push(crate::data::Weapon {
+            name: "Bow".to_string(),
+            category: "medium".to_string(),
+            damage: 4,
+            cost: 5,
+            range: "long".to_string(),
+            notes: String::new(),
+            bulk: 0,
+        });
+
+        let mut char_type = create_test_type();
+        char_type.equipment.weapons = vec!["Sword".to_string(), "bow".to_string()];
+
+        let sheet = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(char_type)
+            .with_descriptor(create_test_descriptor())
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .build(&game_data)
+            .unwrap();
+        assert!(sheet
+            .equipment
+            .weapons
+            .contains(&"Bow (4 damage, long)".to_string()));
+        assert!(sheet
+            .equipment
+            .weapons
+            .contains(&"Sword (4 damage, immediate)".to_string()));
+    }
+
+    #[test]
+    fn test_focus_without_connections_leaves_link_unset() {
+        let game_data = create_test_game_data();
+        let builder = |focus: Focus| {
+            CharacterBuilder::new()
+                .with_name("Test Hero".to_string())
+                .with_type(create_test_type())
+                .with_descriptor(create_test_descriptor())
+                .with_focus(focus)
+                .with_bonus_points(4, 2, 0)
+        };
+
+        let sheet = builder(create_test_focus()).build(&game_data).unwrap();
+        assert_eq!(sheet.background.focus_link, None);
+
+        let mut connected = create_test_focus();
+        connected.connections = vec!["Pick one other PC".to_string()];
+        let sheet = builder(connected).build(&game_data).unwrap();
+        assert_eq!(
+            sheet.background.focus_link.as_deref(),
+            Some("Pick one other PC")
+        );
+    }
+
+    #[test]
+    fn test_equipment_option_grants_one_choice() {
+        let game_data = create_test_game_data();
+        let mut char_type = create_test_type();
+        char_type.equipment.weapons = vec![];
+        char_type.equipment.options = vec![
+            vec!["Sword".to_string()],
+            vec!["Knife".to_string(), "Knife".to_string()],
+        ];
+        let builder = || {
+            CharacterBuilder::new()
+                .with_name("Test Hero".to_string())
+                .with_type(char_type.clone())
+                .with_descriptor(create_test_descriptor())
+                .with_focus(create_test_focus())
+                .with_bonus_points(4, 2, 0)
+        };
+
+        let none = builder().build(&game_data).unwrap();
+        assert!(none.equipment.weapons.is_empty());
+
+        let sword = builder()
+            .with_equipment_option(0)
+            .build(&game_data)
+            .unwrap();
+        assert_eq!(
+            sword.equipment.weapons,
+            vec!["Sword (4 damage, immediate)".to_string()]
+        );
+
+        // Items missing from equipment.toml are kept as gear
+        let knives = builder()
+            .with_equipment_option(1)
+            .build(&game_data)
+            .unwrap();
+        assert!(knives.equipment.weapons.is_empty());
+        assert_eq!(knives.equipment.gear, vec!["Knife", "Knife"]);
+
+        assert!(builder()
+            .with_equipment_option(2)
+            .build(&game_data)
+            .is_err());
+    }
+
+    #[test]
+    fn test_species_skills_stack_with_type_skills() {
+        let mut species = create_test_species();
+        species.skills.specialized = vec!["combat".to_string()];
+        species.skills.hindered = vec!["Climbing".to_string()];
+
+        let mut char_type = create_test_type();
+        char_type.skills.trained = vec!["Combat".to_string(), "Climbing".to_string()];
+
+        let sheet = CharacterBuilder::new()
+            .with_name("Sorrow".to_string())
+            .with_type(char_type)
+            .with_species(species)
+            .with_focus(create_test_focus())
+            .with_bonus_points(1, 1, 1)
+            .build(&create_test_game_data())
+            .unwrap();
+
+        // Specialized upgrades the type's training instead of listing the skill twice
+        assert_eq!(sheet.skills.specialized, vec!["Combat"]);
+        // Hindered cancels the type's training, leaving the skill untrained
+        assert!(sheet.skills.trained.is_empty());
+        assert!(sheet.skills.inabilities.is_empty());
+        assert_eq!(sheet.skills.get_skill_level("Climbing"), 0);
+    }
+
+    #[test]
+    fn test_descriptor_granted_cypher() {
+        let mut game_data = create_test_game_data();
+        game_data.cyphers.push(crate::data::Cypher {
+            name: "Detonation".to_string(),
+            level_formula: "1d6+2".to_string(),
+            cypher_type: "Anoetic".to_string(),
+            category: "Offensive".to_string(),
+            effect: "Explodes in a burst".to_string(),
+            form: "Handheld grenade".to_string(),
+            duration: "Instant".to_string(),
+        });
+
+        let mut descriptor = create_test_descriptor();
+        descriptor.equipment.cyphers = vec!["detonation".to_string()];
+
+        let (sheet, warnings) = CharacterBuilder::new()
+            .with_name("Test Hero".to_string())
+            .with_type(create_test_type())
+            .with_descriptor(descriptor)
+            .with_focus(create_test_focus())
+            .with_bonus_points(4, 2, 0)
+            .build_checked(&game_data)
+            .unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(sheet.cyphers.len(), 1);
+        assert_eq!(sheet.cyphers[0].name, "Detonation");
+    }
+
+    #[test]
+    fn test_picked_oddities_join_granted_ones() {
+        let oddity = |name: &str| Oddity {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            category: "Curiosity".to_string(),
+            theme: String::new(),
+            value_shins: 1,
+            tags: Vec::new(),
+            source: "Discovery".to_string(),
+            description: String::new(),
+            table_number: None,
+        };
+        let mut focus = create_test_focus();
+        focus.oddities = vec!["Humming Stone".to_string()];
+        let mut game_data = create_test_game_data();
+        game_data.oddities.push(oddity("Humming Stone"));
+        game_data.types.push(create_test_type());
+        game_data.descriptors.push(create_test_descriptor());
+        game_data.foci.push(focus.clone());
+
+        let sheet = builder_from_selections(
+            &game_data,
+            "Test Hero".to_string(),
+            &game_data.types[0].name,
+            &game_data.descriptors[0].name,
+            &focus.name,
+            Pools::new(4, 2, 0),
+            Vec::new(),
+        )
+        .unwrap()
+        .with_oddities(vec![oddity("Glass Leaf")])
+        .build(&game_data)
+        .unwrap();
+
+        let names: Vec<&str> = sheet.oddities.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Humming Stone", "Glass Leaf"]);
     }
 }
@@ -1,12 +1,26 @@
 // src/character/mod.rs
+pub mod advancement;
 pub mod builder;
 pub mod sheet;
 pub mod stats;
 
 
-pub use builder::{build_character, CharacterBuilder};
-pub use sheet::{Background, CharacterPools, CharacterSheet, Equipment, Gender, Skills};
+pub use advancement::{
+    advance_to_tier, advancement_warnings, apply_advance, apply_tier_advance,
+    available_tier_abilities, cypher_limit_at_tier, primary_stat, resolve_tier_abilities,
+    tier_ability_choices, tier_ability_count, validate_start_tier, Advance, TierAdvance,
+    ADVANCES_PER_TIER, CAPABILITY_POINTS, MAX_TIER, TIER_POOL_POINTS,
+};
+pub use builder::{
+    apply_equipment_option, build_character, build_character_checked, build_character_with_rng,
+    intrusion_note, low_pool_warnings, random_intrusion_note, CharacterBuilder, DEFAULT_POOL_FLOOR,
+    STARTING_ODDITIES,
+};
+pub use sheet::{
+    Background, CharacterPools, CharacterSheet, Equipment, Gender, PhysicalDescription,
+    RerollOptions, SelectedAbility, Skills, SpeciesMode,
+};
 pub use stats::{
-    calculate_armor, calculate_pools, determine_damage_track, DamageTrack, Edge, Effort, Pools,
-    RecoveryRoll,
+    calculate_armor, calculate_pools, determine_damage_track, parse_ability_cost, target_number,
+    DamageTrack, Edge, Effort, PoolKind, Pools, RecoveryRoll, DIFFICULTY_NAMES, RECOVERY_ACTIONS,
 };
@@ -1,9 +1,18 @@
 // src/character/sheet.rs
 // Character sheet - the final compiled character
 
-use super::stats::{DamageTrack, Edge, Effort, Pools};
-use crate::data::{ArtifactInstance, CypherInstance, Oddity};
+use super::stats::{DamageTrack, Edge, Effort, Pools, RecoveryRoll};
+use crate::data::{
+    create_artifact_instance_with_rng, create_cypher_instance_with_rng, find_descriptor,
+    find_focus, find_type, Ability, ArtifactInstance, CypherInstance, EquipmentData, GameData,
+    Oddity,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use super::builder::STARTING_ODDITIES;
 
 // ==========================================
 // CHARACTER SHEET
@@ -54,8 +63,11 @@ pub struct CharacterSheet {
 
     // Abilities
     pub special_abilities: Vec<String>,
-    pub type_abilities: Vec<String>,
+    pub type_abilities: Vec<SelectedAbility>,
     pub focus_ability: String,
+    /// A player intrusion example from the type, with its cost
+    #[serde(default)]
+    pub intrusion_note: Option<String>,
 
     // Equipment
     pub equipment: Equipment,
@@ -72,10 +84,127 @@ pub struct CharacterSheet {
 
     // Background
     pub background: Background,
+    /// Age, height, build and a notable feature, for NPCs generated with a description
+    #[serde(default)]
+    pub physical_description: Option<PhysicalDescription>,
 
     // Advancement
     pub xp: u32,
     pub advances: Vec<String>,
+    /// Tier increases, kept apart from the advances bought with XP
+    #[serde(default)]
+    pub tier_changes: Vec<String>,
+
+    // Species modes (e.g. Lattimor states)
+    #[serde(default)]
+    pub species_modes: Vec<SpeciesMode>,
+    #[serde(default)]
+    pub species_mode: Option<String>,
+
+    // RNG seed for randomly generated characters
+    #[serde(default)]
+    pub generation_seed: Option<u64>,
+
+    // Source books the character's options come from
+    #[serde(default)]
+    pub sources: Vec<String>,
+
+    // Campaign labels such as "town-guard", for filtering saved characters
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A species mode/stance the character can switch between
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesMode {
+    pub name: String,
+    pub favored_pool: Option<String>,
+}
+
+/// A chosen type ability, kept with its text so saved sheets don't need the game data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SelectedAbilityRepr")]
+pub struct SelectedAbility {
+    pub name: String,
+    pub cost: String,
+    pub ability_type: String,
+    pub description: String,
+}
+
+impl SelectedAbility {
+    /// An ability known only by name, as in sheets saved before descriptions were stored
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            cost: String::new(),
+            ability_type: String::new(),
+            description: String::new(),
+        }
+    }
+}
+
+impl From<&Ability> for SelectedAbility {
+    fn from(ability: &Ability) -> Self {
+        Self {
+            name: ability.name.clone(),
+            cost: ability.cost.clone(),
+            ability_type: ability.ability_type.clone(),
+            description: ability.description.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for SelectedAbility {
+    /// `Name (cost, type): description`, or just the name when the details are unknown
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        let details: Vec<&str> = [self.cost.as_str(), self.ability_type.as_str()]
+            .into_iter()
+            .filter(|d| !d.is_empty())
+            .collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+        if !self.description.is_empty() {
+            write!(f, ": {}", self.description)?;
+        }
+        Ok(())
+    }
+}
+
+/// Saved sheets list type abilities either as bare names or as full entries
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SelectedAbilityRepr {
+    Name(String),
+    Full {
+        name: String,
+        #[serde(default)]
+        cost: String,
+        #[serde(default)]
+        ability_type: String,
+        #[serde(default)]
+        description: String,
+    },
+}
+
+impl From<SelectedAbilityRepr> for SelectedAbility {
+    fn from(repr: SelectedAbilityRepr) -> Self {
+        match repr {
+            SelectedAbilityRepr::Name(name) => SelectedAbility::named(name),
+            SelectedAbilityRepr::Full {
+                name,
+                cost,
+                ability_type,
+                description,
+            } => SelectedAbility {
+                name,
+                cost,
+                ability_type,
+                description,
+            },
+        }
+    }
 }
 
 /// Character pools with both current and maximum values
@@ -123,24 +252,52 @@ impl Skills {
         }
     }
 
-    /// Add a trained skill
+    /// Add a trained skill (repeats stack when normalized)
     pub fn add_trained(&mut self, skill: String) {
-        if !self.trained.contains(&skill) {
-            self.trained.push(skill);
-        }
+        self.trained.push(skill);
     }
 
     /// Add a specialized skill
     pub fn add_specialized(&mut self, skill: String) {
-        if !self.specialized.contains(&skill) {
-            self.specialized.push(skill);
-        }
+        self.specialized.push(skill);
     }
 
     /// Add an inability
     pub fn add_inability(&mut self, skill: String) {
-        if !self.inabilities.contains(&skill) {
-            self.inabilities.push(skill);
+        self.inabilities.push(skill);
+    }
+
+    /// Apply the stacking rules so each skill appears in at most one list
+    ///
+    /// Trained twice becomes specialized, training and an inability cancel
+    /// out, and specialized plus an inability drops to trained.
+    pub fn normalize(&mut self) {
+        let mut skills: Vec<(String, i32)> = Vec::new();
+
+        let entries = self
+            .trained
+            .drain(..)
+            .map(|s| (s, 1))
+            .chain(self.specialized.drain(..).map(|s| (s, 2)))
+            .chain(self.inabilities.drain(..).map(|s| (s, -1)));
+
+        for (skill, level) in entries {
+            match skills
+                .iter_mut()
+                .find(|(s, _)| s.eq_ignore_ascii_case(&skill))
+            {
+                Some((_, total)) => *total += level,
+                None => skills.push((skill, level)),
+            }
+        }
+
+        for (skill, level) in skills {
+            match level.clamp(-1, 2) {
+                2 => self.specialized.push(skill),
+                1 => self.trained.push(skill),
+                -1 => self.inabilities.push(skill),
+                _ => {}
+            }
         }
     }
 
@@ -180,6 +337,9 @@ pub struct Equipment {
     pub shield: Option<String>,
     pub gear: Vec<String>,
     pub shins: u32,
+    /// Bulk of one of each carried item, by item name; only items with bulk are listed
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bulk: BTreeMap<String, u32>,
 }
 
 impl Equipment {
@@ -191,6 +351,7 @@ impl Equipment {
             shield: None,
             gear: Vec::new(),
             shins: 0,
+            bulk: BTreeMap::new(),
         }
     }
 
@@ -208,6 +369,59 @@ impl Equipment {
     pub fn add_gear(&mut self, item: String) {
         self.gear.push(item);
     }
+
+    /// Stable-sort weapons and gear by name (case-insensitive)
+    pub fn sort(&mut self) {
+        self.weapons.sort_by_key(|w| w.to_lowercase());
+        self.gear.sort_by_key(|g| g.to_lowercase());
+    }
+
+    /// Look up the bulk of each carried weapon, armor and gear item in `data`
+    ///
+    /// Items missing from the data or without bulk are left out, so content
+    /// with no weights records nothing.
+    pub fn record_bulk(&mut self, data: &EquipmentData) {
+        let mut bulk = BTreeMap::new();
+
+        for label in &self.weapons {
+            let name = item_name(label);
+            if let Some(weapon) = data
+                .weapons
+                .iter()
+                .find(|w| w.name.eq_ignore_ascii_case(name))
+            {
+                bulk.insert(name.to_string(), weapon.bulk);
+            }
+        }
+        if let Some(label) = &self.armor {
+            let name = item_name(label);
+            if let Some(armor) = data
+                .armor
+                .iter()
+                .find(|a| a.name.eq_ignore_ascii_case(name))
+            {
+                bulk.insert(name.to_string(), armor.bulk);
+            }
+        }
+        for item in &self.gear {
+            let (name, _) = split_gear_quantity(item);
+            let gear = data
+                .gear
+                .iter()
+                .find(|g| g.name.eq_ignore_ascii_case(name))
+                .or_else(|| {
+                    data.gear
+                        .iter()
+                        .find(|g| g.name.eq_ignore_ascii_case(item_name(name)))
+                });
+            if let Some(gear) = gear {
+                bulk.insert(name.to_string(), gear.bulk);
+            }
+        }
+
+        bulk.retain(|_, b| *b > 0);
+        self.bulk = bulk;
+    }
 }
 
 impl Default for Equipment {
@@ -216,6 +430,27 @@ impl Default for Equipment {
     }
 }
 
+/// Which randomized numenera `CharacterSheet::reroll_numenera` replaces
+///
+/// Artifacts are off by default: nothing draws them at random, so any on a
+/// sheet were picked by the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RerollOptions {
+    pub cyphers: bool,
+    pub artifacts: bool,
+    pub oddities: bool,
+}
+
+impl Default for RerollOptions {
+    fn default() -> Self {
+        Self {
+            cyphers: true,
+            artifacts: false,
+            oddities: true,
+        }
+    }
+}
+
 /// Character background and connections
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Background {
@@ -243,6 +478,67 @@ impl Default for Background {
     }
 }
 
+/// How a character looks, rolled from species-appropriate tables
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhysicalDescription {
+    pub age: u32,
+    pub height: String,
+    pub build: String,
+    pub notable_feature: String,
+}
+
+impl std::fmt::Display for PhysicalDescription {
+    /// "Age 34, tall, wiry; a jagged scar across one cheek"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Age {}, {}, {}; {}",
+            self.age,
+            self.height.to_lowercase(),
+            self.build.to_lowercase(),
+            self.notable_feature
+        )
+    }
+}
+
+/// Split a trailing quantity ("Rations x3", "Rations ×3") off a gear entry
+fn split_gear_quantity(item: &str) -> (&str, usize) {
+    let item = item.trim();
+    if let Some((name, suffix)) = item.rsplit_once(' ') {
+        let digits = suffix
+            .strip_prefix('x')
+            .or_else(|| suffix.strip_prefix('×'));
+        if let Some(count) = digits.and_then(|d| d.parse::<usize>().ok()) {
+            if count > 0 && !name.trim().is_empty() {
+                return (name.trim_end(), count);
+            }
+        }
+    }
+    (item, 1)
+}
+
+/// Item name from a sheet label, e.g. "Bow" from "Bow (4 damage, long)"
+fn item_name(label: &str) -> &str {
+    label.split(" (").next().unwrap_or(label).trim()
+}
+
+/// Keep only the items matching a granted name, one item per grant
+fn keep_granted<T>(items: &mut Vec<T>, granted: &[&String], name: impl Fn(&T) -> &str) {
+    let mut remaining = granted.to_vec();
+    items.retain(|item| {
+        match remaining
+            .iter()
+            .position(|g| g.eq_ignore_ascii_case(name(item)))
+        {
+            Some(index) => {
+                remaining.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    });
+}
+
 // ==========================================
 // CHARACTER SHEET METHODS
 // ==========================================
@@ -267,17 +563,30 @@ impl CharacterSheet {
             special_abilities: Vec::new(),
             type_abilities: Vec::new(),
             focus_ability: String::new(),
+            intrusion_note: None,
             equipment: Equipment::new(),
             cypher_limit: 2,
             cyphers: Vec::new(),
             artifacts: Vec::new(),
             oddities: Vec::new(),
             background: Background::new(),
+            physical_description: None,
             xp: 0,
             advances: Vec::new(),
+            tier_changes: Vec::new(),
+            species_modes: Vec::new(),
+            species_mode: None,
+            generation_seed: None,
+            sources: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
+    /// Recovery at the character's tier, using the average d6 roll
+    pub fn recovery(&self) -> RecoveryRoll {
+        RecoveryRoll::simulate(self.tier)
+    }
+
     /// Get the character's full descriptive sentence
     pub fn character_sentence(&self) -> String {
         let descriptor_or_species = if let Some(species) = &self.species {
@@ -294,6 +603,43 @@ impl CharacterSheet {
         )
     }
 
+    /// Distinct source books for the chosen type, descriptor and focus, in that order
+    ///
+    /// Species carry no source in the data, so they add nothing here.
+    pub fn required_sources(&self, game_data: &GameData) -> Vec<String> {
+        let type_source = find_type(&game_data.types, &self.character_type).map(|t| &t.source);
+        let descriptor_source = self
+            .descriptor
+            .as_deref()
+            .and_then(|name| find_descriptor(&game_data.descriptors, name))
+            .map(|d| &d.source);
+        let focus_source = find_focus(&game_data.foci, &self.focus).map(|f| &f.source);
+
+        let mut sources: Vec<String> = Vec::new();
+        for source in [type_source, descriptor_source, focus_source]
+            .into_iter()
+            .flatten()
+        {
+            let source = source.trim();
+            if !source.is_empty() && !sources.iter().any(|s| s.eq_ignore_ascii_case(source)) {
+                sources.push(source.to_string());
+            }
+        }
+        sources
+    }
+
+    /// Names of the chosen type abilities
+    pub fn type_ability_names(&self) -> Vec<String> {
+        self.type_abilities.iter().map(|a| a.name.clone()).collect()
+    }
+
+    /// Whether a type ability was chosen (case-insensitive)
+    pub fn has_type_ability(&self, name: &str) -> bool {
+        self.type_abilities
+            .iter()
+            .any(|a| a.name.eq_ignore_ascii_case(name))
+    }
+
     /// Check if character is using a species (which replaces descriptor)
     pub fn uses_species(&self) -> bool {
         self.species.is_some()
@@ -304,6 +650,102 @@ impl CharacterSheet {
         self.damage_track = super::stats::determine_damage_track(self.pools.current);
     }
 
+    /// Sort equipment lists so sheets with the same items print identically
+    pub fn sort_equipment(&mut self) {
+        self.equipment.sort();
+    }
+
+    /// Gear names with identical entries collapsed into counts, in first-seen order
+    ///
+    /// A trailing quantity such as "Rations x3" counts as three "Rations".
+    /// The underlying gear list is left untouched.
+    pub fn consolidated_gear(&self) -> Vec<(String, usize)> {
+        let mut consolidated: Vec<(String, usize)> = Vec::new();
+        for item in &self.equipment.gear {
+            let (name, count) = split_gear_quantity(item);
+            match consolidated.iter_mut().find(|(n, _)| n == name) {
+                Some((_, total)) => *total += count,
+                None => consolidated.push((name.to_string(), count)),
+            }
+        }
+        consolidated
+    }
+
+    /// Total bulk of weapons, armor and gear, counting gear quantities
+    ///
+    /// Uses the bulk recorded by `Equipment::record_bulk`; 0 when nothing has bulk.
+    pub fn total_bulk(&self) -> u32 {
+        let equipment = &self.equipment;
+        let bulk_of = |name: &str| equipment.bulk.get(name).copied().unwrap_or(0);
+
+        let weapons: u32 = equipment
+            .weapons
+            .iter()
+            .map(|w| bulk_of(item_name(w)))
+            .sum();
+        let armor = equipment
+            .armor
+            .as_deref()
+            .map_or(0, |a| bulk_of(item_name(a)));
+        let gear: u32 = self
+            .consolidated_gear()
+            .iter()
+            .map(|(name, count)| bulk_of(name) * *count as u32)
+            .sum();
+        weapons + armor + gear
+    }
+
+    /// Gear for display, with repeated items shown as "Torch ×3"
+    pub fn gear_display(&self) -> Vec<String> {
+        self.consolidated_gear()
+            .into_iter()
+            .map(|(name, count)| match count {
+                1 => name,
+                _ => format!("{} ×{}", name, count),
+            })
+            .collect()
+    }
+
+    /// Adjust a current pool by delta, clamped to 0..=maximum, and refresh the damage track
+    pub fn adjust_current_pool(&mut self, pool_name: &str, delta: i32) -> bool {
+        let (Some(current), Some(maximum)) = (
+            self.pools.current.get_pool(pool_name),
+            self.pools.maximum.get_pool(pool_name),
+        ) else {
+            return false;
+        };
+
+        let value = (current + delta).clamp(0, maximum.max(0));
+        self.pools.current.set_pool(pool_name, value);
+        self.update_damage_track();
+        true
+    }
+
+    /// Switch to one of the species' modes
+    pub fn set_species_mode(&mut self, mode: &str) -> Result<(), String> {
+        let Some(found) = self
+            .species_modes
+            .iter()
+            .find(|m| m.name.eq_ignore_ascii_case(mode))
+        else {
+            return Err(format!("Unknown species mode: {}", mode));
+        };
+
+        self.species_mode = Some(found.name.clone());
+        Ok(())
+    }
+
+    /// Get the currently active species mode
+    pub fn current_species_mode(&self) -> Option<&SpeciesMode> {
+        let active = self.species_mode.as_ref()?;
+        self.species_modes.iter().find(|m| &m.name == active)
+    }
+
+    /// Get the pool favored by the active species mode
+    pub fn favored_pool(&self) -> Option<&str> {
+        self.current_species_mode()?.favored_pool.as_deref()
+    }
+
     /// Add XP
     pub fn add_xp(&mut self, amount: u32) {
         self.xp += amount;
@@ -319,8 +761,16 @@ impl CharacterSheet {
         }
     }
 
+    /// Check whether `levels` of Effort can be applied from a pool right now
+    ///
+    /// Combines the Effort cap with the pool's current value (after Edge).
+    pub fn can_apply_effort(&self, pool_name: &str, levels: u32) -> bool {
+        self.effort.is_valid(levels)
+            && self.can_spend(pool_name, self.effort.calculate_cost(levels))
+    }
+
     /// Add a cypher instance
-    pub fn add_cypher(&mut self, cypher: CypherInstance) -> Result<(), String> {
+    pub fn add_cypher(&mut self, mut cypher: CypherInstance) -> Result<(), String> {
         if self.cyphers.len() >= self.cypher_limit as usize {
             return Err(format!(
                 "Cypher limit reached ({}/{})",
@@ -328,10 +778,24 @@ impl CharacterSheet {
                 self.cypher_limit
             ));
         }
+        if cypher.instance_id == 0 {
+            cypher.instance_id = self.next_instance_id();
+        }
         self.cyphers.push(cypher);
         Ok(())
     }
 
+    /// Lowest id no cypher or artifact on the sheet uses
+    fn next_instance_id(&self) -> u64 {
+        let used: HashSet<u64> = self
+            .cyphers
+            .iter()
+            .map(|c| c.instance_id)
+            .chain(self.artifacts.iter().map(|a| a.instance_id))
+            .collect();
+        (1..).find(|id| !used.contains(id)).unwrap_or(1)
+    }
+
     /// Remove a cypher by index
     pub fn remove_cypher(&mut self, index: usize) -> Option<CypherInstance> {
         if index < self.cyphers.len() {
@@ -356,7 +820,10 @@ impl CharacterSheet {
     // ==========================================
 
     /// Add an artifact
-    pub fn add_artifact(&mut self, artifact: ArtifactInstance) {
+    pub fn add_artifact(&mut self, mut artifact: ArtifactInstance) {
+        if artifact.instance_id == 0 {
+            artifact.instance_id = self.next_instance_id();
+        }
         self.artifacts.push(artifact);
     }
 
@@ -397,6 +864,121 @@ impl CharacterSheet {
         self.oddities.len()
     }
 
+    // ==========================================
+    // TAGS
+    // ==========================================
+
+    /// Add a tag, trimmed; returns false when it is blank or already present
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        if tag.is_empty() || self.has_tag(tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        true
+    }
+
+    /// Whether the character carries `tag` (case-insensitive)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag.trim()))
+    }
+
+    // ==========================================
+    // NUMENERA REROLL
+    // ==========================================
+
+    /// Replace the randomized numenera, keeping every deliberate choice
+    ///
+    /// Type, descriptor, focus, abilities, stats and equipment are untouched.
+    pub fn reroll_numenera(&mut self, game_data: &GameData, options: &RerollOptions) {
+        self.reroll_numenera_with_rng(game_data, options, &mut rand::thread_rng());
+    }
+
+    /// Replace the randomized numenera, drawing from `rng`
+    ///
+    /// Cyphers and oddities granted by the descriptor or focus are kept.
+    /// Cyphers are refilled to the cypher limit; artifacts and oddities keep
+    /// their count, with at least the starting number of oddities.
+    pub fn reroll_numenera_with_rng(
+        &mut self,
+        game_data: &GameData,
+        options: &RerollOptions,
+        rng: &mut impl Rng,
+    ) {
+        let (granted_cyphers, granted_oddities) = self.granted_numenera(game_data);
+
+        if options.cyphers {
+            keep_granted(&mut self.cyphers, &granted_cyphers, |c| &c.name);
+            let free = (self.cypher_limit as usize).saturating_sub(self.cyphers.len());
+            let cyphers: Vec<CypherInstance> = game_data
+                .cyphers
+                .choose_multiple(rng, free)
+                .map(|cypher| create_cypher_instance_with_rng(cypher, rng))
+                .collect();
+            for mut cypher in cyphers {
+                cypher.instance_id = self.next_instance_id();
+                self.cyphers.push(cypher);
+            }
+        }
+        if options.artifacts {
+            let count = self.artifacts.len();
+            self.artifacts.clear();
+            for _ in 0..count {
+                if let Some(artifact) = game_data.artifacts.choose(rng) {
+                    let mut artifact = create_artifact_instance_with_rng(artifact, rng);
+                    artifact.instance_id = self.next_instance_id();
+                    self.artifacts.push(artifact);
+                }
+            }
+        }
+        if options.oddities {
+            let count = self.oddities.len().max(STARTING_ODDITIES);
+            keep_granted(&mut self.oddities, &granted_oddities, |o| &o.name);
+            let random = count.saturating_sub(self.oddities.len());
+            self.oddities
+                .extend(game_data.oddities.choose_multiple(rng, random).cloned());
+        }
+    }
+
+    /// Index of the starting oddity: the first one the descriptor or focus didn't grant
+    ///
+    /// Granted oddities come first on a built sheet and bought ones last.
+    pub fn starting_oddity_index(&self, game_data: &GameData) -> Option<usize> {
+        let (_, mut granted) = self.granted_numenera(game_data);
+        self.oddities.iter().position(|oddity| {
+            match granted
+                .iter()
+                .position(|g| g.eq_ignore_ascii_case(&oddity.name))
+            {
+                Some(index) => {
+                    granted.swap_remove(index);
+                    false
+                }
+                None => true,
+            }
+        })
+    }
+
+    /// Names of the cyphers and oddities granted by the descriptor and focus
+    fn granted_numenera<'a>(&self, game_data: &'a GameData) -> (Vec<&'a String>, Vec<&'a String>) {
+        let descriptor = self
+            .descriptor
+            .as_deref()
+            .and_then(|name| find_descriptor(&game_data.descriptors, name));
+        let focus = find_focus(&game_data.foci, &self.focus);
+        let cyphers = descriptor
+            .into_iter()
+            .flat_map(|d| &d.equipment.cyphers)
+            .chain(focus.into_iter().flat_map(|f| &f.cyphers))
+            .collect();
+        let oddities = descriptor
+            .into_iter()
+            .flat_map(|d| &d.equipment.oddities)
+            .chain(focus.into_iter().flat_map(|f| &f.oddities))
+            .collect();
+        (cyphers, oddities)
+    }
+
     // ==========================================
     // SUMMARY WITH NUMENERA
     // ==========================================
@@ -477,6 +1059,53 @@ mod tests {
         assert!(sheet.uses_species());
     }
 
+    #[test]
+    fn test_tags_round_trip_and_default() {
+        let mut sheet = CharacterSheet::new("Guard".to_string());
+        assert!(sheet.add_tag(" town-guard "));
+        assert!(!sheet.add_tag("Town-Guard"));
+        assert!(!sheet.add_tag("  "));
+        assert!(sheet.has_tag("TOWN-GUARD"));
+
+        let mut json = serde_json::to_value(&sheet).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["town-guard"]));
+        let reloaded: CharacterSheet = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(reloaded.tags, sheet.tags);
+
+        // Sheets saved before tags existed have none
+        json.as_object_mut().unwrap().remove("tags");
+        let old: CharacterSheet = serde_json::from_value(json).unwrap();
+        assert!(old.tags.is_empty());
+    }
+
+    #[test]
+    fn test_type_abilities_load_from_old_and_new_sheets() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.type_abilities = vec![SelectedAbility {
+            name: "Fleet of Foot".to_string(),
+            cost: "1+ Speed point".to_string(),
+            ability_type: "Action".to_string(),
+            description: "You move a short distance.".to_string(),
+        }];
+        let mut json = serde_json::to_value(&sheet).unwrap();
+
+        let reloaded: CharacterSheet = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(reloaded.type_abilities, sheet.type_abilities);
+        assert_eq!(
+            reloaded.type_abilities[0].to_string(),
+            "Fleet of Foot (1+ Speed point, Action): You move a short distance."
+        );
+
+        // Sheets saved before descriptions were stored list bare names
+        json["type_abilities"] = serde_json::json!(["Misdirect"]);
+        let old: CharacterSheet = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            old.type_abilities,
+            vec![SelectedAbility::named("Misdirect")]
+        );
+        assert_eq!(old.type_abilities[0].to_string(), "Misdirect");
+    }
+
     #[test]
     fn test_skills() {
         let mut skills = Skills::new();
@@ -490,6 +1119,38 @@ mod tests {
         assert_eq!(skills.get_skill_level("Swimming"), 0);
     }
 
+    #[test]
+    fn test_skills_normalize_stacking() {
+        let mut skills = Skills::new();
+        skills.add_trained("Climbing".to_string());
+        skills.add_trained("climbing".to_string());
+        skills.add_trained("Stealth".to_string());
+        skills.add_inability("Stealth".to_string());
+        skills.add_specialized("Persuasion".to_string());
+        skills.add_trained("Persuasion".to_string());
+        skills.add_specialized("Lore".to_string());
+        skills.add_inability("Lore".to_string());
+        skills.add_inability("History".to_string());
+        skills.add_inability("History".to_string());
+        skills.normalize();
+
+        // trained + trained -> specialized
+        assert_eq!(skills.get_skill_level("Climbing"), 2);
+        // trained + inability -> cancel out
+        assert_eq!(skills.get_skill_level("Stealth"), 0);
+        // specialized + trained -> still specialized
+        assert_eq!(skills.get_skill_level("Persuasion"), 2);
+        // specialized + inability -> trained
+        assert_eq!(skills.get_skill_level("Lore"), 1);
+        // inability + inability -> inability
+        assert_eq!(skills.get_skill_level("History"), -1);
+
+        // Each skill ends up in exactly one list
+        assert_eq!(skills.specialized, vec!["Climbing", "Persuasion"]);
+        assert_eq!(skills.trained, vec!["Lore"]);
+        assert_eq!(skills.inabilities, vec!["History"]);
+    }
+
     #[test]
     fn test_equipment() {
         let mut equipment = Equipment::new();
@@ -505,6 +1166,228 @@ mod tests {
         assert_eq!(equipment.shins, 15);
     }
 
+    #[test]
+    fn test_reroll_numenera_keeps_choices() {
+        use rand::SeedableRng;
+
+        let mut game_data = GameData::new();
+        game_data.cyphers = ["Detonation", "Stim", "Phase Changer"]
+            .iter()
+            .map(|name| crate::data::Cypher {
+                name: name.to_string(),
+                level_formula: "1d6".to_string(),
+                cypher_type: "Manifest".to_string(),
+                category: "Utility".to_string(),
+                effect: String::new(),
+                form: String::new(),
+                duration: String::new(),
+            })
+            .collect();
+        game_data.oddities.push(Oddity {
+            id: "glowing-cube".to_string(),
+            name: "Glowing Cube".to_string(),
+            category: "light".to_string(),
+            theme: "light".to_string(),
+            value_shins: 1,
+            tags: vec![],
+            source: "Discovery".to_string(),
+            description: String::new(),
+            table_number: None,
+        });
+
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.character_type = "Glaive".to_string();
+        sheet.pools = CharacterPools::new(Pools::new(11, 10, 7));
+        sheet.type_abilities = vec![SelectedAbility::named("Fleet of Foot")];
+        sheet.cypher_limit = 2;
+        let before = serde_json::to_value((&sheet.pools, &sheet.type_abilities)).unwrap();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        sheet.reroll_numenera_with_rng(&game_data, &RerollOptions::default(), &mut rng);
+
+        let after = serde_json::to_value((&sheet.pools, &sheet.type_abilities)).unwrap();
+        assert_eq!(before, after);
+        assert_eq!(sheet.character_type, "Glaive");
+        assert_eq!(sheet.cyphers.len(), 2);
+        assert_ne!(sheet.cyphers[0].name, sheet.cyphers[1].name);
+        let ids: Vec<u64> = sheet.cyphers.iter().map(|c| c.instance_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(sheet.oddity_count(), STARTING_ODDITIES);
+        assert!(sheet.artifacts.is_empty());
+
+        // Options leave the unselected kinds alone
+        let cyphers = sheet.cyphers.clone();
+        let options = RerollOptions {
+            cyphers: false,
+            ..Default::default()
+        };
+        sheet.reroll_numenera_with_rng(&game_data, &options, &mut rng);
+        assert_eq!(
+            serde_json::to_value(&sheet.cyphers).unwrap(),
+            serde_json::to_value(&cyphers).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_reroll_numenera_keeps_granted_items() {
+        use rand::SeedableRng;
+
+        let mut game_data = GameData::new();
+        game_data.cyphers = ["Detonation", "Stim", "Phase Changer"]
+            .iter()
+            .map(|name| crate::data::Cypher {
+                name: name.to_string(),
+                level_formula: "1d6".to_string(),
+                cypher_type: "Manifest".to_string(),
+                category: "Utility".to_string(),
+                effect: String::new(),
+                form: String::new(),
+                duration: String::new(),
+            })
+            .collect();
+        game_data.oddities = ["Glowing Cube", "Singing Shell", "Warm Stone"]
+            .iter()
+            .map(|name| Oddity {
+                id: name.to_lowercase(),
+                name: name.to_string(),
+                category: "curio".to_string(),
+                theme: "curio".to_string(),
+                value_shins: 1,
+                tags: vec![],
+                source: "Discovery".to_string(),
+                description: String::new(),
+                table_number: None,
+            })
+            .collect();
+        game_data.foci.push(crate::data::Focus {
+            name: "Carries a Quiver".to_string(),
+            cyphers: vec!["detonation".to_string()],
+            oddities: vec!["Glowing Cube".to_string()],
+            ..Default::default()
+        });
+
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.focus = "Carries a Quiver".to_string();
+        sheet.cypher_limit = 2;
+        for cypher in &game_data.cyphers[..2] {
+            sheet
+                .add_cypher(crate::data::create_cypher_instance(cypher))
+                .unwrap();
+        }
+        sheet.add_oddity(game_data.oddities[0].clone());
+        sheet.add_oddity(game_data.oddities[1].clone());
+        let artifact = ArtifactInstance {
+            instance_id: 0,
+            name: "Lightning Rod".to_string(),
+            level: 5,
+            depletion: "1 in 1d20".to_string(),
+            form_type: "Handheld".to_string(),
+            effect: String::new(),
+            form: String::new(),
+        };
+        sheet.add_artifact(artifact);
+        let granted_id = sheet.cyphers[0].instance_id;
+        // The focus's oddity doesn't count as the starting one
+        assert_eq!(sheet.starting_oddity_index(&game_data), Some(1));
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        sheet.reroll_numenera_with_rng(&game_data, &RerollOptions::default(), &mut rng);
+
+        // The focus's cypher and oddity survive; the rest is redrawn to the same counts
+        assert_eq!(sheet.cyphers.len(), 2);
+        assert_eq!(sheet.cyphers[0].name, "Detonation");
+        assert_eq!(sheet.cyphers[0].instance_id, granted_id);
+        assert_eq!(sheet.oddity_count(), 2);
+        assert_eq!(sheet.oddities[0].name, "Glowing Cube");
+        // Artifacts are the player's picks and stay by default
+        assert_eq!(sheet.artifacts.len(), 1);
+        assert_eq!(sheet.artifacts[0].name, "Lightning Rod");
+    }
+
+    #[test]
+    fn test_reroll_artifacts_follows_the_seed() {
+        use rand::SeedableRng;
+
+        let mut game_data = GameData::new();
+        game_data.artifacts = ["Lightning Rod", "Skystrider", "Nullifier"]
+            .iter()
+            .map(|name| crate::data::Artifact {
+                id: name.to_lowercase(),
+                name: name.to_string(),
+                level_formula: "1d6+2".to_string(),
+                depletion: "1 in 1d20".to_string(),
+                form_type: "Handheld".to_string(),
+                category: "Weapon".to_string(),
+                tags: vec![],
+                effect: String::new(),
+                form: String::new(),
+                gm_intrusion: String::new(),
+                crafting: None,
+            })
+            .collect();
+        let options = RerollOptions {
+            artifacts: true,
+            ..RerollOptions::default()
+        };
+
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        for artifact in &game_data.artifacts[..2] {
+            sheet.add_artifact(crate::data::create_artifact_instance(artifact));
+        }
+        let reroll = |seed| {
+            let mut sheet = sheet.clone();
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            sheet.reroll_numenera_with_rng(&game_data, &options, &mut rng);
+            sheet.artifacts
+        };
+
+        let (first, second) = (reroll(9), reroll(9));
+        assert_eq!(first.len(), 2);
+        assert_eq!(
+            first.iter().map(|a| a.instance_id).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        for (a, b) in first.iter().zip(&second) {
+            assert_eq!(
+                (&a.name, a.level, a.instance_id),
+                (&b.name, b.level, b.instance_id)
+            );
+        }
+    }
+
+    #[test]
+    fn test_total_bulk() {
+        let mut data = GameData::new().equipment;
+        data.weapons.push(crate::data::Weapon {
+            name: "Bow".to_string(),
+            category: "medium".to_string(),
+            damage: 4,
+            cost: 5,
+            range: "long".to_string(),
+            notes: String::new(),
+            bulk: 2,
+        });
+        data.gear.push(crate::data::Gear {
+            name: "Rations".to_string(),
+            category: "food".to_string(),
+            cost: 1,
+            notes: String::new(),
+            bulk: 1,
+        });
+
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet
+            .equipment
+            .add_weapon("Bow (4 damage, long)".to_string());
+        sheet.equipment.add_gear("Rations x3".to_string());
+        sheet.equipment.add_gear("Hammer".to_string());
+        assert_eq!(sheet.total_bulk(), 0);
+
+        sheet.equipment.record_bulk(&data);
+        assert_eq!(sheet.total_bulk(), 2 + 3);
+        assert!(!sheet.equipment.bulk.contains_key("Hammer"));
+    }
+
     #[test]
     fn test_can_spend() {
         let mut sheet = CharacterSheet::new("Test".to_string());
@@ -524,6 +1407,27 @@ mod tests {
         assert!(!sheet.can_spend("might", 12));
     }
 
+    #[test]
+    fn test_can_apply_effort() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.pools = CharacterPools::new(Pools::new(10, 10, 4));
+        sheet.edge = Edge::new(1, 0, 0);
+        sheet.effort = Effort::new(2);
+
+        // Two levels cost 5, minus Edge 1
+        assert!(sheet.can_apply_effort("might", 2));
+        assert!(sheet.can_apply_effort("might", 0));
+
+        // Over the Effort cap, even with plenty in the pool
+        assert!(!sheet.can_apply_effort("might", 3));
+
+        // Within the cap, but two levels cost 5 against an Intellect of 4
+        assert!(sheet.can_apply_effort("intellect", 1));
+        assert!(!sheet.can_apply_effort("intellect", 2));
+
+        assert!(!sheet.can_apply_effort("luck", 1));
+    }
+
     #[test]
     fn test_character_pools_reset() {
         let mut pools = CharacterPools::new(Pools::new(10, 10, 8));
@@ -538,6 +1442,106 @@ mod tests {
         assert_eq!(pools.current.speed, 10);
     }
 
+    #[test]
+    fn test_adjust_current_pool_bounds_and_damage_track() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.pools = CharacterPools::new(Pools::new(10, 10, 8));
+
+        // Cannot exceed maximum
+        assert!(sheet.adjust_current_pool("might", 5));
+        assert_eq!(sheet.pools.current.might, 10);
+
+        // Cannot go below zero, and damage track follows
+        sheet.adjust_current_pool("might", -15);
+        assert_eq!(sheet.pools.current.might, 0);
+        assert_eq!(sheet.damage_track, DamageTrack::Impaired);
+
+        sheet.adjust_current_pool("speed", -10);
+        assert_eq!(sheet.damage_track, DamageTrack::Debilitated);
+
+        sheet.adjust_current_pool("speed", 1);
+        assert_eq!(sheet.damage_track, DamageTrack::Impaired);
+
+        assert!(!sheet.adjust_current_pool("luck", 1));
+    }
+
+    #[test]
+    fn test_set_species_mode() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.species_modes = vec![
+            SpeciesMode {
+                name: "Fugue State".to_string(),
+                favored_pool: None,
+            },
+            SpeciesMode {
+                name: "Bursk State".to_string(),
+                favored_pool: Some("Might".to_string()),
+            },
+        ];
+        sheet.species_mode = Some("Fugue State".to_string());
+        assert_eq!(sheet.favored_pool(), None);
+
+        assert!(sheet.set_species_mode("bursk state").is_ok());
+        assert_eq!(sheet.species_mode.as_deref(), Some("Bursk State"));
+        assert_eq!(sheet.favored_pool(), Some("Might"));
+
+        assert!(sheet.set_species_mode("Neem State").is_err());
+        assert_eq!(sheet.species_mode.as_deref(), Some("Bursk State"));
+    }
+
+    #[test]
+    fn test_sort_equipment_is_stable() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.equipment.weapons = vec![
+            "Sword (4 damage)".to_string(),
+            "Dagger (2 damage)".to_string(),
+        ];
+        sheet.equipment.gear = vec![
+            "Torch".to_string(),
+            "rope (second)".to_string(),
+            "Rope (first)".to_string(),
+            "Bedroll".to_string(),
+        ];
+        sheet.equipment.armor = Some("Leather jerkin".to_string());
+
+        sheet.sort_equipment();
+
+        assert_eq!(
+            sheet.equipment.weapons,
+            vec!["Dagger (2 damage)", "Sword (4 damage)"]
+        );
+        assert_eq!(
+            sheet.equipment.gear,
+            vec!["Bedroll", "Rope (first)", "rope (second)", "Torch"]
+        );
+        assert_eq!(sheet.equipment.armor.as_deref(), Some("Leather jerkin"));
+    }
+
+    #[test]
+    fn test_consolidated_gear() {
+        let mut sheet = CharacterSheet::new("Test".to_string());
+        sheet.equipment.gear = vec![
+            "Torch".to_string(),
+            "Rations x3".to_string(),
+            "Torch".to_string(),
+            "Rope".to_string(),
+            "Rations".to_string(),
+            "Torch".to_string(),
+            "Rations ×1".to_string(),
+        ];
+
+        assert_eq!(
+            sheet.consolidated_gear(),
+            vec![
+                ("Torch".to_string(), 3),
+                ("Rations".to_string(), 5),
+                ("Rope".to_string(), 1),
+            ]
+        );
+        assert_eq!(sheet.gear_display(), vec!["Torch ×3", "Rations ×5", "Rope"]);
+        assert_eq!(sheet.equipment.gear.len(), 7);
+    }
+
     #[test]
     fn test_xp_tracking() {
         let mut sheet = CharacterSheet::new("Test".to_string());
@@ -87,6 +87,39 @@ impl std::fmt::Display for Pools {
     }
 }
 
+/// Pools above this fraction of their maximum count as healthy
+pub const POOL_HIGH_RATIO: f32 = 0.7;
+
+/// Pools at or below this fraction of their maximum count as low
+pub const POOL_LOW_RATIO: f32 = 0.3;
+
+/// How full a pool is, for colouring current values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolLevel {
+    High,
+    Medium,
+    Low,
+}
+
+impl PoolLevel {
+    /// Classify a current/maximum pair; empty maximums count as full
+    pub fn from_values(current: i32, maximum: i32) -> Self {
+        let ratio = if maximum > 0 {
+            current as f32 / maximum as f32
+        } else {
+            1.0
+        };
+
+        if ratio > POOL_HIGH_RATIO {
+            PoolLevel::High
+        } else if ratio > POOL_LOW_RATIO {
+            PoolLevel::Medium
+        } else {
+            PoolLevel::Low
+        }
+    }
+}
+
 // ==========================================
 // EDGE VALUES
 // ==========================================
@@ -180,6 +213,84 @@ impl Effort {
     }
 }
 
+// ==========================================
+// ABILITY COSTS
+// ==========================================
+
+/// The pool an ability cost is paid from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoolKind {
+    Might,
+    Speed,
+    Intellect,
+}
+
+impl std::fmt::Display for PoolKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PoolKind::Might => write!(f, "Might"),
+            PoolKind::Speed => write!(f, "Speed"),
+            PoolKind::Intellect => write!(f, "Intellect"),
+        }
+    }
+}
+
+/// Parse an ability cost such as "1 Intellect", "2 Int pts", "1+ Speed" or "0"
+///
+/// Free abilities ("0" or no cost) have no pool; variable costs ("2+") give their minimum.
+/// Returns `None` for text that doesn't follow the `<points> <pool> [pt|pts|point|points]` form.
+pub fn parse_ability_cost(s: &str) -> Option<(u32, Option<PoolKind>)> {
+    let mut words = s.split_whitespace();
+    let Some(amount) = words.next() else {
+        return Some((0, None));
+    };
+    let points: u32 = amount.strip_suffix('+').unwrap_or(amount).parse().ok()?;
+
+    let Some(pool) = words.next() else {
+        return (points == 0).then_some((0, None));
+    };
+    let pool = match pool.to_lowercase().as_str() {
+        "might" => PoolKind::Might,
+        "speed" => PoolKind::Speed,
+        "intellect" | "int" => PoolKind::Intellect,
+        _ => return None,
+    };
+
+    match words.next().map(|unit| unit.to_lowercase()) {
+        None => {}
+        Some(unit) if matches!(unit.as_str(), "pt" | "pts" | "point" | "points") => {}
+        Some(_) => return None,
+    }
+    if words.next().is_some() {
+        return None;
+    }
+    Some((points, Some(pool)))
+}
+
+// ==========================================
+// TASK DIFFICULTY
+// ==========================================
+
+/// Names of the task difficulties, indexed by difficulty (0 = routine)
+pub const DIFFICULTY_NAMES: [&str; 11] = [
+    "Routine",
+    "Simple",
+    "Standard",
+    "Demanding",
+    "Difficult",
+    "Challenging",
+    "Intimidating",
+    "Formidable",
+    "Heroic",
+    "Immortal",
+    "Impossible",
+];
+
+/// d20 target number for a difficulty eased by `steps`; 0 means the task succeeds automatically
+pub fn target_number(difficulty: u32, steps: u32) -> u32 {
+    difficulty.saturating_sub(steps) * 3
+}
+
 // ==========================================
 // DAMAGE TRACK
 // ==========================================
@@ -306,6 +417,9 @@ pub fn calculate_armor(base_armor: u32, additional_armor: &[u32]) -> u32 {
 // RECOVERY ROLLS
 // ==========================================
 
+/// Recovery rolls a character may take each day, in the order they are taken
+pub const RECOVERY_ACTIONS: [&str; 4] = ["One action", "Ten minutes", "One hour", "Ten hours"];
+
 /// Represents a recovery roll
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RecoveryRoll {
@@ -331,6 +445,23 @@ impl RecoveryRoll {
         // Use average roll (3.5 rounded to 4) for character creation
         Self::new(4, tier)
     }
+
+    /// Roll the d6 of a recovery for play
+    pub fn roll(tier: u32, rng: &mut impl rand::Rng) -> Self {
+        Self::new(rng.gen_range(1..=6), tier)
+    }
+
+    /// Dice expression, e.g. "1d6+2"
+    pub fn formula(&self) -> String {
+        format!("1d6+{}", self.tier)
+    }
+}
+
+impl std::fmt::Display for RecoveryRoll {
+    /// "1d6+2 (6)": the dice and the points recovered
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.formula(), self.total())
+    }
 }
 
 // ==========================================
@@ -417,6 +548,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_ability_cost() {
+        assert_eq!(
+            parse_ability_cost("1 Intellect"),
+            Some((1, Some(PoolKind::Intellect)))
+        );
+        assert_eq!(parse_ability_cost("0"), Some((0, None)));
+        assert_eq!(parse_ability_cost(""), Some((0, None)));
+        assert_eq!(
+            parse_ability_cost("2 Int pts"),
+            Some((2, Some(PoolKind::Intellect)))
+        );
+        assert_eq!(
+            parse_ability_cost("1+ Speed"),
+            Some((1, Some(PoolKind::Speed)))
+        );
+        assert_eq!(
+            parse_ability_cost("1 Might pt"),
+            Some((1, Some(PoolKind::Might)))
+        );
+
+        assert_eq!(parse_ability_cost("one point"), None);
+        assert_eq!(parse_ability_cost("3"), None);
+        assert_eq!(parse_ability_cost("2 Luck"), None);
+        assert_eq!(parse_ability_cost("2 Might per round"), None);
+    }
+
+    #[test]
+    fn test_target_number() {
+        assert_eq!(target_number(4, 0), 12);
+        assert_eq!(target_number(4, 1), 9);
+        assert_eq!(target_number(2, 3), 0);
+        assert_eq!(DIFFICULTY_NAMES[4], "Difficult");
+    }
+
     #[test]
     fn test_calculate_pools() {
         let base = Pools::new(10, 10, 8);
@@ -445,5 +611,13 @@ mod tests {
 
         let simulated = RecoveryRoll::simulate(2);
         assert_eq!(simulated.total(), 6); // 4 (avg roll) + 2 (tier)
+        assert_eq!(simulated.to_string(), "1d6+2 (6)");
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let rolled = RecoveryRoll::roll(3, &mut rng);
+            assert!((1..=6).contains(&rolled.roll));
+            assert_eq!(rolled.formula(), "1d6+3");
+        }
     }
 }
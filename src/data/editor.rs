@@ -0,0 +1,175 @@
+// src/data/editor.rs
+// Format-preserving edits to the TOML content files
+
+use toml_edit::{DocumentMut, TableLike, Value};
+
+use crate::error::{ChargenError, Result};
+
+// ==========================================
+// CONTENT FILES
+// ==========================================
+
+/// Editable content kinds: (kind, file, array-of-tables key)
+pub const CONTENT_FILES: &[(&str, &str, &str)] = &[
+    ("types", "types.toml", "types"),
+    ("descriptors", "descriptors.toml", "descriptors"),
+    ("foci", "foci.toml", "foci"),
+    ("species", "species.toml", "species"),
+    ("cyphers", "cyphers.toml", "cypher"),
+    ("artifacts", "artifacts.toml", "artifact"),
+    ("oddities", "oddities.toml", "oddity"),
+    ("discoveries", "discoveries.toml", "discovery"),
+    ("weapons", "equipment.toml", "weapons"),
+    ("armor", "equipment.toml", "armor"),
+    ("gear", "equipment.toml", "gear"),
+];
+
+/// File name and array key for a content kind (case-insensitive)
+pub fn content_file(kind: &str) -> Option<(&'static str, &'static str)> {
+    CONTENT_FILES
+        .iter()
+        .find(|(k, _, _)| k.eq_ignore_ascii_case(kind))
+        .map(|(_, file, key)| (*file, *key))
+}
+
+// ==========================================
+// FIELD EDITING
+// ==========================================
+
+/// Set one scalar field of the entry named `entry`, keeping comments and layout
+///
+/// `field` may be a dotted path into nested tables (e.g. `stat_pools.might`).
+/// The field must already exist, and `value` must parse as its current type.
+pub fn set_field(text: &str, key: &str, entry: &str, field: &str, value: &str) -> Result<String> {
+    let edit_error = |message: String| ChargenError::Edit(message);
+    let mut doc: DocumentMut = text
+        .parse()
+        .map_err(|e| edit_error(format!("Failed to parse TOML: {}", e)))?;
+
+    let entries = doc
+        .get_mut(key)
+        .and_then(|item| item.as_array_of_tables_mut())
+        .ok_or_else(|| edit_error(format!("No [[{}]] entries in file", key)))?;
+    let table = entries
+        .iter_mut()
+        .find(|t| {
+            t.get("name")
+                .and_then(|n| n.as_str())
+                .is_some_and(|n| n.eq_ignore_ascii_case(entry))
+        })
+        .ok_or_else(|| edit_error(format!("No entry named '{}' in [[{}]]", entry, key)))?;
+
+    let segments: Vec<&str> = field.split('.').collect();
+    let (last, parents) = segments
+        .split_last()
+        .filter(|(last, _)| !last.is_empty())
+        .ok_or_else(|| edit_error("Field path is empty".to_string()))?;
+
+    let mut current: &mut dyn TableLike = table;
+    for segment in parents {
+        current = current
+            .get_mut(segment)
+            .and_then(|item| item.as_table_like_mut())
+            .ok_or_else(|| edit_error(format!("'{}' has no table '{}'", entry, segment)))?;
+    }
+
+    let target = current
+        .get_mut(last)
+        .and_then(|item| item.as_value_mut())
+        .ok_or_else(|| edit_error(format!("'{}' has no field '{}'", entry, field)))?;
+    let replacement = parse_like(target, value)
+        .map_err(|e| edit_error(format!("Invalid value for '{}': {}", field, e)))?;
+
+    let decor = target.decor().clone();
+    *target = replacement;
+    *target.decor_mut() = decor;
+
+    Ok(doc.to_string())
+}
+
+/// Parse `value` as the same scalar type as `current`
+fn parse_like(current: &Value, value: &str) -> Result<Value> {
+    let parsed = match current {
+        Value::String(_) => Some(Value::from(value)),
+        Value::Integer(_) => value.parse::<i64>().ok().map(Value::from),
+        Value::Float(_) => value.parse::<f64>().ok().map(Value::from),
+        Value::Boolean(_) => value.parse::<bool>().ok().map(Value::from),
+        other => {
+            return Err(ChargenError::Edit(format!(
+                "Only scalar fields can be set, not {}",
+                other.type_name()
+            )))
+        }
+    };
+    parsed.ok_or_else(|| {
+        ChargenError::Edit(format!(
+            "'{}' is not a valid {}",
+            value,
+            current.type_name()
+        ))
+    })
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"# Hand-written foci
+[[foci]]
+name = "Bears a Halo of Fire"
+theme = "Fire"   # keep this note
+stat_pools = { might = 1, speed = 0 }
+
+[[foci]]
+name = "Leads"
+theme = "Leadership"
+suitable = true
+tags = ["social"]
+"#;
+
+    #[test]
+    fn test_set_field_preserves_comments() {
+        let edited = set_field(FIXTURE, "foci", "bears a halo of fire", "theme", "Flame").unwrap();
+
+        assert!(edited.contains("theme = \"Flame\"   # keep this note"));
+        assert!(edited.starts_with("# Hand-written foci\n"));
+        assert!(edited.contains("theme = \"Leadership\""));
+
+        let edited = set_field(
+            &edited,
+            "foci",
+            "Bears a Halo of Fire",
+            "stat_pools.might",
+            "2",
+        )
+        .unwrap();
+        assert!(edited.contains("might = 2, speed = 0"));
+    }
+
+    #[test]
+    fn test_set_field_rejects_bad_paths_and_types() {
+        let err = |entry, field, value| {
+            set_field(FIXTURE, "foci", entry, field, value)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(err("Juggles Moons", "theme", "x").contains("No entry named"));
+        assert!(err("Leads", "colour", "x").contains("no field 'colour'"));
+        assert!(err("Leads", "suitable", "maybe").contains("Invalid value"));
+        assert!(err("Leads", "tags", "x").contains("Invalid value"));
+        assert!(err("Bears a Halo of Fire", "stat_pools.might", "lots").contains("Invalid value"));
+        assert!(set_field(FIXTURE, "cypher", "Leads", "theme", "x").is_err());
+    }
+
+    #[test]
+    fn test_content_file_lookup() {
+        assert_eq!(content_file("Foci"), Some(("foci.toml", "foci")));
+        assert_eq!(content_file("weapons"), Some(("equipment.toml", "weapons")));
+        assert_eq!(content_file("presets"), None);
+    }
+}
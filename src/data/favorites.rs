@@ -0,0 +1,211 @@
+// src/data/favorites.rs
+// Player favorites: types, descriptors and foci pinned to the top of selection lists
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::loader::{find_descriptor, find_focus, find_species, find_type};
+use super::models::GameData;
+use crate::error::{ChargenError, Result};
+
+// ==========================================
+// FAVORITES MODEL
+// ==========================================
+
+/// Which selection list a favorite belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FavoriteKind {
+    Type,
+    /// Descriptors and species share the descriptor step
+    Descriptor,
+    Focus,
+}
+
+/// Names the player has marked as favorites, stored in favorites.toml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Favorites {
+    #[serde(default)]
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub descriptors: Vec<String>,
+    #[serde(default)]
+    pub foci: Vec<String>,
+}
+
+impl Favorites {
+    fn names(&self, kind: FavoriteKind) -> &Vec<String> {
+        match kind {
+            FavoriteKind::Type => &self.types,
+            FavoriteKind::Descriptor => &self.descriptors,
+            FavoriteKind::Focus => &self.foci,
+        }
+    }
+
+    fn names_mut(&mut self, kind: FavoriteKind) -> &mut Vec<String> {
+        match kind {
+            FavoriteKind::Type => &mut self.types,
+            FavoriteKind::Descriptor => &mut self.descriptors,
+            FavoriteKind::Focus => &mut self.foci,
+        }
+    }
+
+    /// Whether `name` is a favorite (case-insensitive)
+    pub fn contains(&self, kind: FavoriteKind, name: &str) -> bool {
+        self.names(kind)
+            .iter()
+            .any(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    /// Add or remove `name`, returning whether it is now a favorite
+    pub fn toggle(&mut self, kind: FavoriteKind, name: &str) -> bool {
+        let names = self.names_mut(kind);
+        match names.iter().position(|n| n.eq_ignore_ascii_case(name)) {
+            Some(pos) => {
+                names.remove(pos);
+                false
+            }
+            None => {
+                names.push(name.to_string());
+                true
+            }
+        }
+    }
+
+    /// Indices of `names` with favorites first, otherwise keeping their order
+    pub fn pinned_order<'a>(
+        &self,
+        kind: FavoriteKind,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<usize> {
+        let (mut pinned, rest): (Vec<_>, Vec<_>) = names
+            .into_iter()
+            .enumerate()
+            .partition(|(_, name)| self.contains(kind, name));
+        pinned.extend(rest);
+        pinned.into_iter().map(|(i, _)| i).collect()
+    }
+}
+
+// ==========================================
+// LOADING, SAVING AND VALIDATION
+// ==========================================
+
+/// Load favorites from a TOML file; a missing file means no favorites
+pub fn load_favorites(path: &Path) -> Result<Favorites> {
+    if !path.exists() {
+        return Ok(Favorites::default());
+    }
+    let text = std::fs::read_to_string(path).map_err(|source| ChargenError::DataFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    toml::from_str(&text).map_err(|source| ChargenError::DataParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Write favorites back to a TOML file
+pub fn save_favorites(path: &Path, favorites: &Favorites) -> Result<()> {
+    let text = toml::to_string(favorites).map_err(ChargenError::TomlWrite)?;
+    std::fs::write(path, text).map_err(|source| ChargenError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Warn about favorites that no longer match anything in the loaded data
+pub fn validate_favorites(favorites: &Favorites, game_data: &GameData) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for name in &favorites.types {
+        if find_type(&game_data.types, name).is_none() {
+            warnings.push(format!(
+                "Favorite type '{}' is not in the loaded data",
+                name
+            ));
+        }
+    }
+    for name in &favorites.descriptors {
+        if find_descriptor(&game_data.descriptors, name).is_none()
+            && find_species(&game_data.species, name).is_none()
+        {
+            warnings.push(format!(
+                "Favorite descriptor '{}' is not in the loaded data",
+                name
+            ));
+        }
+    }
+    for name in &favorites.foci {
+        if find_focus(&game_data.foci, name).is_none() {
+            warnings.push(format!(
+                "Favorite focus '{}' is not in the loaded data",
+                name
+            ));
+        }
+    }
+
+    warnings
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_and_pinned_order() {
+        let mut favorites = Favorites::default();
+        assert!(favorites.toggle(FavoriteKind::Focus, "Bears a Halo of Fire"));
+        assert!(favorites.contains(FavoriteKind::Focus, "bears a halo of fire"));
+        assert!(!favorites.contains(FavoriteKind::Type, "Bears a Halo of Fire"));
+
+        let names = [
+            "Carries a Quiver",
+            "Bears a Halo of Fire",
+            "Crafts Illusions",
+        ];
+        assert_eq!(
+            favorites.pinned_order(FavoriteKind::Focus, names),
+            vec![1, 0, 2]
+        );
+
+        assert!(!favorites.toggle(FavoriteKind::Focus, "BEARS A HALO OF FIRE"));
+        assert!(favorites.foci.is_empty());
+    }
+
+    #[test]
+    fn test_missing_file_and_round_trip() {
+        let dir = std::env::temp_dir().join(format!("chargen-favorites-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("favorites.toml");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(load_favorites(&path).unwrap(), Favorites::default());
+
+        let favorites = Favorites {
+            types: vec!["Glaive".to_string()],
+            descriptors: vec!["Strong".to_string()],
+            foci: Vec::new(),
+        };
+        save_favorites(&path, &favorites).unwrap();
+        assert_eq!(load_favorites(&path).unwrap(), favorites);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_flags_unknown_names() {
+        let favorites = Favorites {
+            types: vec!["Glaive".to_string()],
+            descriptors: Vec::new(),
+            foci: vec!["Juggles Moons".to_string()],
+        };
+        let warnings = validate_favorites(&favorites, &GameData::new());
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[1].contains("Juggles Moons"));
+    }
+}
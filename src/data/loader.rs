@@ -2,43 +2,208 @@
 // Functions to load TOML data files
 
 use super::models::*;
-use anyhow::{Context, Result};
+use crate::error::{ChargenError, Result};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ==========================================
 // PUBLIC LOADING FUNCTIONS
 // ==========================================
 
 pub fn load_all_data() -> Result<GameData> {
-    let mut data = GameData::new();
+    load_all_data_with_progress(|_| {})
+}
 
-    data.types = load_types()?;
-    data.descriptors = load_descriptors()?;
-    data.foci = load_foci()?;
-    data.equipment = load_equipment()?;
-    data.cyphers = load_cyphers()?;
-    data.artifacts = load_artifacts()?;
-    data.oddities = load_oddities()?;
-    data.discoveries = load_discoveries()?;
-    data.species = load_species()?;
+/// Load all data files, reporting each file name before it is read
+pub fn load_all_data_with_progress(on_progress: impl FnMut(&str)) -> Result<GameData> {
+    load_game_data(&DataSource::Dir(data_dir()), on_progress)
+}
 
-    Ok(data)
+/// Load all data files from a specific directory
+pub fn load_all_data_from_dir(dir: &Path, on_progress: impl FnMut(&str)) -> Result<GameData> {
+    load_game_data(&DataSource::Dir(dir.to_path_buf()), on_progress)
+}
+
+/// Load the data files compiled into the binary
+#[cfg(feature = "embed-data")]
+pub fn load_all_data_embedded() -> Result<GameData> {
+    load_game_data(&DataSource::Embedded, |_| {})
 }
 
 /// Load character types from types.toml
 pub fn load_types() -> Result<Vec<CharacterType>> {
-    let path = data_dir().join("types.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let data: TypesData = load_toml("types.toml")?;
+    Ok(data.types)
+}
 
-    let data: TypesData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
+/// Where the TOML data files are read from
+enum DataSource {
+    /// A directory on disk
+    Dir(PathBuf),
+    /// Copies compiled into the binary
+    #[cfg(feature = "embed-data")]
+    Embedded,
+}
 
-    Ok(data.types)
+/// Data files compiled in with the `embed-data` feature
+#[cfg(feature = "embed-data")]
+const EMBEDDED_FILES: &[(&str, &str)] = &[
+    ("types.toml", include_str!("../../data/types.toml")),
+    (
+        "descriptors.toml",
+        include_str!("../../data/descriptors.toml"),
+    ),
+    ("foci.toml", include_str!("../../data/foci.toml")),
+    ("equipment.toml", include_str!("../../data/equipment.toml")),
+    ("cyphers.toml", include_str!("../../data/cyphers.toml")),
+    ("artifacts.toml", include_str!("../../data/artifacts.toml")),
+    ("oddities.toml", include_str!("../../data/oddities.toml")),
+    (
+        "discoveries.toml",
+        include_str!("../../data/discoveries.toml"),
+    ),
+    ("species.toml", include_str!("../../data/species.toml")),
+    ("presets.toml", include_str!("../../data/presets.toml")),
+];
+
+/// Text of a data file compiled in with the `embed-data` feature, e.g. "presets.toml"
+#[cfg(feature = "embed-data")]
+pub fn embedded_file(file: &str) -> Option<&'static str> {
+    EMBEDDED_FILES
+        .iter()
+        .find(|(name, _)| *name == file)
+        .map(|(_, content)| *content)
 }
 
-fn data_dir() -> PathBuf {
+impl DataSource {
+    /// Read one file's text, along with the path to report errors against
+    fn read(&self, file: &str) -> Result<(PathBuf, Cow<'static, str>)> {
+        match self {
+            DataSource::Dir(dir) => {
+                let path = dir.join(file);
+                match fs::read_to_string(&path) {
+                    Ok(content) => Ok((path, Cow::Owned(content))),
+                    Err(source) => Err(ChargenError::DataFile { path, source }),
+                }
+            }
+            #[cfg(feature = "embed-data")]
+            DataSource::Embedded => {
+                let path = PathBuf::from(file);
+                match embedded_file(file) {
+                    Some(content) => Ok((path, Cow::Borrowed(content))),
+                    None => Err(ChargenError::DataFile {
+                        path,
+                        source: std::io::ErrorKind::NotFound.into(),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Read and parse one TOML file
+    fn load<T: DeserializeOwned>(&self, file: &str) -> Result<T> {
+        let (path, content) = self.read(file)?;
+        toml::from_str(&content).map_err(|source| ChargenError::DataParse { path, source })
+    }
+
+    /// Read and parse one TOML file, recording where each `[[key]]` entry starts
+    fn load_entries<T: DeserializeOwned>(
+        &self,
+        file: &str,
+        key: &str,
+        sources: &mut SourceMap,
+    ) -> Result<T> {
+        let (path, content) = self.read(file)?;
+        let parsed =
+            toml::from_str(&content).map_err(|source| ChargenError::DataParse { path, source })?;
+        sources.record(file, entry_lines(&content, key));
+        Ok(parsed)
+    }
+
+    /// Read and parse a TOML file that may be absent; embedded data has no optional files
+    fn load_optional<T: DeserializeOwned>(&self, file: &str) -> Result<Option<T>> {
+        match self {
+            DataSource::Dir(dir) if !dir.join(file).exists() => Ok(None),
+            DataSource::Dir(_) => self.load(file).map(Some),
+            #[cfg(feature = "embed-data")]
+            DataSource::Embedded => Ok(None),
+        }
+    }
+}
+
+/// 1-based line of each `[[key]]` header, in file order
+fn entry_lines(content: &str, key: &str) -> Vec<usize> {
+    let Ok(doc) = toml_edit::ImDocument::parse(content) else {
+        return Vec::new();
+    };
+    doc.get(key)
+        .and_then(|item| item.as_array_of_tables())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|table| table.span())
+                .map(|span| content[..span.start].matches('\n').count() + 1)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Load every data file from a source, reporting each file name before it is read
+fn load_game_data(source: &DataSource, mut on_progress: impl FnMut(&str)) -> Result<GameData> {
+    let mut data = GameData::new();
+    let sources = &mut data.sources;
+
+    on_progress("types.toml");
+    data.types = source
+        .load_entries::<TypesData>("types.toml", "types", sources)?
+        .types;
+    on_progress("descriptors.toml");
+    data.descriptors = source
+        .load_entries::<DescriptorsData>("descriptors.toml", "descriptors", sources)?
+        .descriptors;
+    on_progress("foci.toml");
+    data.foci = source
+        .load_entries::<FociData>("foci.toml", "foci", sources)?
+        .foci;
+    on_progress("equipment.toml");
+    data.equipment = source.load::<EquipmentData>("equipment.toml")?;
+    on_progress("cyphers.toml");
+    data.cyphers = source
+        .load_entries::<CyphersData>("cyphers.toml", "cypher", sources)?
+        .cypher;
+    on_progress("artifacts.toml");
+    data.artifacts = source
+        .load_entries::<ArtifactsData>("artifacts.toml", "artifact", sources)?
+        .artifact;
+    on_progress("oddities.toml");
+    data.oddities = source
+        .load_entries::<OdditiesData>("oddities.toml", "oddity", sources)?
+        .oddity;
+    on_progress("discoveries.toml");
+    data.discoveries = source
+        .load::<DiscoveriesData>("discoveries.toml")?
+        .discovery;
+    on_progress("species.toml");
+    data.species = source
+        .load_entries::<SpeciesData>("species.toml", "species", sources)?
+        .species;
+    if let Some(skills) = source.load_optional::<SkillsData>("skills.toml")? {
+        on_progress("skills.toml");
+        data.skills = skills.skills;
+    }
+
+    Ok(data)
+}
+
+/// Read and parse one TOML file from the data directory
+fn load_toml<T: DeserializeOwned>(file: &str) -> Result<T> {
+    DataSource::Dir(data_dir()).load(file)
+}
+
+/// Default data directory: `data` next to the executable, else `./data`
+pub fn data_dir() -> PathBuf {
     // Try to find data directory relative to executable
     if let Ok(exe_path) = std::env::current_exe() {
         if let Some(exe_dir) = exe_path.parent() {
@@ -55,61 +220,30 @@ fn data_dir() -> PathBuf {
 
 /// Load descriptors from descriptors.toml
 pub fn load_descriptors() -> Result<Vec<Descriptor>> {
-    let path = data_dir().join("descriptors.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: DescriptorsData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: DescriptorsData = load_toml("descriptors.toml")?;
     Ok(data.descriptors)
 }
 
 /// Load foci from foci.toml
 pub fn load_foci() -> Result<Vec<Focus>> {
-    let path = data_dir().join("foci.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: FociData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: FociData = load_toml("foci.toml")?;
     Ok(data.foci)
 }
 
 /// Load equipment from equipment.toml
 pub fn load_equipment() -> Result<EquipmentData> {
-    let path = data_dir().join("equipment.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: EquipmentData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
-    Ok(data)
+    load_toml::<EquipmentData>("equipment.toml")
 }
 
 /// Load cyphers from cyphers.toml
 pub fn load_cyphers() -> Result<Vec<Cypher>> {
-    let path = data_dir().join("cyphers.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: CyphersData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: CyphersData = load_toml("cyphers.toml")?;
     Ok(data.cypher)
 }
 
 /// Load species from species.toml
 pub fn load_species() -> Result<Vec<Species>> {
-    let path = data_dir().join("species.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: SpeciesData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: SpeciesData = load_toml("species.toml")?;
     Ok(data.species)
 }
 
@@ -141,13 +275,7 @@ pub fn find_species<'a>(species: &'a [Species], name: &str) -> Option<&'a Specie
 
 /// Get foci suitable for a given character type
 pub fn get_suitable_foci<'a>(foci: &'a [Focus], type_name: &str) -> Vec<&'a Focus> {
-    foci.iter()
-        .filter(|f| {
-            f.suitable_types
-                .iter()
-                .any(|t| t.eq_ignore_ascii_case(type_name))
-        })
-        .collect()
+    foci.iter().filter(|f| f.suits_type(type_name)).collect()
 }
 
 /// Get weapons by category
@@ -181,12 +309,14 @@ pub fn get_cyphers_by_category<'a>(cyphers: &'a [Cypher], category: &str) -> Vec
 
 /// Roll a dice formula like "1d6", "1d6+2", "1d6+4"
 pub fn roll_level_formula(formula: &str) -> u32 {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
+    roll_level_formula_with_rng(formula, &mut rand::thread_rng())
+}
 
+/// Roll a dice formula using the given RNG
+pub fn roll_level_formula_with_rng(formula: &str, rng: &mut impl rand::Rng) -> u32 {
     if let Some(plus_pos) = formula.find('+') {
         // Handle "1d6+2" format
-        let base = roll_level_formula(&formula[..plus_pos]);
+        let base = roll_level_formula_with_rng(&formula[..plus_pos], rng);
         let bonus: u32 = formula[plus_pos + 1..].trim().parse().unwrap_or(0);
         base + bonus
     } else if formula.contains("d6") {
@@ -198,11 +328,34 @@ pub fn roll_level_formula(formula: &str) -> u32 {
     }
 }
 
+/// Random non-zero id for a cypher or artifact instance (0 marks a missing id)
+///
+/// Drawn from the thread RNG; seeded generation leaves ids for the sheet to number.
+pub fn new_instance_id() -> u64 {
+    use rand::Rng;
+    rand::thread_rng().gen_range(1..=u64::MAX)
+}
+
 /// Create a cypher instance with rolled level
 pub fn create_cypher_instance(cypher: &Cypher) -> CypherInstance {
-    let level = roll_level_formula(&cypher.level_formula);
+    CypherInstance {
+        instance_id: new_instance_id(),
+        ..create_cypher_instance_with_rng(cypher, &mut rand::thread_rng())
+    }
+}
+
+/// Create a cypher instance, rolling its level with the given RNG
+///
+/// Only the level is drawn from `rng`. The id is left at 0 for
+/// `CharacterSheet::add_cypher` to number, so seeded sheets stay reproducible.
+pub fn create_cypher_instance_with_rng(
+    cypher: &Cypher,
+    rng: &mut impl rand::Rng,
+) -> CypherInstance {
+    let level = roll_level_formula_with_rng(&cypher.level_formula, rng);
 
     CypherInstance {
+        instance_id: 0,
         name: cypher.name.clone(),
         level,
         cypher_type: cypher.cypher_type.clone(),
@@ -214,9 +367,23 @@ pub fn create_cypher_instance(cypher: &Cypher) -> CypherInstance {
 
 /// Create an artifact instance with rolled level
 pub fn create_artifact_instance(artifact: &Artifact) -> ArtifactInstance {
-    let level = roll_level_formula(&artifact.level_formula);
+    ArtifactInstance {
+        instance_id: new_instance_id(),
+        ..create_artifact_instance_with_rng(artifact, &mut rand::thread_rng())
+    }
+}
+
+/// Create an artifact instance, rolling its level with the given RNG
+///
+/// Like `create_cypher_instance_with_rng`, the id is left at 0 for the sheet to number.
+pub fn create_artifact_instance_with_rng(
+    artifact: &Artifact,
+    rng: &mut impl rand::Rng,
+) -> ArtifactInstance {
+    let level = roll_level_formula_with_rng(&artifact.level_formula, rng);
 
     ArtifactInstance {
+        instance_id: 0,
         name: artifact.name.clone(),
         level,
         depletion: artifact.depletion.clone(),
@@ -230,50 +397,64 @@ pub fn create_artifact_instance(artifact: &Artifact) -> ArtifactInstance {
 // VALIDATION FUNCTIONS
 // ==========================================
 
+/// Data files every data directory must contain
+pub const REQUIRED_DATA_FILES: [&str; 9] = [
+    "types.toml",
+    "descriptors.toml",
+    "foci.toml",
+    "equipment.toml",
+    "cyphers.toml",
+    "artifacts.toml",
+    "oddities.toml",
+    "discoveries.toml",
+    "species.toml",
+];
+
 /// Validate that all required data files exist
 pub fn validate_data_files() -> Result<()> {
-    let required_files = [
-        "types.toml",
-        "descriptors.toml",
-        "foci.toml",
-        "equipment.toml",
-        "cyphers.toml",
-        "artifacts.toml",
-        "oddities.toml",
-        "discoveries.toml",
-        "species.toml",
-    ];
-
-    for file in &required_files {
-        let path = data_dir().join(file);
-        if !path.exists() {
-            anyhow::bail!("Required data file not found: {}", path.display());
-        }
+    if let Some(path) = missing_data_files(&data_dir()).first() {
+        return Err(ChargenError::MissingDataFile(path.clone()));
     }
 
     Ok(())
 }
 
+/// Paths of the required data files missing from `dir`
+pub fn missing_data_files(dir: &Path) -> Vec<PathBuf> {
+    REQUIRED_DATA_FILES
+        .iter()
+        .map(|file| dir.join(file))
+        .filter(|path| !path.exists())
+        .collect()
+}
+
 /// Validate loaded game data
 pub fn validate_game_data(data: &GameData) -> Result<()> {
     // Check that we have data
     if data.types.is_empty() {
-        anyhow::bail!("No character types loaded");
+        return Err(ChargenError::InvalidData(
+            "No character types loaded".to_string(),
+        ));
     }
     if data.descriptors.is_empty() {
-        anyhow::bail!("No descriptors loaded");
+        return Err(ChargenError::InvalidData(
+            "No descriptors loaded".to_string(),
+        ));
     }
     if data.foci.is_empty() {
-        anyhow::bail!("No foci loaded");
+        return Err(ChargenError::InvalidData("No foci loaded".to_string()));
     }
     if data.cyphers.is_empty() {
-        anyhow::bail!("No cyphers loaded");
+        return Err(ChargenError::InvalidData("No cyphers loaded".to_string()));
     }
 
     // Validate character types have required fields
     for char_type in &data.types {
         if char_type.tier_abilities.is_empty() {
-            anyhow::bail!("Character type '{}' has no tier abilities", char_type.name);
+            return Err(ChargenError::InvalidData(format!(
+                "Character type '{}' has no tier abilities",
+                char_type.name
+            )));
         }
     }
 
@@ -286,6 +467,7 @@ pub fn validate_game_data(data: &GameData) -> Result<()> {
 
 /// Get a summary of loaded data
 pub fn data_summary(data: &GameData) -> String {
+    let counts = data.counts();
     format!(
         "Loaded Game Data:\n\
          - {} character types\n\
@@ -298,52 +480,34 @@ pub fn data_summary(data: &GameData) -> String {
          - {} oddities\n\
          - {} discoveries\n\
          - {} species options",
-        data.types.len(),
-        data.descriptors.len(),
-        data.foci.len(),
-        data.equipment.weapons.len(),
-        data.equipment.armor.len(),
-        data.cyphers.len(),
-        data.artifacts.len(),
-        data.oddities.len(),
-        data.discoveries.len(),
-        data.species.len()
+        counts.types,
+        counts.descriptors,
+        counts.foci,
+        counts.weapons,
+        counts.armor,
+        counts.cyphers,
+        counts.artifacts,
+        counts.oddities,
+        counts.discoveries,
+        counts.species
     )
 }
 
 /// Load artifacts from artifacts.toml
 pub fn load_artifacts() -> Result<Vec<Artifact>> {
-    let path = data_dir().join("artifacts.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: ArtifactsData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: ArtifactsData = load_toml("artifacts.toml")?;
     Ok(data.artifact)
 }
 
 /// Load oddities from oddities.toml
 pub fn load_oddities() -> Result<Vec<Oddity>> {
-    let path = data_dir().join("oddities.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: OdditiesData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: OdditiesData = load_toml("oddities.toml")?;
     Ok(data.oddity)
 }
 
 /// Load discoveries from discoveries.toml
 pub fn load_discoveries() -> Result<Vec<Discovery>> {
-    let path = data_dir().join("discoveries.toml");
-    let content =
-        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let data: DiscoveriesData =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
-
+    let data: DiscoveriesData = load_toml("discoveries.toml")?;
     Ok(data.discovery)
 }
 
@@ -356,20 +520,24 @@ pub fn validate_all_comprehensive(data: &GameData) -> Result<ValidationReport> {
     let mut report = ValidationReport::new();
 
     // Run all validation checks
-    validate_types(&data.types, &mut report);
+    validate_types(&data.types, &data.sources, &mut report);
+    validate_type_equipment(&data.types, data, &mut report);
     validate_descriptors(&data.descriptors, data, &mut report);
     validate_foci(&data.foci, data, &mut report);
-    validate_cyphers(&data.cyphers, &mut report);
-    validate_artifacts(&data.artifacts, &mut report);
-    validate_oddities(&data.oddities, &mut report);
+    validate_cyphers(&data.cyphers, &data.sources, &mut report);
+    validate_artifacts(&data.artifacts, &data.sources, &mut report);
+    validate_oddities(&data.oddities, &data.sources, &mut report);
     validate_species(&data.species, data, &mut report);
+    validate_ability_costs(data, &mut report);
+    validate_skill_references(data, &mut report);
     validate_cross_references(data, &mut report);
 
+    report.sort();
     Ok(report)
 }
 
 /// Validation report structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub struct ValidationReport {
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
@@ -401,6 +569,16 @@ impl ValidationReport {
         !self.errors.is_empty()
     }
 
+    /// Order messages within each severity so output is stable between runs
+    ///
+    /// Located messages sort by file, then line number, so "foci.toml:141"
+    /// comes before "foci.toml:1012".
+    pub fn sort(&mut self) {
+        for messages in [&mut self.errors, &mut self.warnings, &mut self.info] {
+            messages.sort_by(|a, b| message_sort_key(a).cmp(&message_sort_key(b)));
+        }
+    }
+
     pub fn is_clean(&self) -> bool {
         self.errors.is_empty() && self.warnings.is_empty()
     }
@@ -415,91 +593,133 @@ impl ValidationReport {
     }
 }
 
+/// (file, line, message) for a "file.toml:42: message"; unlocated messages sort as a whole
+fn message_sort_key(message: &str) -> (&str, Option<usize>, &str) {
+    let Some((at, rest)) = message.split_once(": ") else {
+        return (message, None, "");
+    };
+    let (file, line) = match at.rsplit_once(':') {
+        Some((file, line)) => match line.parse() {
+            Ok(line) => (file, Some(line)),
+            Err(_) => (at, None),
+        },
+        None => (at, None),
+    };
+    if file.ends_with(".toml") {
+        (file, line, rest)
+    } else {
+        (message, None, "")
+    }
+}
+
 // ==========================================
 // TYPE VALIDATION
 // ==========================================
 
-fn validate_types(types: &[CharacterType], report: &mut ValidationReport) {
+fn validate_types(types: &[CharacterType], sources: &SourceMap, report: &mut ValidationReport) {
     report.add_info(format!("Validating {} character types...", types.len()));
 
     let mut names_seen = std::collections::HashSet::new();
 
-    for char_type in types {
+    for (i, char_type) in types.iter().enumerate() {
+        let at = sources.locate("types.toml", i);
+
         // Check for duplicate names
         if !names_seen.insert(char_type.name.to_lowercase()) {
             report.add_error(format!(
-                "Duplicate character type name: '{}'",
-                char_type.name
+                "{}: Duplicate character type name: '{}'",
+                at, char_type.name
             ));
         }
 
         // Validate stat pools are positive
         if char_type.stat_pools.might == 0 {
-            report.add_warning(format!("Type '{}' has 0 Might pool", char_type.name));
+            report.add_warning(format!(
+                "{}: Type '{}' has 0 Might pool",
+                at, char_type.name
+            ));
         }
         if char_type.stat_pools.speed == 0 {
-            report.add_warning(format!("Type '{}' has 0 Speed pool", char_type.name));
+            report.add_warning(format!(
+                "{}: Type '{}' has 0 Speed pool",
+                at, char_type.name
+            ));
         }
         if char_type.stat_pools.intellect == 0 {
-            report.add_warning(format!("Type '{}' has 0 Intellect pool", char_type.name));
+            report.add_warning(format!(
+                "{}: Type '{}' has 0 Intellect pool",
+                at, char_type.name
+            ));
         }
 
         // Validate bonus points (typically 6)
         if char_type.stat_pools.bonus_points != 6 {
             report.add_warning(format!(
-                "Type '{}' has {} bonus points (expected 6)",
-                char_type.name, char_type.stat_pools.bonus_points
+                "{}: Type '{}' has {} bonus points (expected 6)",
+                at, char_type.name, char_type.stat_pools.bonus_points
             ));
         }
 
         // Validate edge values (typically 0-3)
         if char_type.edge.might > 3 {
             report.add_warning(format!(
-                "Type '{}' has unusually high Might edge: {}",
-                char_type.name, char_type.edge.might
+                "{}: Type '{}' has unusually high Might edge: {}",
+                at, char_type.name, char_type.edge.might
             ));
         }
         if char_type.edge.speed > 3 {
             report.add_warning(format!(
-                "Type '{}' has unusually high Speed edge: {}",
-                char_type.name, char_type.edge.speed
+                "{}: Type '{}' has unusually high Speed edge: {}",
+                at, char_type.name, char_type.edge.speed
             ));
         }
         if char_type.edge.intellect > 3 {
             report.add_warning(format!(
-                "Type '{}' has unusually high Intellect edge: {}",
-                char_type.name, char_type.edge.intellect
+                "{}: Type '{}' has unusually high Intellect edge: {}",
+                at, char_type.name, char_type.edge.intellect
             ));
         }
 
         // Validate starting tier
         if char_type.starting_tier.effort == 0 {
-            report.add_error(format!("Type '{}' has 0 starting effort", char_type.name));
+            report.add_error(format!(
+                "{}: Type '{}' has 0 starting effort",
+                at, char_type.name
+            ));
         }
         if char_type.starting_tier.cypher_limit == 0 {
-            report.add_error(format!("Type '{}' has 0 cypher limit", char_type.name));
+            report.add_error(format!(
+                "{}: Type '{}' has 0 cypher limit",
+                at, char_type.name
+            ));
         }
 
         // Validate tier abilities exist
         if char_type.tier_abilities.is_empty() {
-            report.add_error(format!("Type '{}' has no tier abilities", char_type.name));
+            report.add_error(format!(
+                "{}: Type '{}' has no tier abilities",
+                at, char_type.name
+            ));
         }
 
         // Check tier 1 abilities exist and have correct count
         if let Some(tier_1) = char_type.tier_abilities.iter().find(|ta| ta.tier == 1) {
             if tier_1.abilities.is_empty() {
-                report.add_error(format!("Type '{}' has no tier 1 abilities", char_type.name));
+                report.add_error(format!(
+                    "{}: Type '{}' has no tier 1 abilities",
+                    at, char_type.name
+                ));
             }
             if tier_1.count == 0 {
                 report.add_error(format!(
-                    "Type '{}' requires 0 tier 1 abilities (should be >= 1)",
-                    char_type.name
+                    "{}: Type '{}' requires 0 tier 1 abilities (should be >= 1)",
+                    at, char_type.name
                 ));
             }
         } else {
             report.add_error(format!(
-                "Type '{}' missing tier 1 abilities",
-                char_type.name
+                "{}: Type '{}' missing tier 1 abilities",
+                at, char_type.name
             ));
         }
     }
@@ -509,6 +729,75 @@ fn validate_types(types: &[CharacterType], report: &mut ValidationReport) {
 // DESCRIPTOR VALIDATION
 // ==========================================
 
+/// Warn when a type's starting weapons or armor are missing from the equipment data
+fn validate_type_equipment(
+    types: &[CharacterType],
+    data: &GameData,
+    report: &mut ValidationReport,
+) {
+    for (i, char_type) in types.iter().enumerate() {
+        let at = data.sources.locate("types.toml", i);
+
+        for weapon_name in &char_type.equipment.weapons {
+            if !data
+                .equipment
+                .weapons
+                .iter()
+                .any(|w| w.name.eq_ignore_ascii_case(weapon_name))
+            {
+                report.add_warning(format!(
+                    "{}: Type '{}' references non-existent weapon: '{}'",
+                    at, char_type.name, weapon_name
+                ));
+            }
+        }
+
+        if let Some(armor_name) = &char_type.equipment.armor {
+            if !armor_name.is_empty()
+                && !data
+                    .equipment
+                    .armor
+                    .iter()
+                    .any(|a| a.name.eq_ignore_ascii_case(armor_name))
+            {
+                report.add_warning(format!(
+                    "{}: Type '{}' references non-existent armor: '{}'",
+                    at, char_type.name, armor_name
+                ));
+            }
+        }
+
+        for (i, option) in char_type.equipment.options.iter().enumerate() {
+            if option.is_empty() {
+                report.add_warning(format!(
+                    "{}: Type '{}' has an empty equipment option ({})",
+                    at,
+                    char_type.name,
+                    i + 1
+                ));
+            }
+            for item_name in option {
+                let exists = data
+                    .equipment
+                    .weapons
+                    .iter()
+                    .any(|w| w.name.eq_ignore_ascii_case(item_name))
+                    || data
+                        .equipment
+                        .gear
+                        .iter()
+                        .any(|g| g.name.eq_ignore_ascii_case(item_name));
+                if !exists {
+                    report.add_warning(format!(
+                        "{}: Type '{}' equipment option references non-existent item: '{}'",
+                        at, char_type.name, item_name
+                    ));
+                }
+            }
+        }
+    }
+}
+
 fn validate_descriptors(
     descriptors: &[Descriptor],
     data: &GameData,
@@ -518,29 +807,34 @@ fn validate_descriptors(
 
     let mut names_seen = std::collections::HashSet::new();
 
-    for descriptor in descriptors {
+    for (i, descriptor) in descriptors.iter().enumerate() {
+        let at = data.sources.locate("descriptors.toml", i);
+
         // Check for duplicate names
         if !names_seen.insert(descriptor.name.to_lowercase()) {
-            report.add_error(format!("Duplicate descriptor name: '{}'", descriptor.name));
+            report.add_error(format!(
+                "{}: Duplicate descriptor name: '{}'",
+                at, descriptor.name
+            ));
         }
 
         // Validate stat modifiers are reasonable (-6 to +6)
         if descriptor.stat_modifiers.might.abs() > 6 {
             report.add_warning(format!(
-                "Descriptor '{}' has extreme Might modifier: {}",
-                descriptor.name, descriptor.stat_modifiers.might
+                "{}: Descriptor '{}' has extreme Might modifier: {}",
+                at, descriptor.name, descriptor.stat_modifiers.might
             ));
         }
         if descriptor.stat_modifiers.speed.abs() > 6 {
             report.add_warning(format!(
-                "Descriptor '{}' has extreme Speed modifier: {}",
-                descriptor.name, descriptor.stat_modifiers.speed
+                "{}: Descriptor '{}' has extreme Speed modifier: {}",
+                at, descriptor.name, descriptor.stat_modifiers.speed
             ));
         }
         if descriptor.stat_modifiers.intellect.abs() > 6 {
             report.add_warning(format!(
-                "Descriptor '{}' has extreme Intellect modifier: {}",
-                descriptor.name, descriptor.stat_modifiers.intellect
+                "{}: Descriptor '{}' has extreme Intellect modifier: {}",
+                at, descriptor.name, descriptor.stat_modifiers.intellect
             ));
         }
 
@@ -553,8 +847,8 @@ fn validate_descriptors(
                 .any(|w| w.name == *weapon_name)
             {
                 report.add_warning(format!(
-                    "Descriptor '{}' references non-existent weapon: '{}'",
-                    descriptor.name, weapon_name
+                    "{}: Descriptor '{}' references non-existent weapon: '{}'",
+                    at, descriptor.name, weapon_name
                 ));
             }
         }
@@ -562,11 +856,21 @@ fn validate_descriptors(
         for armor_name in &descriptor.equipment.armor {
             if !data.equipment.armor.iter().any(|a| a.name == *armor_name) {
                 report.add_warning(format!(
-                    "Descriptor '{}' references non-existent armor: '{}'",
-                    descriptor.name, armor_name
+                    "{}: Descriptor '{}' references non-existent armor: '{}'",
+                    at, descriptor.name, armor_name
                 ));
             }
         }
+
+        validate_granted_items(
+            &at,
+            "Descriptor",
+            &descriptor.name,
+            &descriptor.equipment.cyphers,
+            &descriptor.equipment.oddities,
+            data,
+            report,
+        );
     }
 }
 
@@ -580,26 +884,43 @@ fn validate_foci(foci: &[Focus], data: &GameData, report: &mut ValidationReport)
     let mut names_seen = std::collections::HashSet::new();
     let valid_type_names: Vec<String> = data.types.iter().map(|t| t.name.to_lowercase()).collect();
 
-    for focus in foci {
+    for (i, focus) in foci.iter().enumerate() {
+        let at = data.sources.locate("foci.toml", i);
+
         // Check for duplicate names
         if !names_seen.insert(focus.name.to_lowercase()) {
-            report.add_error(format!("Duplicate focus name: '{}'", focus.name));
+            report.add_error(format!("{}: Duplicate focus name: '{}'", at, focus.name));
         }
 
         // Validate suitable_types reference actual types
         if focus.suitable_types.is_empty() {
-            report.add_warning(format!("Focus '{}' has no suitable types", focus.name));
+            report.add_warning(format!(
+                "{}: Focus '{}' has no suitable types",
+                at, focus.name
+            ));
         }
 
+        // "Any" and book notes such as "Nano (best)" are allowed
         for type_name in &focus.suitable_types {
-            if !valid_type_names.contains(&type_name.to_lowercase()) {
+            let Some(name) = suitable_type_name(type_name) else {
+                continue;
+            };
+            if !valid_type_names.contains(&name.to_lowercase()) {
                 report.add_error(format!(
-                    "Focus '{}' references non-existent type: '{}'",
-                    focus.name, type_name
+                    "{}: Focus '{}' references non-existent type: '{}'",
+                    at, focus.name, type_name
                 ));
             }
         }
 
+        // The first connection becomes the sheet's focus link
+        if focus.connections.is_empty() {
+            report.add_warning(format!(
+                "{}: Focus '{}' has no connections; characters will start without a focus link",
+                at, focus.name
+            ));
+        }
+
         // Check equipment references exist
         for equipment_name in &focus.equipment {
             let exists = data
@@ -620,11 +941,58 @@ fn validate_foci(foci: &[Focus], data: &GameData, report: &mut ValidationReport)
 
             if !exists {
                 report.add_warning(format!(
-                    "Focus '{}' references non-existent equipment: '{}'",
-                    focus.name, equipment_name
+                    "{}: Focus '{}' references non-existent equipment: '{}'",
+                    at, focus.name, equipment_name
                 ));
             }
         }
+
+        validate_granted_items(
+            &at,
+            "Focus",
+            &focus.name,
+            &focus.cyphers,
+            &focus.oddities,
+            data,
+            report,
+        );
+    }
+}
+
+/// Check that cyphers and oddities granted by name exist in the loaded data
+fn validate_granted_items(
+    at: &str,
+    kind: &str,
+    owner: &str,
+    cyphers: &[String],
+    oddities: &[String],
+    data: &GameData,
+    report: &mut ValidationReport,
+) {
+    for name in cyphers {
+        if !data
+            .cyphers
+            .iter()
+            .any(|c| c.name.eq_ignore_ascii_case(name))
+        {
+            report.add_error(format!(
+                "{}: {} '{}' grants non-existent cypher: '{}'",
+                at, kind, owner, name
+            ));
+        }
+    }
+
+    for name in oddities {
+        if !data
+            .oddities
+            .iter()
+            .any(|o| o.name.eq_ignore_ascii_case(name))
+        {
+            report.add_error(format!(
+                "{}: {} '{}' grants non-existent oddity: '{}'",
+                at, kind, owner, name
+            ));
+        }
     }
 }
 
@@ -632,31 +1000,33 @@ fn validate_foci(foci: &[Focus], data: &GameData, report: &mut ValidationReport)
 // CYPHER VALIDATION
 // ==========================================
 
-fn validate_cyphers(cyphers: &[Cypher], report: &mut ValidationReport) {
+fn validate_cyphers(cyphers: &[Cypher], sources: &SourceMap, report: &mut ValidationReport) {
     report.add_info(format!("Validating {} cyphers...", cyphers.len()));
 
     let mut names_seen = std::collections::HashSet::new();
 
-    for cypher in cyphers {
+    for (i, cypher) in cyphers.iter().enumerate() {
+        let at = sources.locate("cyphers.toml", i);
+
         // Check for duplicate names
         if !names_seen.insert(cypher.name.to_lowercase()) {
-            report.add_error(format!("Duplicate cypher name: '{}'", cypher.name));
+            report.add_error(format!("{}: Duplicate cypher name: '{}'", at, cypher.name));
         }
 
         // Validate level formula
         if !is_valid_level_formula(&cypher.level_formula) {
             report.add_error(format!(
-                "Cypher '{}' has invalid level formula: '{}'",
-                cypher.name, cypher.level_formula
+                "{}: Cypher '{}' has invalid level formula: '{}'",
+                at, cypher.name, cypher.level_formula
             ));
         }
 
         // Check required fields are not empty
         if cypher.effect.is_empty() {
-            report.add_error(format!("Cypher '{}' has empty effect", cypher.name));
+            report.add_error(format!("{}: Cypher '{}' has empty effect", at, cypher.name));
         }
         if cypher.form.is_empty() {
-            report.add_error(format!("Cypher '{}' has empty form", cypher.name));
+            report.add_error(format!("{}: Cypher '{}' has empty form", at, cypher.name));
         }
     }
 }
@@ -665,40 +1035,77 @@ fn validate_cyphers(cyphers: &[Cypher], report: &mut ValidationReport) {
 // ARTIFACT VALIDATION
 // ==========================================
 
-fn validate_artifacts(artifacts: &[Artifact], report: &mut ValidationReport) {
+/// Recognized artifact `form_type` values; extend when new forms appear in the data
+pub const ARTIFACT_FORM_TYPES: &[&str] = &[
+    "Usable",
+    "Wearable",
+    "Handheld",
+    "Installation",
+    "Consumable",
+];
+
+fn validate_artifacts(artifacts: &[Artifact], sources: &SourceMap, report: &mut ValidationReport) {
     report.add_info(format!("Validating {} artifacts...", artifacts.len()));
 
     let mut ids_seen = std::collections::HashSet::new();
     let mut names_seen = std::collections::HashSet::new();
 
-    for artifact in artifacts {
+    for (i, artifact) in artifacts.iter().enumerate() {
+        let at = sources.locate("artifacts.toml", i);
+
         // Check for duplicate IDs
         if !ids_seen.insert(artifact.id.to_lowercase()) {
-            report.add_error(format!("Duplicate artifact ID: '{}'", artifact.id));
+            report.add_error(format!("{}: Duplicate artifact ID: '{}'", at, artifact.id));
         }
 
         // Check for duplicate names
         if !names_seen.insert(artifact.name.to_lowercase()) {
-            report.add_error(format!("Duplicate artifact name: '{}'", artifact.name));
+            report.add_error(format!(
+                "{}: Duplicate artifact name: '{}'",
+                at, artifact.name
+            ));
         }
 
         // Validate level formula
         if !is_valid_level_formula(&artifact.level_formula) {
             report.add_error(format!(
-                "Artifact '{}' has invalid level formula: '{}'",
-                artifact.name, artifact.level_formula
+                "{}: Artifact '{}' has invalid level formula: '{}'",
+                at, artifact.name, artifact.level_formula
             ));
         }
 
         // Check required fields are not empty
         if artifact.effect.is_empty() {
-            report.add_error(format!("Artifact '{}' has empty effect", artifact.name));
+            report.add_error(format!(
+                "{}: Artifact '{}' has empty effect",
+                at, artifact.name
+            ));
         }
         if artifact.form.is_empty() {
-            report.add_error(format!("Artifact '{}' has empty form", artifact.name));
+            report.add_error(format!(
+                "{}: Artifact '{}' has empty form",
+                at, artifact.name
+            ));
         }
         if artifact.depletion.is_empty() {
-            report.add_error(format!("Artifact '{}' has empty depletion", artifact.name));
+            report.add_error(format!(
+                "{}: Artifact '{}' has empty depletion",
+                at, artifact.name
+            ));
+        }
+
+        // Unknown form types are usually typos
+        if !ARTIFACT_FORM_TYPES
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(artifact.form_type.trim()))
+        {
+            report.add_warning(format!(
+                "{}: Artifact '{}' has unrecognized form_type: '{}' (expected one of: {})",
+                at,
+                artifact.name,
+                artifact.form_type,
+                ARTIFACT_FORM_TYPES.join(", ")
+            ));
         }
     }
 }
@@ -707,27 +1114,44 @@ fn validate_artifacts(artifacts: &[Artifact], report: &mut ValidationReport) {
 // ODDITY VALIDATION
 // ==========================================
 
-fn validate_oddities(oddities: &[Oddity], report: &mut ValidationReport) {
+fn validate_oddities(oddities: &[Oddity], sources: &SourceMap, report: &mut ValidationReport) {
     report.add_info(format!("Validating {} oddities...", oddities.len()));
 
     let mut ids_seen = std::collections::HashSet::new();
+    let mut names_seen = std::collections::HashSet::new();
+
+    for (i, oddity) in oddities.iter().enumerate() {
+        let at = sources.locate("oddities.toml", i);
 
-    for oddity in oddities {
         // Check for duplicate IDs
         if !ids_seen.insert(oddity.id.to_lowercase()) {
-            report.add_error(format!("Duplicate oddity ID: '{}'", oddity.id));
+            report.add_error(format!("{}: Duplicate oddity ID: '{}'", at, oddity.id));
+        }
+
+        // Check for duplicate names (selection compares by name)
+        if !oddity.name.is_empty() && !names_seen.insert(oddity.name.to_lowercase()) {
+            report.add_warning(format!("{}: Duplicate oddity name: '{}'", at, oddity.name));
         }
 
         // Check required fields are not empty
+        if oddity.name.trim().is_empty() {
+            report.add_error(format!("{}: Oddity '{}' has empty name", at, oddity.id));
+        }
+
         if oddity.description.is_empty() {
-            report.add_error(format!("Oddity '{}' has empty description", oddity.name));
+            report.add_error(format!(
+                "{}: Oddity '{}' has empty description",
+                at, oddity.name
+            ));
         }
 
-        // Validate value is reasonable (0-100 shins typically)
-        if oddity.value_shins > 100 {
+        // Validate value is reasonable (1-100 shins typically)
+        if oddity.value_shins == 0 {
+            report.add_warning(format!("{}: Oddity '{}' has zero value", at, oddity.name));
+        } else if oddity.value_shins > 100 {
             report.add_warning(format!(
-                "Oddity '{}' has unusually high value: {} shins",
-                oddity.name, oddity.value_shins
+                "{}: Oddity '{}' has unusually high value: {} shins",
+                at, oddity.name, oddity.value_shins
             ));
         }
     }
@@ -737,35 +1161,54 @@ fn validate_oddities(oddities: &[Oddity], report: &mut ValidationReport) {
 // SPECIES VALIDATION
 // ==========================================
 
+/// Largest species bonus point override that still leaves allocation meaningful
+const MAX_SPECIES_BONUS_POINTS: u32 = 8;
+
 fn validate_species(species: &[Species], data: &GameData, report: &mut ValidationReport) {
     report.add_info(format!("Validating {} species...", species.len()));
 
     let mut names_seen = std::collections::HashSet::new();
 
-    for sp in species {
+    for (i, sp) in species.iter().enumerate() {
+        let at = data.sources.locate("species.toml", i);
+
         // Check for duplicate names
         if !names_seen.insert(sp.name.to_lowercase()) {
-            report.add_error(format!("Duplicate species name: '{}'", sp.name));
+            report.add_error(format!("{}: Duplicate species name: '{}'", at, sp.name));
         }
 
         // Validate stat modifiers are reasonable
         if sp.stat_modifiers.might.abs() > 6 {
             report.add_warning(format!(
-                "Species '{}' has extreme Might modifier: {}",
-                sp.name, sp.stat_modifiers.might
+                "{}: Species '{}' has extreme Might modifier: {}",
+                at, sp.name, sp.stat_modifiers.might
             ));
         }
         if sp.stat_modifiers.speed.abs() > 6 {
             report.add_warning(format!(
-                "Species '{}' has extreme Speed modifier: {}",
-                sp.name, sp.stat_modifiers.speed
+                "{}: Species '{}' has extreme Speed modifier: {}",
+                at, sp.name, sp.stat_modifiers.speed
             ));
         }
         if sp.stat_modifiers.intellect.abs() > 6 {
             report.add_warning(format!(
-                "Species '{}' has extreme Intellect modifier: {}",
-                sp.name, sp.stat_modifiers.intellect
+                "{}: Species '{}' has extreme Intellect modifier: {}",
+                at, sp.name, sp.stat_modifiers.intellect
+            ));
+        }
+
+        // Bonus point override replaces the type's total, so it must leave room to allocate
+        if let Some(points) = sp.stat_modifiers.initial_bonus_points {
+            report.add_info(format!(
+                "{}: Species '{}' overrides starting bonus points to {}",
+                at, sp.name, points
             ));
+            if points == 0 || points > MAX_SPECIES_BONUS_POINTS {
+                report.add_warning(format!(
+                    "{}: Species '{}' has unusual initial_bonus_points: {} (expected 1-{})",
+                    at, sp.name, points, MAX_SPECIES_BONUS_POINTS
+                ));
+            }
         }
 
         // Check equipment references exist
@@ -776,14 +1219,163 @@ fn validate_species(species: &[Species], data: &GameData, report: &mut Validatio
 
             if !exists {
                 report.add_warning(format!(
-                    "Species '{}' references non-existent equipment: '{}'",
-                    sp.name, item_name
+                    "{}: Species '{}' references non-existent equipment: '{}'",
+                    at, sp.name, item_name
                 ));
             }
         }
     }
 }
 
+// ==========================================
+// ABILITY COST VALIDATION
+// ==========================================
+
+/// Warn about ability costs that `parse_ability_cost` can't read, so pool spending can't use them
+fn validate_ability_costs(data: &GameData, report: &mut ValidationReport) {
+    let mut check = |owner: String, ability: &str, cost: &str| {
+        if crate::character::parse_ability_cost(cost).is_none() {
+            report.add_warning(format!(
+                "{} ability '{}' has an unparseable cost: '{}'",
+                owner, ability, cost
+            ));
+        }
+    };
+
+    for (i, char_type) in data.types.iter().enumerate() {
+        for ability in char_type.tier_abilities.iter().flat_map(|t| &t.abilities) {
+            check(
+                format!(
+                    "{}: Type '{}'",
+                    data.sources.locate("types.toml", i),
+                    char_type.name
+                ),
+                &ability.name,
+                &ability.cost,
+            );
+        }
+    }
+    for (i, focus) in data.foci.iter().enumerate() {
+        let ability = &focus.tier_1_ability;
+        check(
+            format!(
+                "{}: Focus '{}'",
+                data.sources.locate("foci.toml", i),
+                focus.name
+            ),
+            &ability.name,
+            &ability.cost,
+        );
+    }
+    for (i, sp) in data.species.iter().enumerate() {
+        for ability in &sp.abilities {
+            check(
+                format!(
+                    "{}: Species '{}'",
+                    data.sources.locate("species.toml", i),
+                    sp.name
+                ),
+                &ability.name,
+                &ability.cost,
+            );
+        }
+    }
+}
+
+// ==========================================
+// SKILL VALIDATION
+// ==========================================
+
+/// Warn about type, descriptor and species skills missing from skills.toml
+///
+/// Does nothing without a skills.toml, so skills stay free-form. Each warning
+/// suggests the closest canonical name when one is near enough to be a typo.
+fn validate_skill_references(data: &GameData, report: &mut ValidationReport) {
+    if data.skills.is_empty() {
+        return;
+    }
+
+    let mut check = |owner: String, skill: &str| {
+        if data.skills.iter().any(|s| s.eq_ignore_ascii_case(skill)) {
+            return;
+        }
+        report.add_warning(match closest_name(skill, &data.skills) {
+            Some(suggestion) => format!(
+                "{} skill '{}' is not in skills.toml (did you mean '{}'?)",
+                owner, skill, suggestion
+            ),
+            None => format!("{} skill '{}' is not in skills.toml", owner, skill),
+        });
+    };
+
+    for (i, char_type) in data.types.iter().enumerate() {
+        let at = data.sources.locate("types.toml", i);
+        let skills = &char_type.skills;
+        for skill in skills
+            .trained
+            .iter()
+            .chain(&skills.specialized)
+            .chain(&skills.inabilities)
+        {
+            check(format!("{}: Type '{}'", at, char_type.name), skill);
+        }
+    }
+    for (i, descriptor) in data.descriptors.iter().enumerate() {
+        let at = data.sources.locate("descriptors.toml", i);
+        let skills = &descriptor.skills;
+        for skill in skills
+            .trained
+            .iter()
+            .chain(&skills.specialized)
+            .chain(&skills.inabilities.hindered)
+        {
+            check(format!("{}: Descriptor '{}'", at, descriptor.name), skill);
+        }
+    }
+    for (i, sp) in data.species.iter().enumerate() {
+        let at = data.sources.locate("species.toml", i);
+        let skills = &sp.skills;
+        for skill in skills
+            .trained
+            .iter()
+            .chain(&skills.specialized)
+            .chain(&skills.hindered)
+        {
+            check(format!("{}: Species '{}'", at, sp.name), skill);
+        }
+    }
+}
+
+/// The candidate closest to `name` by edit distance, if within a third of its length (at least 2)
+fn closest_name<'a>(name: &str, candidates: &'a [String]) -> Option<&'a str> {
+    let name = name.to_lowercase();
+    let limit = (name.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|c| (edit_distance(&name, &c.to_lowercase()), c))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c.as_str())
+}
+
+/// Levenshtein distance between two strings, counted in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
 // ==========================================
 // CROSS-REFERENCE VALIDATION
 // ==========================================
@@ -792,19 +1384,48 @@ fn validate_cross_references(data: &GameData, report: &mut ValidationReport) {
     report.add_info("Validating cross-references...".to_string());
 
     // Check that each type has at least one suitable focus
-    for char_type in &data.types {
+    for (i, char_type) in data.types.iter().enumerate() {
         let suitable_foci_count = data
             .foci
             .iter()
-            .filter(|f| {
-                f.suitable_types
-                    .iter()
-                    .any(|t| t.eq_ignore_ascii_case(&char_type.name))
-            })
+            .filter(|f| f.suits_type(&char_type.name))
             .count();
 
         if suitable_foci_count == 0 {
-            report.add_warning(format!("Type '{}' has no suitable foci", char_type.name));
+            report.add_warning(format!(
+                "{}: Type '{}' has no suitable foci",
+                data.sources.locate("types.toml", i),
+                char_type.name
+            ));
+        }
+    }
+
+    // And that each focus is usable by at least one loaded type
+    for (i, focus) in data.foci.iter().enumerate() {
+        let usable = data.types.iter().any(|t| focus.suits_type(&t.name));
+
+        // Foci with no suitable types at all are already reported by validate_foci
+        if !usable && !focus.suitable_types.is_empty() {
+            report.add_warning(format!(
+                "{}: Focus '{}' is suitable for no loaded type",
+                data.sources.locate("foci.toml", i),
+                focus.name
+            ));
+        }
+    }
+
+    // Descriptors and species share one selection list and are told apart by name
+    for (i, descriptor) in data.descriptors.iter().enumerate() {
+        if data
+            .species
+            .iter()
+            .any(|s| s.name.eq_ignore_ascii_case(&descriptor.name))
+        {
+            report.add_error(format!(
+                "{}: Name '{}' is used by both a descriptor and a species",
+                data.sources.locate("descriptors.toml", i),
+                descriptor.name
+            ));
         }
     }
 }
@@ -854,9 +1475,8 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_find_functions() {
-        let types = vec![CharacterType {
+    fn create_test_type() -> CharacterType {
+        CharacterType {
             name: "Glaive".to_string(),
             source: "Discovery".to_string(),
             tagline: "Warrior".to_string(),
@@ -885,6 +1505,7 @@ mod tests {
                 explorer_pack: false,
                 shins: 0,
                 other: vec![],
+                options: vec![],
             },
             skills: TypeSkills {
                 trained: vec![],
@@ -894,7 +1515,12 @@ mod tests {
             special_abilities: vec![],
             tier_abilities: vec![],
             tier_progression: vec![],
-        }];
+        }
+    }
+
+    #[test]
+    fn test_find_functions() {
+        let types = vec![create_test_type()];
 
         let found = find_type(&types, "glaive");
         assert!(found.is_some());
@@ -904,6 +1530,44 @@ mod tests {
         assert!(not_found.is_none());
     }
 
+    #[test]
+    fn test_cypher_instance_id_round_trips() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let cypher = Cypher {
+            name: "Detonation".to_string(),
+            level_formula: "1d6+2".to_string(),
+            cypher_type: "Manifest".to_string(),
+            category: "Attack".to_string(),
+            effect: String::new(),
+            form: String::new(),
+            duration: String::new(),
+        };
+
+        // Seeded instances draw only their level and leave the id to the sheet
+        let mut rng = StdRng::seed_from_u64(7);
+        let seeded = create_cypher_instance_with_rng(&cypher, &mut rng);
+        let mut expected = StdRng::seed_from_u64(7);
+        assert_eq!(
+            seeded.level,
+            roll_level_formula_with_rng("1d6+2", &mut expected)
+        );
+        assert_eq!(rng.gen::<u64>(), expected.gen::<u64>());
+        assert_eq!(seeded.instance_id, 0);
+
+        let instance = create_cypher_instance(&cypher);
+        assert_ne!(instance.instance_id, 0);
+
+        let json = serde_json::to_string(&instance).unwrap();
+        let parsed: CypherInstance = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.instance_id, instance.instance_id);
+
+        // Sheets saved before ids existed load with the missing-id marker
+        let legacy = json.replace(&format!("\"instance_id\":{},", instance.instance_id), "");
+        let parsed: CypherInstance = serde_json::from_str(&legacy).unwrap();
+        assert_eq!(parsed.instance_id, 0);
+    }
+
     #[test]
     fn test_get_suitable_foci() {
         let foci = vec![
@@ -947,4 +1611,495 @@ mod tests {
         assert_eq!(arkus_foci.len(), 1);
         assert_eq!(arkus_foci[0].name, "Leads");
     }
+
+    #[test]
+    fn test_suitable_types_any_and_notes() {
+        let mut data = GameData::new();
+        data.types.push(create_test_type());
+        data.foci = vec![
+            Focus {
+                name: "Controls Gravity".to_string(),
+                suitable_types: vec!["Any".to_string()],
+                ..Default::default()
+            },
+            Focus {
+                name: "Crafts Illusions".to_string(),
+                suitable_types: vec!["Glaive (best)".to_string(), "rare Glaive".to_string()],
+                ..Default::default()
+            },
+            Focus {
+                name: "Fuses Flesh and Steel".to_string(),
+                suitable_types: vec!["Any; Glaives especially.".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(get_suitable_foci(&data.foci, "Glaive").len(), 3);
+        assert_eq!(get_suitable_foci(&data.foci, "Jack").len(), 2);
+
+        let mut report = ValidationReport::new();
+        validate_foci(&data.foci, &data, &mut report);
+        assert!(
+            !report
+                .errors
+                .iter()
+                .any(|e| e.contains("non-existent type")),
+            "{:?}",
+            report.errors
+        );
+        validate_cross_references(&data, &mut report);
+        assert!(!report
+            .warnings
+            .iter()
+            .any(|w| w.contains("suitable for no")));
+    }
+
+    fn create_test_oddity(id: &str, name: &str, value_shins: u32) -> Oddity {
+        Oddity {
+            id: id.to_string(),
+            name: name.to_string(),
+            category: "Curiosity".to_string(),
+            theme: "Test".to_string(),
+            value_shins,
+            tags: vec![],
+            source: "Discovery".to_string(),
+            description: "A strange little thing.".to_string(),
+            table_number: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_oddities_duplicate_names() {
+        let oddities = vec![
+            create_test_oddity("oddity_1", "Glowing Cube", 5),
+            create_test_oddity("oddity_2", "glowing cube", 5),
+        ];
+
+        let mut report = ValidationReport::new();
+        validate_oddities(&oddities, &SourceMap::default(), &mut report);
+
+        assert!(!report.has_errors());
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains("Duplicate oddity name")));
+    }
+
+    #[test]
+    fn test_validate_oddities_empty_name_and_zero_value() {
+        let oddities = vec![
+            create_test_oddity("oddity_1", "", 5),
+            create_test_oddity("oddity_2", "Dull Pebble", 0),
+        ];
+
+        let mut report = ValidationReport::new();
+        validate_oddities(&oddities, &SourceMap::default(), &mut report);
+
+        assert!(report.errors.iter().any(|e| e.contains("empty name")));
+        assert!(report.warnings.iter().any(|w| w.contains("zero value")));
+    }
+
+    #[test]
+    fn test_validate_skill_references_suggests_canonical_name() {
+        let mut data = GameData::new();
+        let mut char_type = create_test_type();
+        char_type.skills.trained = vec!["Persaysion".to_string(), "stealth".to_string()];
+        char_type.skills.specialized = vec!["Juggling".to_string()];
+        data.types.push(char_type);
+
+        // Without skills.toml, skills are free-form
+        let mut report = ValidationReport::new();
+        validate_skill_references(&data, &mut report);
+        assert!(report.warnings.is_empty());
+
+        data.skills = vec!["Persuasion".to_string(), "Stealth".to_string()];
+        let mut report = ValidationReport::new();
+        validate_skill_references(&data, &mut report);
+        assert_eq!(
+            report.warnings,
+            vec![
+                "types.toml: Type 'Glaive' skill 'Persaysion' is not in skills.toml (did you mean 'Persuasion'?)",
+                "types.toml: Type 'Glaive' skill 'Juggling' is not in skills.toml",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skills_toml_is_optional() {
+        let shipped = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let dir = std::env::temp_dir().join(format!("chargen-skills-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in REQUIRED_DATA_FILES {
+            std::fs::copy(shipped.join(file), dir.join(file)).unwrap();
+        }
+
+        assert!(load_all_data_from_dir(&dir, |_| {})
+            .unwrap()
+            .skills
+            .is_empty());
+
+        std::fs::write(
+            dir.join("skills.toml"),
+            "skills = [\"Persuasion\", \"Stealth\"]\n",
+        )
+        .unwrap();
+        let data = load_all_data_from_dir(&dir, |_| {}).unwrap();
+        assert_eq!(data.skills, vec!["Persuasion", "Stealth"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validation_messages_name_file_and_line() {
+        let shipped = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let dir = std::env::temp_dir().join(format!("chargen-origins-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in REQUIRED_DATA_FILES {
+            std::fs::copy(shipped.join(file), dir.join(file)).unwrap();
+        }
+
+        // Append a copy of the first focus so its name is duplicated at the end of the file
+        let foci = std::fs::read_to_string(dir.join("foci.toml")).unwrap();
+        let first = foci.find("[[foci]]").unwrap();
+        let second = first + 1 + foci[first + 1..].find("[[foci]]").unwrap();
+        let duplicated = format!("{}\n{}", foci, &foci[first..second]);
+        let line = duplicated[..duplicated.rfind("[[foci]]").unwrap()]
+            .matches('\n')
+            .count()
+            + 1;
+        std::fs::write(dir.join("foci.toml"), duplicated).unwrap();
+
+        let data = load_all_data_from_dir(&dir, |_| {}).unwrap();
+        let name = &data.foci[0].name;
+        let report = validate_all_comprehensive(&data).unwrap();
+        assert!(
+            report.errors.contains(&format!(
+                "foci.toml:{}: Duplicate focus name: '{}'",
+                line, name
+            )),
+            "{:?}",
+            report.errors
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_data_files() {
+        let shipped = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        assert!(missing_data_files(&shipped).is_empty());
+
+        let empty = std::env::temp_dir().join(format!("chargen-no-data-{}", std::process::id()));
+        let missing = missing_data_files(&empty);
+        assert_eq!(missing.len(), REQUIRED_DATA_FILES.len());
+        assert!(missing[0].ends_with("types.toml"));
+    }
+
+    #[test]
+    fn test_validate_ability_costs() {
+        let mut data = GameData::new();
+        data.foci = ["1 Intellect", "0", "one point"]
+            .iter()
+            .enumerate()
+            .map(|(i, cost)| Focus {
+                name: format!("Focus {}", i),
+                tier_1_ability: Ability {
+                    name: format!("Ability {}", i),
+                    cost: cost.to_string(),
+                    ability_type: "Action".to_string(),
+                    description: String::new(),
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        let mut report = ValidationReport::new();
+        validate_ability_costs(&data, &mut report);
+
+        assert_eq!(
+            report.warnings,
+            vec!["foci.toml: Focus 'Focus 2' ability 'Ability 2' has an unparseable cost: 'one point'"]
+        );
+    }
+
+    #[test]
+    fn test_validate_foci_without_connections() {
+        let mut data = GameData::new();
+        data.types.push(create_test_type());
+        let foci = vec![
+            Focus {
+                name: "Masters Weaponry".to_string(),
+                suitable_types: vec!["Glaive".to_string()],
+                connections: vec!["Pick one other PC".to_string()],
+                ..Default::default()
+            },
+            Focus {
+                name: "Walks Alone".to_string(),
+                suitable_types: vec!["Glaive".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        let mut report = ValidationReport::new();
+        validate_foci(&foci, &data, &mut report);
+
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("'Walks Alone' has no connections"));
+    }
+
+    #[test]
+    fn test_cross_references_focus_for_unloaded_type() {
+        let mut data = GameData::new();
+        data.types.push(create_test_type());
+        data.foci = vec![
+            Focus {
+                name: "Masters Weaponry".to_string(),
+                suitable_types: vec!["glaive".to_string()],
+                ..Default::default()
+            },
+            Focus {
+                name: "Leads".to_string(),
+                suitable_types: vec!["Arkus".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        let mut report = ValidationReport::new();
+        validate_cross_references(&data, &mut report);
+
+        assert_eq!(
+            report.warnings,
+            vec!["foci.toml: Focus 'Leads' is suitable for no loaded type".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_descriptor_species_name_collision() {
+        let descriptors: DescriptorsData = toml::from_str(
+            r#"
+            [[descriptors]]
+            name = "Varjellen"
+            source = "Test"
+            tagline = "Test descriptor sharing a species name"
+            initial_links = []
+
+            [descriptors.stat_modifiers]
+
+            [descriptors.skills]
+
+            [descriptors.equipment]
+            "#,
+        )
+        .unwrap();
+        let species: SpeciesData = toml::from_str(
+            r#"
+            [[species]]
+            name = "varjellen"
+            category = "visitant"
+            replaces_descriptor = true
+            tagline = "Test species"
+
+            [species.description]
+            appearance = "Test"
+            culture = "Test"
+            lifespan = 100
+
+            [species.stat_modifiers]
+
+            [species.skills]
+
+            [species.equipment]
+            starting_shins = 0
+            "#,
+        )
+        .unwrap();
+
+        let mut data = GameData::new();
+        data.descriptors = descriptors.descriptors;
+        data.species = species.species;
+
+        let report = validate_all_comprehensive(&data).unwrap();
+        assert!(report.errors.contains(
+            &"descriptors.toml: Name 'Varjellen' is used by both a descriptor and a species"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_comprehensive_report_is_sorted_and_stable() {
+        let mut data = GameData::new();
+        let mut nano = create_test_type();
+        nano.name = "Nano".to_string();
+        data.types = vec![nano, create_test_type(), create_test_type()];
+        data.oddities = vec![
+            create_test_oddity("oddity_2", "Zinc Bell", 0),
+            create_test_oddity("oddity_1", "Amber Bead", 0),
+        ];
+
+        let first = validate_all_comprehensive(&data).unwrap();
+        let second = validate_all_comprehensive(&data).unwrap();
+        assert_eq!(first, second);
+
+        for messages in [&first.errors, &first.warnings, &first.info] {
+            assert!(messages.windows(2).all(|w| w[0] <= w[1]));
+        }
+        assert!(!first.errors.is_empty());
+        assert!(first.warnings.len() >= 2);
+    }
+
+    #[test]
+    fn test_report_sorts_line_numbers_numerically() {
+        let mut report = ValidationReport::new();
+        for error in [
+            "foci.toml:1012: Focus 'B' has no connections",
+            "foci.toml:141: Focus 'A' has no connections",
+            "foci.toml: Focus 'C' has no suitable types",
+            "cyphers.toml:9: Duplicate cypher name: 'D'",
+        ] {
+            report.add_error(error.to_string());
+        }
+
+        report.sort();
+
+        assert_eq!(
+            report.errors,
+            vec![
+                "cyphers.toml:9: Duplicate cypher name: 'D'",
+                "foci.toml: Focus 'C' has no suitable types",
+                "foci.toml:141: Focus 'A' has no connections",
+                "foci.toml:1012: Focus 'B' has no connections",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_species_zero_bonus_points() {
+        let fixture: SpeciesData = toml::from_str(
+            r#"
+            [[species]]
+            name = "Hollowborn"
+            category = "visitant"
+            replaces_descriptor = true
+            tagline = "Test species with no bonus points"
+
+            [species.description]
+            appearance = "Test"
+            culture = "Test"
+            lifespan = 100
+
+            [species.stat_modifiers]
+            initial_bonus_points = 0
+
+            [species.skills]
+
+            [species.equipment]
+            starting_shins = 0
+            "#,
+        )
+        .unwrap();
+
+        let mut report = ValidationReport::new();
+        validate_species(&fixture.species, &GameData::new(), &mut report);
+
+        assert!(report.info.iter().any(
+            |i| i == "species.toml: Species 'Hollowborn' overrides starting bonus points to 0"
+        ));
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("initial_bonus_points: 0"));
+    }
+
+    #[test]
+    fn test_validate_artifacts_unknown_form_type() {
+        let fixture: ArtifactsData = toml::from_str(
+            r#"
+            [[artifact]]
+            id = "typo_lens"
+            name = "Typo Lens"
+            level_formula = "1d6+2"
+            depletion = "1 in 1d10"
+            form_type = "Handhald"
+            category = "Utility"
+            tags = []
+            effect = "Test"
+            form = "Test"
+            gm_intrusion = "Test"
+
+            [[artifact]]
+            id = "fine_lens"
+            name = "Fine Lens"
+            level_formula = "1d6+2"
+            depletion = "1 in 1d10"
+            form_type = "handheld"
+            category = "Utility"
+            tags = []
+            effect = "Test"
+            form = "Test"
+            gm_intrusion = "Test"
+            "#,
+        )
+        .unwrap();
+
+        let mut report = ValidationReport::new();
+        validate_artifacts(&fixture.artifact, &SourceMap::default(), &mut report);
+
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("'Typo Lens'"));
+        assert!(report.warnings[0].contains("'Handhald'"));
+    }
+
+    #[test]
+    fn test_validate_type_equipment_unknown_weapon() {
+        let mut data = GameData::new();
+        data.equipment.weapons.push(Weapon {
+            name: "Sword".to_string(),
+            category: "medium".to_string(),
+            damage: 4,
+            cost: 5,
+            range: "immediate".to_string(),
+            notes: String::new(),
+            bulk: 0,
+        });
+
+        let mut char_type = create_test_type();
+        char_type.equipment.weapons = vec!["sword".to_string(), "Greatsword".to_string()];
+        char_type.equipment.armor = Some(String::new());
+
+        let mut report = ValidationReport::new();
+        validate_type_equipment(&[char_type], &data, &mut report);
+
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("'Greatsword'"));
+    }
+
+    #[test]
+    fn test_validate_type_equipment_options() {
+        let mut data = GameData::new();
+        data.equipment.weapons.push(Weapon {
+            name: "Sword".to_string(),
+            category: "medium".to_string(),
+            damage: 4,
+            cost: 5,
+            range: "immediate".to_string(),
+            notes: String::new(),
+            bulk: 0,
+        });
+
+        let mut char_type = create_test_type();
+        char_type.equipment.options = vec![
+            vec!["Sword".to_string()],
+            vec!["Dagger".to_string(), "Dagger".to_string()],
+            vec![],
+        ];
+
+        let mut report = ValidationReport::new();
+        validate_type_equipment(&[char_type], &data, &mut report);
+
+        assert_eq!(report.warnings.len(), 3);
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains("empty equipment option (3)")));
+        assert!(report.warnings.iter().all(|w| !w.contains("'Sword'")));
+    }
 }
@@ -1,22 +1,38 @@
+pub mod editor;
+pub mod favorites;
 pub mod loader;
 pub mod models;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod stats;
 
+pub use editor::{content_file, set_field, CONTENT_FILES};
+pub use favorites::{load_favorites, save_favorites, validate_favorites, FavoriteKind, Favorites};
 pub use loader::{
-    create_artifact_instance, create_cypher_instance, data_summary, find_descriptor, find_focus,
+    create_artifact_instance, create_artifact_instance_with_rng, create_cypher_instance,
+    create_cypher_instance_with_rng, data_dir, data_summary, find_descriptor, find_focus,
     find_species, find_type, get_armor_by_category, get_cyphers_by_category, get_suitable_foci,
-    get_weapons_by_category, load_all_data, load_artifacts, load_cyphers, load_descriptors,
-    load_discoveries, load_equipment, load_foci, load_oddities, load_species, load_types,
-    roll_level_formula, validate_data_files, validate_game_data,
+    get_weapons_by_category, load_all_data, load_all_data_from_dir, load_all_data_with_progress,
+    load_artifacts, load_cyphers, load_descriptors, load_discoveries, load_equipment, load_foci,
+    load_oddities, load_species, load_types, missing_data_files, new_instance_id,
+    roll_level_formula, roll_level_formula_with_rng, validate_data_files, validate_game_data,
+    REQUIRED_DATA_FILES,
 };
 
+#[cfg(feature = "embed-data")]
+pub use loader::{embedded_file, load_all_data_embedded};
+
+pub use stats::{library_stats, LibraryStats, TypeStats, ValueRange};
+
 pub use models::{
-    Ability, Ammunition, Armor, Artifact, ArtifactCrafting, ArtifactInstance, ArtifactsData,
-    CharacterType, Clothing, Consumable, Cypher, CypherInstance, CyphersData, Descriptor,
-    DescriptorEquipment, DescriptorInabilities, DescriptorSkills, DescriptorStatModifiers,
-    DescriptorsData, DiscoveriesData, Discovery, DistinctiveMutation, EdgeValues, EquipmentData,
-    FociData, Focus, GameData, Gear, InitialLink, IotumRequirement, MutationEntry, MutationOptions,
-    MutationSystem, OdditiesData, Oddity, PlayerIntrusions, Shield, SpecialAbility,
-    SpecialEquipment, Species, SpeciesAbility, SpeciesData, SpeciesDescription, SpeciesEquipment,
-    SpeciesSkills, SpeciesStatModifiers, StartingTier, StatPools, TierAbilities, TierProgression,
-    TypeEquipment, TypeSkills, TypesData, Weapon,
+    suitable_type_name, Ability, Ammunition, Armor, Artifact, ArtifactCrafting, ArtifactInstance,
+    ArtifactsData, CharacterType, Clothing, Consumable, Cypher, CypherInstance, CyphersData,
+    DataCounts, Descriptor, DescriptorEquipment, DescriptorInabilities, DescriptorSkills,
+    DescriptorStatModifiers, DescriptorsData, DiscoveriesData, Discovery, DistinctiveMutation,
+    EdgeValues, EquipmentData, FociData, Focus, GameData, Gear, InitialLink, IotumRequirement,
+    MutationEntry, MutationOptions, MutationSystem, OdditiesData, Oddity, PlayerIntrusions, Shield,
+    SourceMap, SpecialAbility, SpecialEquipment, Species, SpeciesAbility, SpeciesData,
+    SpeciesDescription, SpeciesEquipment, SpeciesSkills, SpeciesStatModifiers, StartingTier,
+    StatPools, TierAbilities, TierProgression, TypeEquipment, TypeSkills, TypesData, Weapon,
+    NAME_CATEGORIES,
 };
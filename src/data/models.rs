@@ -2,11 +2,13 @@
 // Data models for all TOML files
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 // ==========================================
 // CHARACTER TYPES (types.toml)
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CharacterType {
     pub name: String,
     pub source: String,
@@ -23,6 +25,7 @@ pub struct CharacterType {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StatPools {
     pub might: u32,
     pub speed: u32,
@@ -31,6 +34,7 @@ pub struct StatPools {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EdgeValues {
     pub might: u32,
     pub speed: u32,
@@ -38,27 +42,44 @@ pub struct EdgeValues {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StartingTier {
     pub effort: u32,
     pub cypher_limit: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PlayerIntrusions {
     pub cost: String,
     pub examples: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TypeEquipment {
     pub weapons: Vec<String>,
     pub armor: Option<String>,
     pub explorer_pack: bool,
     pub shins: u32,
     pub other: Vec<String>,
+    /// Mutually exclusive choices (e.g. one medium weapon or two light ones); one is granted
+    #[serde(default)]
+    pub options: Vec<Vec<String>>,
+}
+
+impl TypeEquipment {
+    /// Readable label for each equipment option, e.g. "Knife + Knife"
+    pub fn option_labels(&self) -> Vec<String> {
+        self.options
+            .iter()
+            .map(|option| option.join(" + "))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TypeSkills {
     pub trained: Vec<String>,
     pub specialized: Vec<String>,
@@ -67,6 +88,7 @@ pub struct TypeSkills {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TierAbilities {
     pub tier: u32,
     pub count: u32,
@@ -74,6 +96,7 @@ pub struct TierAbilities {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ability {
     pub name: String,
     pub cost: String,
@@ -83,6 +106,7 @@ pub struct Ability {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TierProgression {
     pub tier: u32,
     pub effort: u32,
@@ -91,6 +115,7 @@ pub struct TierProgression {
 
 // Root structure for types.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TypesData {
     pub types: Vec<CharacterType>,
 }
@@ -100,6 +125,7 @@ pub struct TypesData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Descriptor {
     pub name: String,
     pub source: String,
@@ -113,6 +139,7 @@ pub struct Descriptor {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescriptorStatModifiers {
     #[serde(default)]
     pub might: i32,
@@ -123,6 +150,7 @@ pub struct DescriptorStatModifiers {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescriptorSkills {
     #[serde(default)]
     pub trained: Vec<String>,
@@ -133,18 +161,21 @@ pub struct DescriptorSkills {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescriptorInabilities {
     #[serde(default)]
     pub hindered: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpecialAbility {
     pub name: String,
     pub description: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescriptorEquipment {
     #[serde(default)]
     pub shins: u32,
@@ -154,15 +185,23 @@ pub struct DescriptorEquipment {
     pub armor: Vec<String>,
     #[serde(default)]
     pub other: Vec<String>,
+    /// Cyphers granted by name (resolved against cyphers.toml)
+    #[serde(default)]
+    pub cyphers: Vec<String>,
+    /// Oddities granted by name (resolved against oddities.toml)
+    #[serde(default)]
+    pub oddities: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct InitialLink {
     pub text: String,
 }
 
 // Root structure for descriptors.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DescriptorsData {
     pub descriptors: Vec<Descriptor>,
 }
@@ -172,6 +211,7 @@ pub struct DescriptorsData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Focus {
     pub name: String,
     pub source: String,
@@ -180,12 +220,19 @@ pub struct Focus {
     pub connections: Vec<String>,
     #[serde(default)]
     pub equipment: Vec<String>,
+    /// Cyphers granted by name (resolved against cyphers.toml)
+    #[serde(default)]
+    pub cyphers: Vec<String>,
+    /// Oddities granted by name (resolved against oddities.toml)
+    #[serde(default)]
+    pub oddities: Vec<String>,
     pub tier_1_ability: Ability,
     #[serde(default)]
     pub stat_modifiers: Option<StatModifiers>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StatModifiers {
     #[serde(default)]
     pub might: i32,
@@ -198,6 +245,7 @@ pub struct StatModifiers {
 
 // Root structure for foci.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FociData {
     pub foci: Vec<Focus>,
 }
@@ -207,6 +255,7 @@ pub struct FociData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Weapon {
     pub name: String,
     pub category: String,
@@ -214,9 +263,24 @@ pub struct Weapon {
     pub cost: u32,
     pub range: String,
     pub notes: String,
+    /// Encumbrance for house rules; 0 when untracked
+    #[serde(default)]
+    pub bulk: u32,
+}
+
+impl Weapon {
+    /// Sheet label with damage and range, e.g. "Bow (4 damage, long)"
+    pub fn label(&self) -> String {
+        if self.range.trim().is_empty() {
+            format!("{} ({} damage)", self.name, self.damage)
+        } else {
+            format!("{} ({} damage, {})", self.name, self.damage, self.range)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Armor {
     pub name: String,
     pub category: String,
@@ -224,9 +288,13 @@ pub struct Armor {
     pub speed_effort_cost: u32,
     pub cost: u32,
     pub notes: String,
+    /// Encumbrance for house rules; 0 when untracked
+    #[serde(default)]
+    pub bulk: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Shield {
     pub name: String,
     pub armor_bonus: u32,
@@ -236,14 +304,19 @@ pub struct Shield {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Gear {
     pub name: String,
     pub category: String,
     pub cost: u32,
     pub notes: String,
+    /// Encumbrance for house rules; 0 when untracked
+    #[serde(default)]
+    pub bulk: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Consumable {
     pub name: String,
     pub category: String,
@@ -252,6 +325,7 @@ pub struct Consumable {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Clothing {
     pub name: String,
     pub category: String,
@@ -260,6 +334,7 @@ pub struct Clothing {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpecialEquipment {
     pub name: String,
     pub category: String,
@@ -269,6 +344,7 @@ pub struct SpecialEquipment {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ammunition {
     pub name: String,
     pub category: String,
@@ -278,6 +354,7 @@ pub struct Ammunition {
 
 // Root structure for equipment.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EquipmentData {
     #[serde(default)]
     pub weapons: Vec<Weapon>,
@@ -302,6 +379,7 @@ pub struct EquipmentData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Cypher {
     pub name: String,
     pub level_formula: String,
@@ -315,6 +393,7 @@ pub struct Cypher {
 
 // Root structure for cyphers.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CyphersData {
     pub cypher: Vec<Cypher>,
 }
@@ -324,6 +403,7 @@ pub struct CyphersData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Artifact {
     pub id: String,
     pub name: String,
@@ -340,6 +420,7 @@ pub struct Artifact {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ArtifactCrafting {
     pub source: String,
     #[serde(default)]
@@ -351,6 +432,7 @@ pub struct ArtifactCrafting {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IotumRequirement {
     pub name: String,
     pub quantity: u32,
@@ -358,6 +440,7 @@ pub struct IotumRequirement {
 
 // Root structure for artifacts.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ArtifactsData {
     pub artifact: Vec<Artifact>,
 }
@@ -367,6 +450,7 @@ pub struct ArtifactsData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Oddity {
     pub id: String,
     pub name: String,
@@ -382,6 +466,7 @@ pub struct Oddity {
 
 // Root structure for oddities.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OdditiesData {
     pub oddity: Vec<Oddity>,
 }
@@ -391,6 +476,7 @@ pub struct OdditiesData {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Discovery {
     pub id: String,
     pub name: String,
@@ -406,6 +492,7 @@ pub struct Discovery {
 
 // Root structure for discoveries.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DiscoveriesData {
     pub discovery: Vec<Discovery>,
 }
@@ -416,7 +503,11 @@ pub struct DiscoveriesData {
 
 /// An instance of a cypher with a rolled level
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CypherInstance {
+    /// Distinguishes instances that share a name; 0 in sheets saved before ids existed
+    #[serde(default)]
+    pub instance_id: u64,
     pub name: String,
     pub level: u32,
     pub cypher_type: String,
@@ -427,7 +518,11 @@ pub struct CypherInstance {
 
 /// An instance of an artifact with a rolled level
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ArtifactInstance {
+    /// Distinguishes instances that share a name; 0 in sheets saved before ids existed
+    #[serde(default)]
+    pub instance_id: u64,
     pub name: String,
     pub level: u32,
     pub depletion: String,
@@ -441,6 +536,7 @@ pub struct ArtifactInstance {
 // ==========================================
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Species {
     pub name: String,
     pub category: String,
@@ -457,6 +553,7 @@ pub struct Species {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesDescription {
     pub appearance: String,
     pub culture: String,
@@ -464,6 +561,7 @@ pub struct SpeciesDescription {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesStatModifiers {
     #[serde(default)]
     pub might: i32,
@@ -478,15 +576,31 @@ pub struct SpeciesStatModifiers {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesAbility {
     pub name: String,
     #[serde(rename = "type")]
     pub ability_type: String,
     pub cost: String,
     pub description: String,
+    /// Pool favored while this mode is active (mode abilities only)
+    #[serde(default)]
+    pub favored_pool: Option<String>,
+    /// Whether this mode is active when the character is created
+    #[serde(default)]
+    pub default_mode: bool,
+}
+
+impl SpeciesAbility {
+    /// Check if this ability is a switchable mode/stance
+    pub fn is_mode(&self) -> bool {
+        self.ability_type.eq_ignore_ascii_case("mode")
+            || self.ability_type.eq_ignore_ascii_case("stance")
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesSkills {
     #[serde(default)]
     pub trained: Vec<String>,
@@ -497,6 +611,7 @@ pub struct SpeciesSkills {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesEquipment {
     pub starting_shins: u32,
     #[serde(default)]
@@ -504,6 +619,7 @@ pub struct SpeciesEquipment {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MutationSystem {
     pub beneficial_base: u32,
     pub harmful_optional: u32,
@@ -517,11 +633,13 @@ pub struct MutationSystem {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MutationOptions {
     pub description: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MutationEntry {
     pub roll_range: String,
     pub name: String,
@@ -529,6 +647,7 @@ pub struct MutationEntry {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DistinctiveMutation {
     pub name: String,
     pub effect: String,
@@ -536,16 +655,40 @@ pub struct DistinctiveMutation {
 
 // Root structure for species.toml
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SpeciesData {
     pub species: Vec<Species>,
 }
 
+// ==========================================
+// SKILLS (skills.toml, optional)
+// ==========================================
+
+// Root structure for skills.toml
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillsData {
+    pub skills: Vec<String>,
+}
+
 // ==========================================
 // GAME DATA - ALL LOADED DATA
 // ==========================================
 
+/// Categories reported by [`GameData::all_names`]
+pub const NAME_CATEGORIES: &[&str] = &[
+    "type",
+    "descriptor",
+    "focus",
+    "species",
+    "cypher",
+    "artifact",
+    "oddity",
+    "equipment",
+];
+
 /// Represents all loaded game data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GameData {
     pub types: Vec<CharacterType>,
     pub descriptors: Vec<Descriptor>,
@@ -556,6 +699,32 @@ pub struct GameData {
     pub oddities: Vec<Oddity>,
     pub discoveries: Vec<Discovery>,
     pub species: Vec<Species>,
+    /// Canonical skill names from the optional skills.toml; empty leaves skills free-form
+    pub skills: Vec<String>,
+    /// Where each entry was loaded from, for locating validation messages
+    #[serde(skip)]
+    pub sources: SourceMap,
+}
+
+/// Line of each `[[entry]]` header in the data files, in load order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    lines: HashMap<String, Vec<usize>>,
+}
+
+impl SourceMap {
+    /// Record the header lines of the entries loaded from `file`
+    pub fn record(&mut self, file: &str, lines: Vec<usize>) {
+        self.lines.insert(file.to_string(), lines);
+    }
+
+    /// "foci.toml:42" for the `index`th entry of `file`, or just the file when the line is unknown
+    pub fn locate(&self, file: &str, index: usize) -> String {
+        match self.lines.get(file).and_then(|lines| lines.get(index)) {
+            Some(line) => format!("{}:{}", file, line),
+            None => file.to_string(),
+        }
+    }
 }
 
 impl GameData {
@@ -580,8 +749,151 @@ impl GameData {
             oddities: Vec::new(),
             discoveries: Vec::new(),
             species: Vec::new(),
+            skills: Vec::new(),
+            sources: SourceMap::default(),
+        }
+    }
+
+    /// Number of entries in each loaded collection
+    pub fn counts(&self) -> DataCounts {
+        DataCounts {
+            types: self.types.len(),
+            descriptors: self.descriptors.len(),
+            foci: self.foci.len(),
+            weapons: self.equipment.weapons.len(),
+            armor: self.equipment.armor.len(),
+            cyphers: self.cyphers.len(),
+            artifacts: self.artifacts.len(),
+            oddities: self.oddities.len(),
+            discoveries: self.discoveries.len(),
+            species: self.species.len(),
+        }
+    }
+
+    /// Distinct focus themes, deduplicated case-insensitively and sorted
+    ///
+    /// The first spelling seen for a theme is kept, with surrounding
+    /// whitespace trimmed.
+    pub fn focus_themes(&self) -> Vec<String> {
+        let mut themes: Vec<String> = Vec::new();
+        for focus in &self.foci {
+            let theme = focus.theme.trim();
+            if theme.is_empty() || themes.iter().any(|t| t.eq_ignore_ascii_case(theme)) {
+                continue;
+            }
+            themes.push(theme.to_string());
         }
+        themes.sort_by_key(|t| t.to_lowercase());
+        themes
     }
+
+    /// Every selectable name as `(category, name)` pairs, for completion
+    ///
+    /// Categories are the entries of [`NAME_CATEGORIES`], in that order.
+    /// Names borrow from the loaded data.
+    pub fn all_names(&self) -> Vec<(&'static str, &str)> {
+        let equipment = &self.equipment;
+        let equipment_names = equipment
+            .weapons
+            .iter()
+            .map(|w| w.name.as_str())
+            .chain(equipment.armor.iter().map(|a| a.name.as_str()))
+            .chain(equipment.shields.iter().map(|s| s.name.as_str()))
+            .chain(equipment.gear.iter().map(|g| g.name.as_str()))
+            .chain(equipment.consumables.iter().map(|c| c.name.as_str()))
+            .chain(equipment.clothing.iter().map(|c| c.name.as_str()))
+            .chain(equipment.special_equipment.iter().map(|s| s.name.as_str()))
+            .chain(equipment.ammunition.iter().map(|a| a.name.as_str()));
+
+        self.types
+            .iter()
+            .map(|t| ("type", t.name.as_str()))
+            .chain(
+                self.descriptors
+                    .iter()
+                    .map(|d| ("descriptor", d.name.as_str())),
+            )
+            .chain(self.foci.iter().map(|f| ("focus", f.name.as_str())))
+            .chain(self.species.iter().map(|s| ("species", s.name.as_str())))
+            .chain(self.cyphers.iter().map(|c| ("cypher", c.name.as_str())))
+            .chain(self.artifacts.iter().map(|a| ("artifact", a.name.as_str())))
+            .chain(self.oddities.iter().map(|o| ("oddity", o.name.as_str())))
+            .chain(equipment_names.map(|name| ("equipment", name)))
+            .collect()
+    }
+
+    /// Short, stable hash of all loaded content
+    ///
+    /// Two installs with identical data files report the same hash, whatever
+    /// platform or build they run on.
+    pub fn content_hash(&self) -> String {
+        // Plain data structs always serialize; an empty buffer would still hash
+        let bytes = serde_json::to_vec(self).unwrap_or_default();
+        format!("{:012x}", fnv1a_64(&bytes) >> 16)
+    }
+
+    /// Selectable names in one category of [`NAME_CATEGORIES`]
+    pub fn names_in(&self, category: &str) -> Vec<&str> {
+        self.all_names()
+            .into_iter()
+            .filter(|(c, _)| *c == category)
+            .map(|(_, name)| name)
+            .collect()
+    }
+}
+
+impl Focus {
+    /// Whether this focus's theme mentions the given text, ignoring case
+    pub fn matches_theme(&self, theme: &str) -> bool {
+        self.theme.to_lowercase().contains(&theme.trim().to_lowercase())
+    }
+
+    /// Whether `type_name` is among the suitable types, or the focus suits "Any"
+    pub fn suits_type(&self, type_name: &str) -> bool {
+        self.suitable_types
+            .iter()
+            .any(|entry| match suitable_type_name(entry) {
+                Some(name) => name.eq_ignore_ascii_case(type_name),
+                None => true,
+            })
+    }
+}
+
+/// The type a `suitable_types` entry names, or `None` when it allows any type
+///
+/// Book notes are ignored, so "Nano (best)" and "rare Glaive" name Nano and
+/// Glaive, and "Any; Glaives especially." allows every type.
+pub fn suitable_type_name(entry: &str) -> Option<&str> {
+    let name = entry.split(['(', ';']).next().unwrap_or_default().trim();
+    let name = match name.get(..5) {
+        Some(rare) if rare.eq_ignore_ascii_case("rare ") => name[5..].trim(),
+        _ => name,
+    };
+    (!name.eq_ignore_ascii_case("any")).then_some(name)
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Collection sizes of loaded game data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DataCounts {
+    pub types: usize,
+    pub descriptors: usize,
+    pub foci: usize,
+    pub weapons: usize,
+    pub armor: usize,
+    pub cyphers: usize,
+    pub artifacts: usize,
+    pub oddities: usize,
+    pub discoveries: usize,
+    pub species: usize,
 }
 
 impl Default for GameData {
@@ -0,0 +1,86 @@
+// src/data/schema.rs
+// JSON Schema export for the TOML data formats
+
+use anyhow::Result;
+use schemars::schema_for;
+
+use super::models::*;
+
+/// Data files with a schema, by file stem
+pub const SCHEMA_FILES: &[&str] = &[
+    "types",
+    "descriptors",
+    "foci",
+    "equipment",
+    "cyphers",
+    "species",
+    "artifacts",
+    "oddities",
+    "discoveries",
+    "skills",
+];
+
+/// Get the JSON Schema for one data file (e.g. "types" for types.toml)
+pub fn file_schema(file: &str) -> Result<serde_json::Value> {
+    let stem = file.trim_end_matches(".toml").to_lowercase();
+
+    let schema = match stem.as_str() {
+        "types" => schema_for!(TypesData),
+        "descriptors" => schema_for!(DescriptorsData),
+        "foci" => schema_for!(FociData),
+        "equipment" => schema_for!(EquipmentData),
+        "cyphers" => schema_for!(CyphersData),
+        "species" => schema_for!(SpeciesData),
+        "artifacts" => schema_for!(ArtifactsData),
+        "oddities" => schema_for!(OdditiesData),
+        "discoveries" => schema_for!(DiscoveriesData),
+        "skills" => schema_for!(SkillsData),
+        _ => anyhow::bail!(
+            "Unknown data file '{}'. Expected one of: {}",
+            file,
+            SCHEMA_FILES.join(", ")
+        ),
+    };
+
+    Ok(serde_json::to_value(schema)?)
+}
+
+/// Get a combined JSON object with the schema of every data file, keyed by file stem
+pub fn all_schemas() -> Result<serde_json::Value> {
+    let mut schemas = serde_json::Map::new();
+
+    for file in SCHEMA_FILES {
+        schemas.insert(file.to_string(), file_schema(file)?);
+    }
+
+    Ok(serde_json::Value::Object(schemas))
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_schema_types() {
+        let schema = file_schema("types.toml").unwrap();
+        assert_eq!(schema["title"], "TypesData");
+        assert!(schema["definitions"]["CharacterType"].is_object());
+    }
+
+    #[test]
+    fn test_all_schemas_cover_every_file() {
+        let schemas = all_schemas().unwrap();
+        for file in SCHEMA_FILES {
+            assert!(schemas[*file].is_object(), "Missing schema for {}", file);
+        }
+    }
+
+    #[test]
+    fn test_unknown_file_schema() {
+        assert!(file_schema("recipes").is_err());
+    }
+}
@@ -0,0 +1,144 @@
+// src/data/stats.rs
+// Summary statistics over the loaded content library
+
+use super::loader::{get_cyphers_by_category, get_suitable_foci};
+use super::models::GameData;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// ==========================================
+// LIBRARY STATS
+// ==========================================
+
+/// Coverage summary of the loaded game data
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LibraryStats {
+    pub types: Vec<TypeStats>,
+    /// Mean of the types' total starting pools (including bonus points)
+    pub average_starting_pool: f64,
+    /// Median of the types' total starting pools (including bonus points)
+    pub median_starting_pool: f64,
+    pub cyphers_by_category: BTreeMap<String, usize>,
+    pub artifact_level_formulas: BTreeMap<String, usize>,
+    pub oddity_values: Option<ValueRange>,
+}
+
+/// Starting pools and focus coverage for one character type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TypeStats {
+    pub name: String,
+    pub foci: usize,
+    pub might: u32,
+    pub speed: u32,
+    pub intellect: u32,
+    pub bonus_points: u32,
+    pub total_pool: u32,
+}
+
+/// Smallest and largest value in a collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ValueRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+/// Summarize the content library
+pub fn library_stats(game_data: &GameData) -> LibraryStats {
+    let types: Vec<TypeStats> = game_data
+        .types
+        .iter()
+        .map(|t| {
+            let pools = &t.stat_pools;
+            TypeStats {
+                name: t.name.clone(),
+                foci: get_suitable_foci(&game_data.foci, &t.name).len(),
+                might: pools.might,
+                speed: pools.speed,
+                intellect: pools.intellect,
+                bonus_points: pools.bonus_points,
+                total_pool: pools.might + pools.speed + pools.intellect + pools.bonus_points,
+            }
+        })
+        .collect();
+
+    let totals: Vec<u32> = types.iter().map(|t| t.total_pool).collect();
+
+    // Categories keep the first spelling seen; counts match case-insensitively
+    let mut cyphers_by_category = BTreeMap::new();
+    for cypher in &game_data.cyphers {
+        let known = cyphers_by_category
+            .keys()
+            .any(|c: &String| c.eq_ignore_ascii_case(&cypher.category));
+        if !known {
+            let count = get_cyphers_by_category(&game_data.cyphers, &cypher.category).len();
+            cyphers_by_category.insert(cypher.category.clone(), count);
+        }
+    }
+
+    let mut artifact_level_formulas = BTreeMap::new();
+    for artifact in &game_data.artifacts {
+        *artifact_level_formulas
+            .entry(artifact.level_formula.trim().to_string())
+            .or_insert(0) += 1;
+    }
+
+    let values = game_data.oddities.iter().map(|o| o.value_shins);
+    let oddity_values = values
+        .clone()
+        .min()
+        .zip(values.max())
+        .map(|(min, max)| ValueRange { min, max });
+
+    LibraryStats {
+        types,
+        average_starting_pool: mean(&totals),
+        median_starting_pool: median(&totals),
+        cyphers_by_category,
+        artifact_level_formulas,
+        oddity_values,
+    }
+}
+
+fn mean(values: &[u32]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().map(|v| f64::from(*v)).sum::<f64>() / values.len() as f64
+}
+
+fn median(values: &[u32]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => 0.0,
+        n if n % 2 == 0 => (f64::from(sorted[mid - 1]) + f64::from(sorted[mid])) / 2.0,
+        _ => f64::from(sorted[mid]),
+    }
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_and_median() {
+        assert_eq!(mean(&[]), 0.0);
+        assert_eq!(median(&[]), 0.0);
+        assert_eq!(mean(&[30, 32, 34]), 32.0);
+        assert_eq!(median(&[34, 30, 32]), 32.0);
+        assert_eq!(median(&[30, 31, 33, 40]), 32.0);
+    }
+
+    #[test]
+    fn test_library_stats_empty() {
+        let stats = library_stats(&GameData::new());
+        assert!(stats.types.is_empty());
+        assert!(stats.cyphers_by_category.is_empty());
+        assert_eq!(stats.oddity_values, None);
+    }
+}
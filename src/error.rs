@@ -0,0 +1,117 @@
+// src/error.rs
+// Library error type
+
+use std::path::PathBuf;
+
+/// Errors returned by the library's public API
+#[derive(Debug, thiserror::Error)]
+pub enum ChargenError {
+    #[error("Character type '{0}' not found")]
+    TypeNotFound(String),
+
+    #[error("Focus '{0}' not found")]
+    FocusNotFound(String),
+
+    #[error("Descriptor or species '{0}' not found")]
+    DescriptorNotFound(String),
+
+    #[error("Bonus points must total {expected}. Current: {got}")]
+    InvalidBonusPoints { expected: i32, got: i32 },
+
+    #[error("{0}")]
+    InvalidCharacter(String),
+
+    #[error("Invalid advance '{advance}': {reason}")]
+    InvalidAdvance { advance: String, reason: String },
+
+    #[error("{0}")]
+    InvalidTier(String),
+
+    #[error("{0}")]
+    Preset(String),
+
+    #[error("{0}")]
+    InvalidData(String),
+
+    #[error("Required data file not found: {}", .0.display())]
+    MissingDataFile(PathBuf),
+
+    #[error("{0}")]
+    Edit(String),
+
+    #[error("Unknown output format '{name}' (supported: {supported})")]
+    UnknownFormat { name: String, supported: String },
+
+    #[error("Failed to read {name}")]
+    Read {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to write {}", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{name} is not a saved character JSON file")]
+    CharacterJson {
+        name: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Failed to serialize the character sheet")]
+    Serialize(#[source] serde_json::Error),
+
+    #[error("Failed to write TOML")]
+    TomlWrite(#[source] toml::ser::Error),
+
+    #[error(transparent)]
+    Generation(anyhow::Error),
+
+    #[error("Creation cancelled, nothing saved")]
+    Cancelled,
+
+    #[error("Failed to read {}", path.display())]
+    DataFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", path.display())]
+    DataParse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
+/// Result alias using [`ChargenError`]
+pub type Result<T> = std::result::Result<T, ChargenError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        let err = ChargenError::InvalidBonusPoints {
+            expected: 6,
+            got: 4,
+        };
+        assert_eq!(err.to_string(), "Bonus points must total 6. Current: 4");
+
+        let err = ChargenError::TypeNotFound("Gunslinger".to_string());
+        assert_eq!(err.to_string(), "Character type 'Gunslinger' not found");
+
+        let err = ChargenError::MissingDataFile(PathBuf::from("data/types.toml"));
+        assert_eq!(
+            err.to_string(),
+            "Required data file not found: data/types.toml"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+// src/generator/audit.rs
+// Build every type + focus pairing to catch data that breaks character building
+
+use serde::Serialize;
+
+use crate::character::{build_character_checked, tier_ability_choices, tier_ability_count};
+use crate::data::{get_suitable_foci, CharacterType, GameData};
+
+// ==========================================
+// AUDIT
+// ==========================================
+
+/// A type + focus pairing that could not be built
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditFailure {
+    pub character_type: String,
+    /// Empty when the type fails before any focus is tried
+    pub focus: String,
+    pub error: String,
+}
+
+/// Outcome of building every type with every suitable focus
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AuditReport {
+    /// Combinations attempted
+    pub checked: usize,
+    pub failures: Vec<AuditFailure>,
+}
+
+impl AuditReport {
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Build each type with each of its suitable foci and report the combinations that error
+///
+/// Every build uses the first descriptor, the type's first tier 1 abilities and
+/// all bonus points in Might, so a failure points at the type or focus data.
+pub fn audit_combinations(game_data: &GameData) -> AuditReport {
+    let mut report = AuditReport::default();
+
+    let Some(descriptor) = game_data.descriptors.first() else {
+        report.failures.push(AuditFailure {
+            character_type: String::new(),
+            focus: String::new(),
+            error: "No descriptors loaded".to_string(),
+        });
+        return report;
+    };
+
+    for char_type in &game_data.types {
+        let fail = |error: String| AuditFailure {
+            character_type: char_type.name.clone(),
+            focus: String::new(),
+            error,
+        };
+
+        let abilities = match starting_abilities(char_type) {
+            Ok(abilities) => abilities,
+            Err(error) => {
+                report.failures.push(fail(error));
+                continue;
+            }
+        };
+
+        let foci = get_suitable_foci(&game_data.foci, &char_type.name);
+        if foci.is_empty() {
+            report.failures.push(fail("No suitable foci".to_string()));
+            continue;
+        }
+
+        for focus in foci {
+            report.checked += 1;
+            let result = build_character_checked(
+                game_data,
+                "Audit".to_string(),
+                &char_type.name,
+                &descriptor.name,
+                &focus.name,
+                char_type.stat_pools.bonus_points as i32,
+                0,
+                0,
+                abilities.clone(),
+                Vec::new(),
+            );
+            if let Err(e) = result {
+                report.failures.push(AuditFailure {
+                    focus: focus.name.clone(),
+                    ..fail(e.to_string())
+                });
+            }
+        }
+    }
+
+    report
+}
+
+/// The first tier 1 abilities a type offers, enough to fill its picks
+fn starting_abilities(char_type: &CharacterType) -> Result<Vec<String>, String> {
+    let count = tier_ability_count(char_type, 1);
+    let choices = tier_ability_choices(char_type, 1);
+    if count == 0 || choices.len() < count {
+        return Err(format!(
+            "Offers {} tier 1 abilities for {} picks",
+            choices.len(),
+            count
+        ));
+    }
+    Ok(choices
+        .into_iter()
+        .take(count)
+        .map(|a| a.name.clone())
+        .collect())
+}
@@ -0,0 +1,161 @@
+// src/generator/description.rs
+// Random age, height, build and notable feature, keyed on species
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::character::PhysicalDescription;
+use crate::data::Species;
+
+/// Lifespan used for humans and anything without a species
+pub const HUMAN_LIFESPAN: u32 = 80;
+
+// ==========================================
+// DESCRIPTION TABLES
+// ==========================================
+
+/// Random tables for one kind of character
+struct DescriptionTables {
+    heights: &'static [&'static str],
+    builds: &'static [&'static str],
+    features: &'static [&'static str],
+}
+
+const HUMAN_TABLES: DescriptionTables = DescriptionTables {
+    heights: &[
+        "Short",
+        "Below average height",
+        "Average height",
+        "Tall",
+        "Very tall",
+    ],
+    builds: &[
+        "Slight", "Wiry", "Lean", "Average", "Stocky", "Muscular", "Heavyset",
+    ],
+    features: &[
+        "a jagged scar across one cheek",
+        "eyes of two different colours",
+        "intricate tattoos along both arms",
+        "a shaved head painted with glyphs",
+        "hair streaked with early silver",
+        "a missing finger on the left hand",
+        "synth beads woven into braided hair",
+        "freckles and a crooked smile",
+    ],
+};
+
+const VARJELLEN_TABLES: DescriptionTables = DescriptionTables {
+    heights: &["Tall", "Very tall", "Towering"],
+    builds: &["Angular", "Lean and angular", "Gaunt"],
+    features: &[
+        "a tall, swept-back crest",
+        "deep violet-red flesh",
+        "pale yellow eyes that rarely blink",
+        "faint seams where the crucible opens",
+        "a crest notched with ritual marks",
+    ],
+};
+
+const LATTIMOR_TABLES: DescriptionTables = DescriptionTables {
+    heights: &["About 7 feet", "Well over 7 feet"],
+    builds: &["Broad-shouldered", "Hulking", "Massive"],
+    features: &[
+        "four watchful eyes",
+        "six eyes set in two rows",
+        "a wide, discoloured neem patch across the back",
+        "coarse, matted bursk fur",
+        "a neem patch that pulses faintly when thinking",
+    ],
+};
+
+/// Tables for a species, falling back to the human ones
+fn tables_for(species: Option<&Species>) -> &'static DescriptionTables {
+    match species.map(|s| s.name.to_lowercase()).as_deref() {
+        Some("varjellen") => &VARJELLEN_TABLES,
+        Some("lattimor") => &LATTIMOR_TABLES,
+        _ => &HUMAN_TABLES,
+    }
+}
+
+// ==========================================
+// GENERATION
+// ==========================================
+
+/// Plausible adventuring ages for a lifespan: from a fifth of it to three quarters
+pub fn age_range(lifespan: u32) -> (u32, u32) {
+    let lifespan = lifespan.max(20);
+    (lifespan / 5, lifespan * 3 / 4)
+}
+
+/// Roll a physical description for a character of `species` (human when `None`)
+pub fn generate_physical_description(
+    species: Option<&Species>,
+    rng: &mut impl Rng,
+) -> PhysicalDescription {
+    let lifespan = species.map_or(HUMAN_LIFESPAN, |s| s.description.lifespan);
+    let (youngest, oldest) = age_range(lifespan);
+    let tables = tables_for(species);
+
+    PhysicalDescription {
+        age: rng.gen_range(youngest..=oldest),
+        height: pick(tables.heights, rng),
+        build: pick(tables.builds, rng),
+        notable_feature: pick(tables.features, rng),
+    }
+}
+
+/// One entry from a table
+fn pick(table: &[&str], rng: &mut impl Rng) -> String {
+    table.choose(rng).copied().unwrap_or_default().to_string()
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::path::Path;
+
+    #[test]
+    fn test_age_within_species_range() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let game_data = crate::data::load_all_data_from_dir(&dir, |_| {}).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for species in &game_data.species {
+            let lifespan = species.description.lifespan;
+            let (youngest, oldest) = age_range(lifespan);
+            assert!(youngest < oldest && oldest < lifespan);
+            for _ in 0..50 {
+                let description = generate_physical_description(Some(species), &mut rng);
+                assert!(
+                    (youngest..=oldest).contains(&description.age),
+                    "{} aged {}",
+                    species.name,
+                    description.age
+                );
+            }
+        }
+
+        let human = generate_physical_description(None, &mut rng);
+        assert!((16..=60).contains(&human.age));
+        assert!(HUMAN_TABLES.heights.contains(&human.height.as_str()));
+    }
+
+    #[test]
+    fn test_species_tables() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let game_data = crate::data::load_all_data_from_dir(&dir, |_| {}).unwrap();
+        let lattimor = crate::data::find_species(&game_data.species, "Lattimor").unwrap();
+
+        let description = generate_physical_description(Some(lattimor), &mut rand::thread_rng());
+        assert!(LATTIMOR_TABLES.builds.contains(&description.build.as_str()));
+        assert!(LATTIMOR_TABLES
+            .features
+            .contains(&description.notable_feature.as_str()));
+    }
+}
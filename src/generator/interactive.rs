@@ -3,10 +3,18 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::io::{self, Write};
+use std::io::{self, BufRead, Write};
 
-use crate::character::{build_character, CharacterSheet};
-use crate::data::GameData;
+use crate::character::builder::builder_from_selections;
+use crate::character::{
+    apply_equipment_option, apply_tier_advance, available_tier_abilities, intrusion_note,
+    tier_ability_count, validate_start_tier, CharacterSheet, Pools, TierAdvance, STARTING_ODDITIES,
+    TIER_POOL_POINTS,
+};
+use crate::data::{CharacterType, GameData, Oddity};
+use crate::error::ChargenError;
+
+use super::random::Allocation;
 
 // ==========================================
 // MAIN INTERACTIVE FLOW
@@ -14,6 +22,20 @@ use crate::data::GameData;
 
 /// Run the interactive character generator
 pub fn run(game_data: &GameData) -> Result<CharacterSheet> {
+    run_with_start_tier(game_data, 1)
+}
+
+/// Run the interactive character generator, advancing the character to `start_tier`
+pub fn run_with_start_tier(game_data: &GameData, start_tier: u32) -> Result<CharacterSheet> {
+    run_with_allocation(game_data, start_tier, None)
+}
+
+/// Run the interactive character generator, spreading bonus points by `allocation` if given
+pub fn run_with_allocation(
+    game_data: &GameData,
+    start_tier: u32,
+    mut allocation: Option<Allocation>,
+) -> Result<CharacterSheet> {
     println!(
         "\n{}",
         "═══════════════════════════════════════════════"
@@ -29,69 +51,281 @@ pub fn run(game_data: &GameData) -> Result<CharacterSheet> {
     );
     println!();
 
-    // Step 1: Character Name
-    let name = prompt_name()?;
-    println!();
+    let stdin = io::stdin();
+    let input = &mut stdin.lock();
+    let mut choices = Choices::default();
 
-    // Step 2: Select Character Type
-    let character_type = select_type(game_data)?;
-    println!();
+    loop {
+        // Step 1: Character Name
+        let name = match &choices.name {
+            Some(name) => name.clone(),
+            None => {
+                let name = prompt_name(input)?;
+                println!();
+                choices.name.insert(name).clone()
+            }
+        };
+
+        // Step 2: Select Character Type
+        let character_type = match &choices.character_type {
+            Some(character_type) => character_type.clone(),
+            None => {
+                let character_type = select_type(game_data, input)?;
+                println!();
+                choices.character_type.insert(character_type).clone()
+            }
+        };
+        let type_data = game_data
+            .types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(&character_type))
+            .context("Character type not found")?;
+        validate_start_tier(type_data, start_tier)?;
+
+        // Step 3: Select Descriptor or Species
+        let (descriptor_or_species, is_species) = match &choices.descriptor {
+            Some(descriptor) => descriptor.clone(),
+            None => {
+                let descriptor = select_descriptor_or_species(game_data, input)?;
+                println!();
+                choices.descriptor.insert(descriptor).clone()
+            }
+        };
+
+        // Step 4: Allocate Bonus Points
+        let bonus_points = match (choices.bonus_points, allocation) {
+            (Some(bonus_points), _) => bonus_points,
+            (None, Some(allocation)) => *choices.bonus_points.insert(apply_allocation(
+                game_data,
+                &character_type,
+                &descriptor_or_species,
+                is_species,
+                allocation,
+            )?),
+            (None, None) => *choices.bonus_points.insert(allocate_bonus_points(
+                game_data,
+                &character_type,
+                &descriptor_or_species,
+                is_species,
+                input,
+            )?),
+        };
+
+        // Step 5: Select Focus
+        let focus = match &choices.focus {
+            Some(focus) => focus.clone(),
+            None => {
+                let focus = select_focus(game_data, &character_type, input)?;
+                println!();
+                choices.focus.insert(focus).clone()
+            }
+        };
+
+        // Step 6: Select Type Abilities
+        let selected_abilities = match &choices.abilities {
+            Some(abilities) => abilities.clone(),
+            None => {
+                let abilities = select_type_abilities(game_data, &character_type, input)?;
+                println!();
+                choices.abilities.insert(abilities).clone()
+            }
+        };
+
+        // Types offering a choice of starting gear (e.g. one medium or two light weapons)
+        let equipment_option = match choices.equipment_option {
+            Some(option) => Some(option),
+            None if !type_data.equipment.options.is_empty() => {
+                let option = select_equipment_option(type_data, input)?;
+                println!();
+                Some(*choices.equipment_option.insert(option))
+            }
+            None => None,
+        };
+
+        // A player intrusion the character is known for, when the type lists examples
+        let intrusion = match choices.intrusion {
+            Some(example) => Some(example),
+            None if !type_data.intrusions.examples.is_empty() => {
+                let example = select_intrusion(type_data, input)?;
+                println!();
+                Some(*choices.intrusion.insert(example))
+            }
+            None => None,
+        };
+
+        // Step 7: Select Oddities
+        let oddities = match &choices.oddities {
+            Some(oddities) => oddities.clone(),
+            None => {
+                let oddities = select_oddities(game_data, input)?;
+                println!();
+                choices.oddities.insert(oddities).clone()
+            }
+        };
+
+        // Build the character
+        println!("{}", "Building character...".green());
+        // Picked oddities join any the descriptor or focus grants
+        let mut sheet = builder_from_selections(
+            game_data,
+            name,
+            &character_type,
+            &descriptor_or_species,
+            &focus,
+            Pools::new(bonus_points.0, bonus_points.1, bonus_points.2),
+            selected_abilities,
+        )?
+        .with_oddities(oddities)
+        .build(game_data)?;
+        if let Some(option) = equipment_option {
+            apply_equipment_option(&mut sheet, type_data, option, game_data)?;
+        }
+        if let Some(example) = intrusion {
+            sheet.intrusion_note = Some(intrusion_note(
+                type_data,
+                &type_data.intrusions.examples[example],
+            ));
+        }
 
-    // Step 3: Select Descriptor or Species
-    let (descriptor_or_species, is_species) = select_descriptor_or_species(game_data)?;
-    println!();
+        // Step 8: Advance to the starting tier
+        if choices.advances.is_empty() {
+            for tier in 2..=start_tier {
+                println!();
+                let advance = select_tier_advance(type_data, &sheet, tier, input)?;
+                apply_tier_advance(&mut sheet, type_data, &advance)?;
+                choices.advances.push(advance);
+            }
+        } else {
+            for advance in &choices.advances {
+                apply_tier_advance(&mut sheet, type_data, advance)?;
+            }
+        }
 
-    // Step 4: Allocate Bonus Points
-    let bonus_points = allocate_bonus_points(
-        &character_type,
-        &descriptor_or_species,
-        is_species,
-        game_data,
-    )?;
+        // Step 9: Review
+        println!();
+        match review_character(&sheet, input)? {
+            ReviewAction::Save => {
+                println!();
+                println!("{}", "✓ Character created successfully!".green().bold());
+                println!();
+                return Ok(sheet);
+            }
+            ReviewAction::Restart => choices = Choices::default(),
+            ReviewAction::Edit(section) => {
+                // Revisiting the bonus points means spreading them by hand
+                if matches!(section, Section::BonusPoints) {
+                    allocation = None;
+                }
+                choices.clear(section);
+            }
+        }
+        println!();
+    }
+}
 
-    // Step 5: Select Focus
-    let focus = select_focus(game_data, &character_type)?;
-    println!();
+/// Answers collected so far; `None` (or no advances) means the step still needs asking
+#[derive(Debug, Default)]
+struct Choices {
+    name: Option<String>,
+    character_type: Option<String>,
+    descriptor: Option<(String, bool)>,
+    bonus_points: Option<(i32, i32, i32)>,
+    focus: Option<String>,
+    abilities: Option<Vec<String>>,
+    equipment_option: Option<usize>,
+    intrusion: Option<usize>,
+    oddities: Option<Vec<Oddity>>,
+    advances: Vec<TierAdvance>,
+}
 
-    // Step 6: Select Type Abilities
-    let selected_abilities = select_type_abilities(game_data, &character_type)?;
-    println!();
+impl Choices {
+    /// Forget a section and every later answer that depends on it
+    fn clear(&mut self, section: Section) {
+        match section {
+            Section::Name => self.name = None,
+            Section::Type => {
+                self.character_type = None;
+                self.bonus_points = None;
+                self.abilities = None;
+                self.equipment_option = None;
+                self.intrusion = None;
+                self.advances.clear();
+            }
+            Section::Descriptor => {
+                self.descriptor = None;
+                self.bonus_points = None;
+            }
+            Section::BonusPoints => self.bonus_points = None,
+            Section::Focus => self.focus = None,
+            Section::Abilities => {
+                self.abilities = None;
+                self.advances.clear();
+            }
+            Section::Oddities => self.oddities = None,
+            Section::TierAdvances => self.advances.clear(),
+        }
+    }
+}
 
-    // Build the character
-    println!("{}", "Building character...".green());
-    let sheet = build_character(
-        game_data,
-        name,
-        &character_type,
-        &descriptor_or_species,
-        &focus,
-        bonus_points.0,
-        bonus_points.1,
-        bonus_points.2,
-        selected_abilities,
-    )?;
+/// Steps the review can jump back to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Name,
+    Type,
+    Descriptor,
+    BonusPoints,
+    Focus,
+    Abilities,
+    Oddities,
+    TierAdvances,
+}
 
-    println!();
-    println!("{}", "✓ Character created successfully!".green().bold());
-    println!();
+impl Section {
+    const ALL: [Section; 8] = [
+        Section::Name,
+        Section::Type,
+        Section::Descriptor,
+        Section::BonusPoints,
+        Section::Focus,
+        Section::Abilities,
+        Section::Oddities,
+        Section::TierAdvances,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Section::Name => "Name",
+            Section::Type => "Character type",
+            Section::Descriptor => "Descriptor or species",
+            Section::BonusPoints => "Bonus points",
+            Section::Focus => "Focus",
+            Section::Abilities => "Type abilities",
+            Section::Oddities => "Oddities",
+            Section::TierAdvances => "Tier advances",
+        }
+    }
+}
 
-    Ok(sheet)
+/// What to do after reviewing a built character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewAction {
+    Save,
+    Restart,
+    Edit(Section),
 }
 
 // ==========================================
 // STEP 1: CHARACTER NAME
 // ==========================================
 
-fn prompt_name() -> Result<String> {
+fn prompt_name(input: &mut impl BufRead) -> Result<String> {
     println!("{}", "Step 1: Character Name".yellow().bold());
     println!("Enter your character's name:");
 
     print!("> ");
     io::stdout().flush()?;
 
-    let mut name = String::new();
-    io::stdin().read_line(&mut name)?;
-    let name = name.trim().to_string();
+    let name = read_input_line(input)?.trim().to_string();
 
     if name.is_empty() {
         anyhow::bail!("Character name cannot be empty");
@@ -104,7 +338,8 @@ fn prompt_name() -> Result<String> {
 // STEP 2: SELECT CHARACTER TYPE
 // ==========================================
 
-fn select_type(game_data: &GameData) -> Result<String> {
+/// Ask for a character type, returning its name
+pub fn select_type(game_data: &GameData, input: &mut impl BufRead) -> Result<String> {
     println!("{}", "Step 2: Select Character Type".yellow().bold());
     println!("Choose your character type (the noun in 'I am a [adjective] [noun] who [verbs]'):");
     println!();
@@ -120,7 +355,7 @@ fn select_type(game_data: &GameData) -> Result<String> {
     }
 
     println!();
-    let choice = prompt_choice(game_data.types.len())?;
+    let choice = prompt_choice(input, game_data.types.len())?;
     let selected_type = &game_data.types[choice - 1];
 
     println!("\n{} {}", "Selected:".green(), selected_type.name.bold());
@@ -132,7 +367,11 @@ fn select_type(game_data: &GameData) -> Result<String> {
 // STEP 3: SELECT DESCRIPTOR OR SPECIES
 // ==========================================
 
-fn select_descriptor_or_species(game_data: &GameData) -> Result<(String, bool)> {
+/// Ask for a descriptor or species, returning its name and whether it is a species
+pub fn select_descriptor_or_species(
+    game_data: &GameData,
+    input: &mut impl BufRead,
+) -> Result<(String, bool)> {
     println!("{}", "Step 3: Select Descriptor or Species".yellow().bold());
     println!("Choose a descriptor (adjective) or species (replaces descriptor):");
     println!();
@@ -162,7 +401,7 @@ fn select_descriptor_or_species(game_data: &GameData) -> Result<(String, bool)>
 
     println!();
     let total_options = game_data.descriptors.len() + game_data.species.len();
-    let choice = prompt_choice(total_options)?;
+    let choice = prompt_choice(input, total_options)?;
 
     if choice <= game_data.descriptors.len() {
         // Selected a descriptor
@@ -186,13 +425,13 @@ fn select_descriptor_or_species(game_data: &GameData) -> Result<(String, bool)>
 // STEP 4: ALLOCATE BONUS POINTS
 // ==========================================
 
-fn allocate_bonus_points(
+/// Bonus points available to a type and descriptor or species
+fn bonus_total(
+    game_data: &GameData,
     character_type: &str,
     descriptor_or_species_name: &str,
     is_species: bool,
-    game_data: &GameData,
-) -> Result<(i32, i32, i32)> {
-    // Determine bonus points available
+) -> Result<u32> {
     let bonus_total = if is_species {
         // Check if species has custom bonus points
         let species = game_data
@@ -222,6 +461,24 @@ fn allocate_bonus_points(
             .unwrap_or(6)
     };
 
+    Ok(bonus_total)
+}
+
+/// Ask how to spread the bonus points, returning (might, speed, intellect)
+pub fn allocate_bonus_points(
+    game_data: &GameData,
+    character_type: &str,
+    descriptor_or_species_name: &str,
+    is_species: bool,
+    input: &mut impl BufRead,
+) -> Result<(i32, i32, i32)> {
+    let bonus_total = bonus_total(
+        game_data,
+        character_type,
+        descriptor_or_species_name,
+        is_species,
+    )?;
+
     println!("{}", "Step 4: Allocate Bonus Points".yellow().bold());
     println!(
         "You have {} bonus points to distribute among Might, Speed, and Intellect.",
@@ -236,14 +493,14 @@ fn allocate_bonus_points(
 
     print!("Might: ");
     io::stdout().flush()?;
-    let might: i32 = read_number(0, remaining)?;
+    let might: i32 = read_number(input, 0, remaining)?;
 
     remaining -= might;
     println!("Remaining: {}", remaining);
 
     print!("Speed: ");
     io::stdout().flush()?;
-    let speed: i32 = read_number(0, remaining)?;
+    let speed: i32 = read_number(input, 0, remaining)?;
 
     remaining -= speed;
     println!("Remaining: {}", remaining);
@@ -252,19 +509,51 @@ fn allocate_bonus_points(
     let intellect: i32 = remaining;
 
     println!();
+    print_bonus_points(might, speed, intellect);
+
+    Ok((might, speed, intellect))
+}
+
+/// Spread the bonus points by a named allocation instead of asking
+pub fn apply_allocation(
+    game_data: &GameData,
+    character_type: &str,
+    descriptor_or_species_name: &str,
+    is_species: bool,
+    allocation: Allocation,
+) -> Result<(i32, i32, i32)> {
+    let bonus_total = bonus_total(
+        game_data,
+        character_type,
+        descriptor_or_species_name,
+        is_species,
+    )?;
+    let (might, speed, intellect) = allocation.split(bonus_total as i32);
+
+    println!("{}", "Step 4: Allocate Bonus Points".yellow().bold());
+    print_bonus_points(might, speed, intellect);
+    println!();
+
+    Ok((might, speed, intellect))
+}
+
+fn print_bonus_points(might: i32, speed: i32, intellect: i32) {
     println!("{}", "Bonus Point Allocation:".green());
     println!("  Might:     +{}", might);
     println!("  Speed:     +{}", speed);
     println!("  Intellect: +{}", intellect);
-
-    Ok((might, speed, intellect))
 }
 
 // ==========================================
 // STEP 5: SELECT FOCUS
 // ==========================================
 
-fn select_focus(game_data: &GameData, _character_type: &str) -> Result<String> {
+/// Ask for a focus, returning its name
+pub fn select_focus(
+    game_data: &GameData,
+    _character_type: &str,
+    input: &mut impl BufRead,
+) -> Result<String> {
     println!("{}", "Step 5: Select Focus".yellow().bold());
     println!("Choose your focus (the verb in 'I am a [adjective] [noun] who [verbs]'):");
     println!();
@@ -288,7 +577,7 @@ fn select_focus(game_data: &GameData, _character_type: &str) -> Result<String> {
         }
 
         println!();
-        let choice = prompt_choice(game_data.foci.len())?;
+        let choice = prompt_choice(input, game_data.foci.len())?;
         Ok(game_data.foci[choice - 1].name.clone())
     } else {
         for (i, focus) in suitable_foci.iter().enumerate() {
@@ -302,7 +591,7 @@ fn select_focus(game_data: &GameData, _character_type: &str) -> Result<String> {
         }
 
         println!();
-        let choice = prompt_choice(suitable_foci.len())?;
+        let choice = prompt_choice(input, suitable_foci.len())?;
         let selected = &suitable_foci[choice - 1];
 
         println!("\n{} {}", "Selected:".green(), selected.name.bold());
@@ -315,7 +604,12 @@ fn select_focus(game_data: &GameData, _character_type: &str) -> Result<String> {
 // STEP 6: SELECT TYPE ABILITIES
 // ==========================================
 
-fn select_type_abilities(game_data: &GameData, character_type: &str) -> Result<Vec<String>> {
+/// Ask for the type's tier 1 abilities, returning their names
+pub fn select_type_abilities(
+    game_data: &GameData,
+    character_type: &str,
+    input: &mut impl BufRead,
+) -> Result<Vec<String>> {
     println!("{}", "Step 6: Select Type Abilities".yellow().bold());
 
     let char_type = game_data
@@ -358,7 +652,7 @@ fn select_type_abilities(game_data: &GameData, character_type: &str) -> Result<V
         print!("> ");
         io::stdout().flush()?;
 
-        let choice = prompt_choice(tier_1.abilities.len())?;
+        let choice = prompt_choice(input, tier_1.abilities.len())?;
         let ability_name = tier_1.abilities[choice - 1].name.clone();
 
         if selected.contains(&ability_name) {
@@ -377,17 +671,276 @@ fn select_type_abilities(game_data: &GameData, character_type: &str) -> Result<V
     Ok(selected)
 }
 
+/// Pick one of a type's mutually exclusive equipment options, returning its index
+pub fn select_equipment_option(
+    character_type: &CharacterType,
+    input: &mut impl BufRead,
+) -> Result<usize> {
+    println!("{}", "Starting Equipment".yellow().bold());
+    println!("{} offers a choice of starting gear:", character_type.name);
+    println!();
+
+    let labels = character_type.equipment.option_labels();
+    for (i, label) in labels.iter().enumerate() {
+        println!("{}. {}", (i + 1).to_string().cyan(), label.bold());
+    }
+
+    println!();
+    let choice = prompt_choice(input, labels.len())?;
+    println!("\n{} {}", "Selected:".green(), labels[choice - 1].bold());
+    Ok(choice - 1)
+}
+
+/// Pick one of a type's player intrusion examples, returning its index
+pub fn select_intrusion(character_type: &CharacterType, input: &mut impl BufRead) -> Result<usize> {
+    println!("{}", "Player Intrusion".yellow().bold());
+    println!(
+        "Pick an intrusion your character is known for (costs {}):",
+        character_type.intrusions.cost
+    );
+    println!();
+
+    let examples = &character_type.intrusions.examples;
+    for (i, example) in examples.iter().enumerate() {
+        println!("{}. {}", (i + 1).to_string().cyan(), example);
+    }
+
+    println!();
+    let choice = prompt_choice(input, examples.len())?;
+    println!("\n{} {}", "Selected:".green(), examples[choice - 1].bold());
+    Ok(choice - 1)
+}
+
+// ==========================================
+// STEP 7: SELECT ODDITIES
+// ==========================================
+
+fn select_oddities(game_data: &GameData, input: &mut impl BufRead) -> Result<Vec<Oddity>> {
+    println!("{}", "Step 7: Select Oddities".yellow().bold());
+
+    if game_data.oddities.is_empty() {
+        println!("{}", "No oddities available.".dimmed());
+        return Ok(Vec::new());
+    }
+
+    println!(
+        "Choose {} oddities (curiosities with no game effect):",
+        STARTING_ODDITIES.to_string().cyan().bold()
+    );
+    println!();
+
+    for (i, oddity) in game_data.oddities.iter().enumerate() {
+        println!(
+            "{}. {} - {}",
+            (i + 1).to_string().cyan(),
+            oddity.name.bold(),
+            oddity.description.dimmed()
+        );
+    }
+    let random_choice = game_data.oddities.len() + 1;
+    println!("{}. {}", random_choice.to_string().cyan(), "Random".bold());
+    println!();
+
+    let mut oddities: Vec<Oddity> = Vec::new();
+    while oddities.len() < STARTING_ODDITIES {
+        println!(
+            "Select oddity {} of {}:",
+            oddities.len() + 1,
+            STARTING_ODDITIES
+        );
+        let choice = prompt_choice(input, random_choice)?;
+
+        let oddity = if choice == random_choice {
+            use rand::seq::SliceRandom;
+            game_data
+                .oddities
+                .choose(&mut rand::thread_rng())
+                .cloned()
+                .context("No oddities available")?
+        } else {
+            game_data.oddities[choice - 1].clone()
+        };
+
+        if oddities.iter().any(|o| o.name == oddity.name) {
+            println!(
+                "{}",
+                "Already selected! Choose a different oddity.".yellow()
+            );
+            continue;
+        }
+
+        println!("{} {}", "✓ Selected:".green(), oddity.name.bold());
+        oddities.push(oddity);
+    }
+
+    Ok(oddities)
+}
+
+// ==========================================
+// STEP 8: ADVANCE TO STARTING TIER
+// ==========================================
+
+fn select_tier_advance(
+    character_type: &CharacterType,
+    sheet: &CharacterSheet,
+    tier: u32,
+    input: &mut impl BufRead,
+) -> Result<TierAdvance> {
+    println!(
+        "{}",
+        format!("Step 8: Advance to Tier {}", tier).yellow().bold()
+    );
+
+    // Abilities from this tier or lower that haven't been taken yet
+    let choices = available_tier_abilities(character_type, tier, &sheet.type_ability_names());
+    let count = tier_ability_count(character_type, tier).min(choices.len());
+
+    if count == 0 {
+        println!(
+            "{}",
+            format!("No Tier {} abilities left to choose; skipping.", tier).dimmed()
+        );
+    } else {
+        println!(
+            "Select {} abilities from Tier {} or lower:",
+            count.to_string().cyan().bold(),
+            tier
+        );
+    }
+    println!();
+
+    for (i, ability) in choices.iter().enumerate() {
+        println!(
+            "{}. {} ({}, {})",
+            (i + 1).to_string().cyan(),
+            ability.name.bold(),
+            ability.cost,
+            ability.ability_type
+        );
+        println!("   {}", ability.description.dimmed());
+    }
+
+    println!();
+
+    let mut abilities: Vec<String> = Vec::new();
+    while abilities.len() < count {
+        println!("Select ability {} of {}:", abilities.len() + 1, count);
+        let choice = prompt_choice(input, choices.len())?;
+        let ability_name = choices[choice - 1].name.clone();
+
+        if abilities.contains(&ability_name) {
+            println!(
+                "{}",
+                "Already selected! Choose a different ability.".yellow()
+            );
+            continue;
+        }
+
+        println!("{} {}", "✓ Selected:".green(), ability_name.bold());
+        abilities.push(ability_name);
+    }
+
+    println!();
+    println!(
+        "Allocate {} points to stat pools:",
+        TIER_POOL_POINTS.to_string().cyan().bold()
+    );
+
+    print!("Might: ");
+    io::stdout().flush()?;
+    let might = read_number(input, 0, TIER_POOL_POINTS)?;
+
+    print!("Speed: ");
+    io::stdout().flush()?;
+    let speed = read_number(input, 0, TIER_POOL_POINTS - might)?;
+
+    let intellect = TIER_POOL_POINTS - might - speed;
+    println!("Intellect: {}", intellect);
+
+    println!();
+    println!("Increase Edge by 1 in which stat?");
+    println!("{}. Might", "1".cyan());
+    println!("{}. Speed", "2".cyan());
+    println!("{}. Intellect", "3".cyan());
+    let edge_stat = match prompt_choice(input, 3)? {
+        1 => "Might",
+        2 => "Speed",
+        _ => "Intellect",
+    };
+
+    Ok(TierAdvance {
+        tier,
+        abilities,
+        pool_points: Pools::new(might, speed, intellect),
+        edge_stat: edge_stat.to_string(),
+    })
+}
+
+// ==========================================
+// STEP 9: REVIEW
+// ==========================================
+
+fn review_character(sheet: &CharacterSheet, input: &mut impl BufRead) -> Result<ReviewAction> {
+    println!("{}", "Step 9: Review".yellow().bold());
+    println!();
+    println!("{}", sheet.summary());
+    println!();
+
+    loop {
+        println!("{}. Save", "1".cyan());
+        println!("{}. Restart", "2".cyan());
+        println!("{}. Edit a section", "3".cyan());
+        println!();
+
+        match prompt_choice(input, 3)? {
+            1 => return Ok(ReviewAction::Save),
+            2 => {
+                if prompt_confirm(input, "Discard this character and start over?")? {
+                    return Ok(ReviewAction::Restart);
+                }
+            }
+            _ => {
+                // Tier advances only exist above tier 1
+                let sections: Vec<Section> = Section::ALL
+                    .into_iter()
+                    .filter(|s| *s != Section::TierAdvances || sheet.tier > 1)
+                    .collect();
+
+                println!();
+                println!("Which section do you want to change?");
+                for (i, section) in sections.iter().enumerate() {
+                    println!("{}. {}", (i + 1).to_string().cyan(), section.label());
+                }
+                println!();
+
+                let choice = prompt_choice(input, sections.len())?;
+                return Ok(ReviewAction::Edit(sections[choice - 1]));
+            }
+        }
+        println!();
+    }
+}
+
 // ==========================================
 // HELPER FUNCTIONS
 // ==========================================
 
+/// Read one line from stdin, treating end of input as a cancelled creation
+fn read_input_line(input: &mut impl BufRead) -> Result<String> {
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err(ChargenError::Cancelled.into());
+    }
+    Ok(line)
+}
+
 /// Prompt user for a numbered choice
-fn prompt_choice(max: usize) -> Result<usize> {
+fn prompt_choice(input: &mut impl BufRead, max: usize) -> Result<usize> {
     loop {
         print!("Enter choice (1-{}): ", max);
         io::stdout().flush()?;
 
-        let choice = read_number(1, max as i32)?;
+        let choice = read_number(input, 1, max as i32)?;
 
         if choice >= 1 && choice <= max as i32 {
             return Ok(choice as usize);
@@ -401,14 +954,10 @@ fn prompt_choice(max: usize) -> Result<usize> {
 }
 
 /// Read a number from stdin within a range
-fn read_number(min: i32, max: i32) -> Result<i32> {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+fn read_number(input: &mut impl BufRead, min: i32, max: i32) -> Result<i32> {
+    let line = read_input_line(input)?;
 
-    let number: i32 = input
-        .trim()
-        .parse()
-        .context("Please enter a valid number")?;
+    let number: i32 = line.trim().parse().context("Please enter a valid number")?;
 
     if number < min || number > max {
         anyhow::bail!("Number must be between {} and {}", min, max);
@@ -418,16 +967,14 @@ fn read_number(min: i32, max: i32) -> Result<i32> {
 }
 
 /// Prompt for yes/no confirmation
-#[allow(dead_code)]
-fn prompt_confirm(message: &str) -> Result<bool> {
+fn prompt_confirm(input: &mut impl BufRead, message: &str) -> Result<bool> {
     println!("{} (y/n): ", message);
     print!("> ");
     io::stdout().flush()?;
 
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+    let answer = read_input_line(input)?;
 
-    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+    Ok(answer.trim().eq_ignore_ascii_case("y") || answer.trim().eq_ignore_ascii_case("yes"))
 }
 
 // ==========================================
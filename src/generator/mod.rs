@@ -1,12 +1,39 @@
 // src/generator/mod.rs
 // Generator module - character generation (interactive and random)
 
+pub mod audit;
+pub mod description;
 pub mod interactive;
+pub mod preset;
 pub mod random;
+pub mod report;
+pub mod sentence;
 
-pub use interactive::{display_preview, run as run_interactive};
+pub use audit::{audit_combinations, AuditFailure, AuditReport};
+
+pub use description::{age_range, generate_physical_description, HUMAN_LIFESPAN};
+
+pub use interactive::{
+    display_preview, run as run_interactive, run_with_allocation, run_with_start_tier,
+};
+
+pub use preset::{find_preset, load_presets, parse_presets, validate_preset, Preset};
 
 pub use random::{
-    generate_batch, generate_batch_with_type, generate_random, generate_random_with_type,
-    generate_random_with_type_and_descriptor,
+    generate_batch, generate_batch_seeded, generate_batch_with_type, generate_best_of,
+    generate_constrained, generate_examples, generate_from_seed, generate_random,
+    generate_random_valid, generate_random_with_focus, generate_random_with_rng,
+    generate_random_with_type, generate_random_with_type_and_descriptor,
+    generate_random_with_type_and_focus, generate_random_with_type_weights, generate_with_options,
+    is_focus_suitable, select_best, sheet_warnings, Allocation, BonusStrategy, GenerationOptions,
+    PoolMetric, EXAMPLES_SEED,
 };
+
+pub use report::{
+    batch_report, BatchReport, PoolRange, PoolRanges, SpecializedCharacter, SPECIALIZED_RATIO,
+};
+
+pub use sentence::parse_sentence;
+
+#[cfg(feature = "parallel")]
+pub use random::{generate_batch_parallel, generate_batch_parallel_seeded};
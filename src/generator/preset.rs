@@ -0,0 +1,164 @@
+// src/generator/preset.rs
+// Named archetypes ("Frontline Tank") that constrain random generation
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::random::{BonusStrategy, GenerationOptions};
+use crate::data::{find_descriptor, find_focus, find_species, find_type, GameData};
+use crate::error::{ChargenError, Result};
+
+// ==========================================
+// PRESET MODEL
+// ==========================================
+
+/// A house archetype: a type plus preferred descriptors, foci and bonus strategy
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Preset {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Character type the preset always uses; unset leaves the type random
+    #[serde(default, rename = "type")]
+    pub character_type: Option<String>,
+    /// Descriptors or species to draw from; empty allows any
+    #[serde(default)]
+    pub descriptors: Vec<String>,
+    /// Foci to draw from; empty allows any suitable focus
+    #[serde(default)]
+    pub foci: Vec<String>,
+    #[serde(default)]
+    pub bonus_strategy: Option<BonusStrategy>,
+}
+
+// Root structure for presets.toml
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PresetsData {
+    #[serde(default)]
+    pub preset: Vec<Preset>,
+}
+
+impl Preset {
+    /// Layer this preset's constraints onto generation options
+    ///
+    /// A type already pinned in `options` wins over the preset's type.
+    pub fn apply(&self, options: &mut GenerationOptions) {
+        if options.character_type.is_none() {
+            options.character_type = self.character_type.clone();
+        }
+        options.descriptor_pool = self.descriptors.clone();
+        options.focus_pool = self.foci.clone();
+        if let Some(strategy) = self.bonus_strategy {
+            options.bonus_strategy = strategy;
+        }
+    }
+}
+
+// ==========================================
+// LOADING AND VALIDATION
+// ==========================================
+
+/// Load presets from a TOML file
+pub fn load_presets(path: &Path) -> Result<Vec<Preset>> {
+    let text = std::fs::read_to_string(path).map_err(|source| ChargenError::DataFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let data: PresetsData = toml::from_str(&text).map_err(|source| ChargenError::DataParse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(data.preset)
+}
+
+/// Parse presets from TOML text
+pub fn parse_presets(text: &str) -> Result<Vec<Preset>> {
+    let data: PresetsData = toml::from_str(text)
+        .map_err(|e| ChargenError::Preset(format!("Failed to parse presets: {}", e)))?;
+    Ok(data.preset)
+}
+
+/// Find a preset by name (case-insensitive)
+pub fn find_preset<'a>(presets: &'a [Preset], name: &str) -> Option<&'a Preset> {
+    presets.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+/// Check that every type, descriptor and focus a preset names exists
+pub fn validate_preset(preset: &Preset, game_data: &GameData) -> Result<()> {
+    let mut unknown = Vec::new();
+
+    if let Some(type_name) = &preset.character_type {
+        if find_type(&game_data.types, type_name).is_none() {
+            unknown.push(format!("type '{}'", type_name));
+        }
+    }
+    for name in &preset.descriptors {
+        if find_descriptor(&game_data.descriptors, name).is_none()
+            && find_species(&game_data.species, name).is_none()
+        {
+            unknown.push(format!("descriptor or species '{}'", name));
+        }
+    }
+    for name in &preset.foci {
+        if find_focus(&game_data.foci, name).is_none() {
+            unknown.push(format!("focus '{}'", name));
+        }
+    }
+
+    if !unknown.is_empty() {
+        return Err(ChargenError::Preset(format!(
+            "Preset '{}' refers to unknown {}",
+            preset.name,
+            unknown.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"
+        [[preset]]
+        name = "Frontline Tank"
+        type = "Glaive"
+        descriptors = ["Strong", "Tough"]
+        foci = ["Masters Defense"]
+        bonus_strategy = "type-favored"
+
+        [[preset]]
+        name = "Any Thinker"
+        foci = ["Talks to Machines"]
+    "#;
+
+    #[test]
+    fn test_parse_presets() {
+        let presets = parse_presets(FIXTURE).unwrap();
+        assert_eq!(presets.len(), 2);
+
+        let tank = find_preset(&presets, "frontline tank").unwrap();
+        assert_eq!(tank.character_type.as_deref(), Some("Glaive"));
+        assert_eq!(tank.bonus_strategy, Some(BonusStrategy::TypeFavored));
+        assert_eq!(presets[1].character_type, None);
+        assert!(presets[1].descriptors.is_empty());
+    }
+
+    #[test]
+    fn test_apply_keeps_pinned_type() {
+        let presets = parse_presets(FIXTURE).unwrap();
+        let mut options = GenerationOptions {
+            character_type: Some("Nano".to_string()),
+            ..Default::default()
+        };
+        presets[0].apply(&mut options);
+
+        assert_eq!(options.character_type.as_deref(), Some("Nano"));
+        assert_eq!(options.descriptor_pool, vec!["Strong", "Tough"]);
+        assert_eq!(options.bonus_strategy, BonusStrategy::TypeFavored);
+    }
+}
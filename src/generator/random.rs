@@ -2,255 +2,470 @@
 // Random character generation
 
 use anyhow::{Context, Result};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
 
 use crate::character::sheet::Gender;
-use crate::character::{build_character, CharacterSheet};
-use crate::data::{create_cypher_instance, GameData};
+use crate::character::{
+    advancement_warnings, apply_advance, apply_equipment_option, build_character_with_rng,
+    low_pool_warnings, primary_stat, random_intrusion_note, Advance, CharacterSheet,
+    DEFAULT_POOL_FLOOR,
+};
+use crate::data::{create_cypher_instance_with_rng, get_cyphers_by_category, Cypher, GameData};
+
+use super::description::generate_physical_description;
 
 // ==========================================
-// RANDOM GENERATION
+// GENERATION OPTIONS
 // ==========================================
 
-/// Generate a completely random character
-pub fn generate_random(game_data: &GameData) -> Result<CharacterSheet> {
-    let mut rng = rand::thread_rng();
-
-    // Random name and gender
-    let name = generate_random_name(&mut rng);
-    let gender = match rng.gen_range(0..3) {
-        0 => Gender::Male,
-        1 => Gender::Female,
-        _ => Gender::Other,
-    };
-
-    // Random type
-    let character_type = &game_data.types[rng.gen_range(0..game_data.types.len())];
-    let type_name = character_type.name.clone();
-
-    // Random descriptor or species (80% descriptor, 20% species)
-    let (descriptor_or_species, is_species) = if !game_data.species.is_empty() && rng.gen_bool(0.2)
-    {
-        // 20% chance for species
-        let species = &game_data.species[rng.gen_range(0..game_data.species.len())];
-        (species.name.clone(), true)
-    } else {
-        // 80% chance for descriptor
-        let descriptor = &game_data.descriptors[rng.gen_range(0..game_data.descriptors.len())];
-        (descriptor.name.clone(), false)
-    };
+/// How random generation spreads a character's bonus stat points
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum BonusStrategy {
+    /// Each point goes to a uniformly random stat
+    #[default]
+    Uniform,
+    /// Points lean toward the type's primary stat
+    TypeFavored,
+    /// Points are spread as evenly as possible
+    Balanced,
+}
 
-    // Random focus (prefer suitable ones)
-    let suitable_foci = crate::data::get_suitable_foci(&game_data.foci, &type_name);
-    let focus = if !suitable_foci.is_empty() {
-        suitable_foci[rng.gen_range(0..suitable_foci.len())]
-            .name
-            .clone()
-    } else {
-        game_data.foci[rng.gen_range(0..game_data.foci.len())]
-            .name
-            .clone()
-    };
+/// A named, fixed spread of bonus stat points, picked with `--allocation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Allocation {
+    /// Every point into Might
+    AllMight,
+    /// Every point into Speed
+    AllSpeed,
+    /// Every point into Intellect
+    AllIntellect,
+    /// Points spread evenly, any remainder going to Might, then Speed
+    Balanced,
+    /// Most points into Might
+    Brawler,
+    /// Most points into Speed
+    Skirmisher,
+    /// Most points into Intellect
+    Caster,
+}
 
-    // Random bonus point distribution
-    let bonus_total = if is_species {
-        game_data
-            .species
-            .iter()
-            .find(|s| s.name == descriptor_or_species)
-            .and_then(|s| s.stat_modifiers.initial_bonus_points)
-            .unwrap_or(character_type.stat_pools.bonus_points)
-    } else {
-        character_type.stat_pools.bonus_points
-    };
+impl Allocation {
+    /// Relative shares of the points for Might, Speed and Intellect
+    fn weights(self) -> [i32; 3] {
+        match self {
+            Allocation::AllMight => [1, 0, 0],
+            Allocation::AllSpeed => [0, 1, 0],
+            Allocation::AllIntellect => [0, 0, 1],
+            Allocation::Balanced => [1, 1, 1],
+            Allocation::Brawler => [4, 1, 1],
+            Allocation::Skirmisher => [1, 4, 1],
+            Allocation::Caster => [1, 1, 4],
+        }
+    }
 
-    let (might, speed, intellect) = distribute_bonus_points(&mut rng, bonus_total as i32);
+    /// Spread `total` bonus points, returning (might, speed, intellect)
+    ///
+    /// Each stat gets its share rounded down; the leftover points go to the
+    /// most heavily weighted stats first, so the three always sum to `total`.
+    pub fn split(self, total: i32) -> (i32, i32, i32) {
+        let total = total.max(0);
+        let weights = self.weights();
+        let weight_sum: i32 = weights.iter().sum();
+        let mut stats = weights.map(|w| total * w / weight_sum);
+
+        let mut order = [0, 1, 2];
+        order.sort_by_key(|&i| std::cmp::Reverse(weights[i]));
+        let leftover = total - stats.iter().sum::<i32>();
+        for &i in order.iter().take(leftover as usize) {
+            stats[i] += 1;
+        }
 
-    // Random abilities selection
-    let selected_abilities = select_random_abilities(&mut rng, character_type)?;
+        (stats[0], stats[1], stats[2])
+    }
+}
 
-    // Build the character
-    let mut character = build_character(
-        game_data,
-        name,
-        &type_name,
-        &descriptor_or_species,
-        &focus,
-        might,
-        speed,
-        intellect,
-        selected_abilities,
-    )?;
+/// Options controlling random generation
+#[derive(Debug, Clone, Default)]
+pub struct GenerationOptions {
+    pub character_type: Option<String>,
+    pub descriptor: Option<String>,
+    pub focus: Option<String>,
+    pub bonus_strategy: BonusStrategy,
+    /// Fixed spread of bonus points, used instead of `bonus_strategy`
+    pub allocation: Option<Allocation>,
+    /// Draw starting cyphers from distinct categories before repeating one
+    pub cypher_variety: bool,
+    /// Relative odds for random types; missing types weigh 1.0, empty means uniform
+    pub type_weights: HashMap<String, f64>,
+    /// XP the character starts with
+    pub starting_xp: u32,
+    /// Advances already taken, for experienced characters; applied to the sheet
+    pub starting_advances: Vec<Advance>,
+    /// Descriptors or species a random pick is drawn from; empty allows any
+    pub descriptor_pool: Vec<String>,
+    /// Foci a random pick is drawn from, preferring suitable ones; empty allows any
+    pub focus_pool: Vec<String>,
+    /// Roll an age, height, build and notable feature for the sheet
+    pub with_description: bool,
+    /// Tags added to every generated sheet
+    pub tags: Vec<String>,
+}
 
-    // Set gender after building
-    character.gender = gender;
+// ==========================================
+// RANDOM GENERATION
+// ==========================================
 
-    // ========== ADD RANDOM STARTING EQUIPMENT ==========
-    add_random_equipment(&mut rng, &mut character, game_data, &type_name)?;
-    // ===================================================
+/// Generate a completely random character
+pub fn generate_random(game_data: &GameData) -> Result<CharacterSheet> {
+    generate_from_seed(game_data, &GenerationOptions::default(), rand::random())
+}
 
+/// Generate a character from a seed, recording the seed on the sheet
+pub fn generate_from_seed(
+    game_data: &GameData,
+    options: &GenerationOptions,
+    seed: u64,
+) -> Result<CharacterSheet> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut character = generate_with_options(game_data, &mut rng, options)?;
+    character.generation_seed = Some(seed);
     Ok(character)
 }
 
+/// Generate a completely random character using the given RNG
+pub fn generate_random_with_rng(
+    game_data: &GameData,
+    rng: &mut impl Rng,
+) -> Result<CharacterSheet> {
+    generate_constrained(game_data, rng, None, None, None)
+}
+
 /// Generate a random character with a specific type
 pub fn generate_random_with_type(game_data: &GameData, type_name: &str) -> Result<CharacterSheet> {
-    let mut rng = rand::thread_rng();
-
-    // Find the specified type
-    let character_type = game_data
-        .types
-        .iter()
-        .find(|t| t.name.eq_ignore_ascii_case(type_name))
-        .context("Character type not found")?;
-
-    // Random name and gender
-    let name = generate_random_name(&mut rng);
-    let gender = match rng.gen_range(0..3) {
-        0 => Gender::Male,
-        1 => Gender::Female,
-        _ => Gender::Other,
-    };
+    generate_constrained(
+        game_data,
+        &mut rand::thread_rng(),
+        Some(type_name),
+        None,
+        None,
+    )
+}
 
-    // Random descriptor or species
-    let (descriptor_or_species, is_species) = if !game_data.species.is_empty() && rng.gen_bool(0.2)
-    {
-        let species = &game_data.species[rng.gen_range(0..game_data.species.len())];
-        (species.name.clone(), true)
-    } else {
-        let descriptor = &game_data.descriptors[rng.gen_range(0..game_data.descriptors.len())];
-        (descriptor.name.clone(), false)
-    };
+/// Generate a random character with specific type and descriptor/species
+pub fn generate_random_with_type_and_descriptor(
+    game_data: &GameData,
+    type_name: &str,
+    descriptor_or_species: &str,
+) -> Result<CharacterSheet> {
+    generate_constrained(
+        game_data,
+        &mut rand::thread_rng(),
+        Some(type_name),
+        Some(descriptor_or_species),
+        None,
+    )
+}
 
-    // Random suitable focus
-    let suitable_foci = crate::data::get_suitable_foci(&game_data.foci, &character_type.name);
-    let focus = if !suitable_foci.is_empty() {
-        suitable_foci[rng.gen_range(0..suitable_foci.len())]
-            .name
-            .clone()
-    } else {
-        game_data.foci[rng.gen_range(0..game_data.foci.len())]
-            .name
-            .clone()
-    };
+/// Generate a random character with a specific focus
+///
+/// The type is picked from the types the focus is suitable for, when any are loaded.
+pub fn generate_random_with_focus(
+    game_data: &GameData,
+    focus_name: &str,
+) -> Result<CharacterSheet> {
+    generate_constrained(
+        game_data,
+        &mut rand::thread_rng(),
+        None,
+        None,
+        Some(focus_name),
+    )
+}
 
-    // Random bonus points
-    let bonus_total = if is_species {
-        game_data
-            .species
-            .iter()
-            .find(|s| s.name == descriptor_or_species)
-            .and_then(|s| s.stat_modifiers.initial_bonus_points)
-            .unwrap_or(character_type.stat_pools.bonus_points)
-    } else {
-        character_type.stat_pools.bonus_points
+/// Generate a random character, choosing the type by relative weight
+pub fn generate_random_with_type_weights(
+    game_data: &GameData,
+    weights: &HashMap<String, f64>,
+) -> Result<CharacterSheet> {
+    let options = GenerationOptions {
+        type_weights: weights.clone(),
+        ..Default::default()
     };
+    generate_with_options(game_data, &mut rand::thread_rng(), &options)
+}
 
-    let (might, speed, intellect) = distribute_bonus_points(&mut rng, bonus_total as i32);
-
-    // Random abilities
-    let selected_abilities = select_random_abilities(&mut rng, character_type)?;
-
-    let mut character = build_character(
+/// Generate a random character with specific type and focus
+pub fn generate_random_with_type_and_focus(
+    game_data: &GameData,
+    type_name: &str,
+    focus_name: &str,
+) -> Result<CharacterSheet> {
+    generate_constrained(
         game_data,
-        name,
-        type_name,
-        &descriptor_or_species,
-        &focus,
-        might,
-        speed,
-        intellect,
-        selected_abilities,
-    )?;
-
-    // Set gender after building
-    character.gender = gender;
-
-    // ========== ADD RANDOM STARTING EQUIPMENT ==========
-    add_random_equipment(&mut rng, &mut character, game_data, type_name)?;
-
-    Ok(character)
+        &mut rand::thread_rng(),
+        Some(type_name),
+        None,
+        Some(focus_name),
+    )
 }
 
-/// Generate a random character with specific type and descriptor/species
-pub fn generate_random_with_type_and_descriptor(
+/// Generate a random character, pinning any of type, descriptor/species and focus
+pub fn generate_constrained(
     game_data: &GameData,
-    type_name: &str,
-    descriptor_or_species: &str,
+    rng: &mut impl Rng,
+    type_name: Option<&str>,
+    descriptor_or_species: Option<&str>,
+    focus_name: Option<&str>,
 ) -> Result<CharacterSheet> {
-    let mut rng = rand::thread_rng();
+    let options = GenerationOptions {
+        character_type: type_name.map(str::to_string),
+        descriptor: descriptor_or_species.map(str::to_string),
+        focus: focus_name.map(str::to_string),
+        ..Default::default()
+    };
+    generate_with_options(game_data, rng, &options)
+}
 
-    // Find the specified type
-    let character_type = game_data
-        .types
-        .iter()
-        .find(|t| t.name.eq_ignore_ascii_case(type_name))
-        .context("Character type not found")?;
+/// Generate a random character according to the given options
+pub fn generate_with_options(
+    game_data: &GameData,
+    rng: &mut impl Rng,
+    options: &GenerationOptions,
+) -> Result<CharacterSheet> {
+    ensure_generation_data(game_data)?;
+    validate_type_weights(game_data, &options.type_weights)?;
+
+    let type_name = options.character_type.as_deref();
+    let descriptor_or_species = options.descriptor.as_deref();
+    let focus_name = options.focus.as_deref();
+
+    // Resolve the pinned focus first so a random type can respect it
+    let pinned_focus = match focus_name {
+        Some(name) => Some(
+            crate::data::find_focus(&game_data.foci, name)
+                .with_context(|| format!("Focus '{}' not found", name))?,
+        ),
+        None => None,
+    };
 
-    // Check if descriptor_or_species is a species
-    let is_species = game_data
-        .species
-        .iter()
-        .any(|s| s.name.eq_ignore_ascii_case(descriptor_or_species));
+    // Type: pinned, or random (limited to the focus's suitable types if pinned)
+    let character_type = match type_name {
+        Some(name) => game_data
+            .types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .context("Character type not found")?,
+        None => {
+            let suitable_types: Vec<_> = match pinned_focus {
+                Some(focus) => game_data
+                    .types
+                    .iter()
+                    .filter(|t| focus.suits_type(&t.name))
+                    .collect(),
+                None => Vec::new(),
+            };
+            let candidates: Vec<_> = if suitable_types.is_empty() {
+                game_data.types.iter().collect()
+            } else {
+                suitable_types
+            };
+            if options.type_weights.is_empty() {
+                candidates[rng.gen_range(0..candidates.len())]
+            } else {
+                candidates
+                    .choose_weighted(rng, |t| type_weight(&options.type_weights, &t.name))
+                    .map_err(|e| anyhow::anyhow!("Cannot choose a weighted type: {}", e))?
+            }
+        }
+    };
+    let type_name = character_type.name.clone();
 
     // Random name and gender
-    let name = generate_random_name(&mut rng);
+    let name = generate_random_name(rng);
     let gender = match rng.gen_range(0..3) {
         0 => Gender::Male,
         1 => Gender::Female,
         _ => Gender::Other,
     };
 
-    // Random suitable focus
-    let suitable_foci = crate::data::get_suitable_foci(&game_data.foci, &character_type.name);
-    let focus = if !suitable_foci.is_empty() {
-        suitable_foci[rng.gen_range(0..suitable_foci.len())]
-            .name
-            .clone()
-    } else {
-        game_data.foci[rng.gen_range(0..game_data.foci.len())]
-            .name
-            .clone()
+    // Descriptor or species: pinned, or random (80% descriptor, 20% species)
+    let (descriptor_or_species, is_species) = match descriptor_or_species {
+        Some(name) => match crate::data::find_species(&game_data.species, name) {
+            Some(species) => (species.name.clone(), true),
+            None => (name.to_string(), false),
+        },
+        None if !options.descriptor_pool.is_empty() => {
+            let name = &options.descriptor_pool[rng.gen_range(0..options.descriptor_pool.len())];
+            match crate::data::find_species(&game_data.species, name) {
+                Some(species) => (species.name.clone(), true),
+                None => (name.to_string(), false),
+            }
+        }
+        None => {
+            if game_data.descriptors.is_empty()
+                || (!game_data.species.is_empty() && rng.gen_bool(0.2))
+            {
+                let species = &game_data.species[rng.gen_range(0..game_data.species.len())];
+                (species.name.clone(), true)
+            } else {
+                let descriptor =
+                    &game_data.descriptors[rng.gen_range(0..game_data.descriptors.len())];
+                (descriptor.name.clone(), false)
+            }
+        }
+    };
+
+    // Focus: pinned, or random (prefer suitable ones)
+    let focus = match pinned_focus {
+        Some(focus) => focus.name.clone(),
+        None if !options.focus_pool.is_empty() => {
+            let suitable: Vec<&String> = options
+                .focus_pool
+                .iter()
+                .filter(|name| is_focus_suitable(game_data, &type_name, name))
+                .collect();
+            let pool: Vec<&String> = if suitable.is_empty() {
+                options.focus_pool.iter().collect()
+            } else {
+                suitable
+            };
+            let name = pool[rng.gen_range(0..pool.len())];
+            crate::data::find_focus(&game_data.foci, name)
+                .with_context(|| format!("Focus '{}' not found", name))?
+                .name
+                .clone()
+        }
+        None => {
+            let suitable_foci = crate::data::get_suitable_foci(&game_data.foci, &type_name);
+            if !suitable_foci.is_empty() {
+                suitable_foci[rng.gen_range(0..suitable_foci.len())]
+                    .name
+                    .clone()
+            } else {
+                game_data.foci[rng.gen_range(0..game_data.foci.len())]
+                    .name
+                    .clone()
+            }
+        }
     };
 
-    // Random bonus points
+    // Random bonus point distribution
     let bonus_total = if is_species {
         game_data
             .species
             .iter()
-            .find(|s| s.name.eq_ignore_ascii_case(descriptor_or_species))
+            .find(|s| s.name == descriptor_or_species)
             .and_then(|s| s.stat_modifiers.initial_bonus_points)
             .unwrap_or(character_type.stat_pools.bonus_points)
     } else {
         character_type.stat_pools.bonus_points
     };
 
-    let (might, speed, intellect) = distribute_bonus_points(&mut rng, bonus_total as i32);
+    let (might, speed, intellect) = match (options.allocation, options.bonus_strategy) {
+        (Some(allocation), _) => allocation.split(bonus_total as i32),
+        (None, BonusStrategy::Uniform) => distribute_bonus_points(rng, bonus_total as i32),
+        (None, BonusStrategy::TypeFavored) => {
+            distribute_bonus_points_favored(rng, bonus_total as i32, character_type)
+        }
+        (None, BonusStrategy::Balanced) => {
+            distribute_bonus_points_balanced(rng, bonus_total as i32)
+        }
+    };
 
-    // Random abilities
-    let selected_abilities = select_random_abilities(&mut rng, character_type)?;
+    // Random abilities selection
+    let selected_abilities = select_random_abilities(rng, character_type)?;
 
-    let mut character = build_character(
+    // Build the character
+    let mut character = build_character_with_rng(
         game_data,
         name,
-        type_name,
-        descriptor_or_species,
+        &type_name,
+        &descriptor_or_species,
         &focus,
         might,
         speed,
         intellect,
         selected_abilities,
+        rng,
     )?;
 
-    // Set gender after building
+    // Set gender and starting experience after building
     character.gender = gender;
+    character.xp = options.starting_xp;
+    for advance in &options.starting_advances {
+        apply_advance(&mut character, advance)?;
+    }
+
+    // Pick one of the type's mutually exclusive equipment options
+    let equipment_options = character_type.equipment.options.len();
+    if equipment_options > 0 {
+        let choice = rng.gen_range(0..equipment_options);
+        apply_equipment_option(&mut character, character_type, choice, game_data)?;
+    }
 
     // ========== ADD RANDOM STARTING EQUIPMENT ==========
-    add_random_equipment(&mut rng, &mut character, game_data, type_name)?;
+    add_random_equipment(
+        rng,
+        &mut character,
+        game_data,
+        &type_name,
+        options.cypher_variety,
+    )?;
+    character.equipment.record_bulk(&game_data.equipment);
+    character.sort_equipment();
+    // ===================================================
+
+    character.intrusion_note = random_intrusion_note(character_type, rng);
+
+    if options.with_description {
+        let species = is_species
+            .then(|| crate::data::find_species(&game_data.species, &descriptor_or_species))
+            .flatten();
+        character.physical_description = Some(generate_physical_description(species, rng));
+    }
+    for tag in &options.tags {
+        character.add_tag(tag);
+    }
 
     Ok(character)
 }
 
+/// Bail with a friendly error if a collection needed for generation is empty
+fn ensure_generation_data(game_data: &GameData) -> Result<()> {
+    if game_data.types.is_empty() {
+        anyhow::bail!("No character types loaded; cannot generate a character");
+    }
+    if game_data.descriptors.is_empty() && game_data.species.is_empty() {
+        anyhow::bail!("No descriptors or species loaded; cannot generate a character");
+    }
+    if game_data.foci.is_empty() {
+        anyhow::bail!("No foci loaded; cannot generate a character");
+    }
+    Ok(())
+}
+
+/// Check whether a focus lists the given type as suitable
+pub fn is_focus_suitable(game_data: &GameData, type_name: &str, focus_name: &str) -> bool {
+    crate::data::get_suitable_foci(&game_data.foci, type_name)
+        .iter()
+        .any(|f| f.name.eq_ignore_ascii_case(focus_name))
+}
+
 // ==========================================
 // EQUIPMENT ASSIGNMENT
 // ==========================================
@@ -261,30 +476,76 @@ fn add_random_equipment(
     character: &mut CharacterSheet,
     game_data: &GameData,
     type_name: &str,
+    cypher_variety: bool,
 ) -> Result<()> {
     // Add weapons based on type
     assign_random_weapons(rng, character, game_data, type_name)?;
 
-    // Add cyphers (fill to cypher limit)
-    let cypher_limit = character.cypher_limit as usize;
-    let cypher_count = rng.gen_range(cypher_limit.saturating_sub(1)..=cypher_limit);
-    for _ in 0..cypher_count {
-        if let Some(cypher) = game_data.cyphers.choose(rng) {
-            let instance = create_cypher_instance(cypher);
-            let _ = character.add_cypher(instance);
-        }
+    // Add cyphers (fill the free slots up to the cypher limit)
+    let free_slots = (character.cypher_limit as usize).saturating_sub(character.cyphers.len());
+    let cypher_count = rng.gen_range(free_slots.saturating_sub(1)..=free_slots);
+    let chosen: Vec<&Cypher> = if cypher_variety {
+        choose_varied_cyphers(rng, &game_data.cyphers, cypher_count)
+    } else {
+        (0..cypher_count)
+            .filter_map(|_| game_data.cyphers.choose(rng))
+            .collect()
+    };
+    for cypher in chosen {
+        let instance = create_cypher_instance_with_rng(cypher, rng);
+        character.add_cypher(instance).map_err(anyhow::Error::msg)?;
     }
 
-    // Add exactly 1 oddity (per rules)
-    if let Some(oddity) = game_data.oddities.choose(rng) {
-        character.add_oddity(oddity.clone());
-    }
+    // Starting oddities are added by the builder
 
     // NOTE: Artifacts are NOT part of starting equipment
 
     Ok(())
 }
 
+/// Pick up to `count` distinct cyphers, one per category before any category repeats
+fn choose_varied_cyphers<'a>(
+    rng: &mut impl Rng,
+    cyphers: &'a [Cypher],
+    count: usize,
+) -> Vec<&'a Cypher> {
+    let mut categories: Vec<&str> = Vec::new();
+    for cypher in cyphers {
+        if !categories
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(&cypher.category))
+        {
+            categories.push(&cypher.category);
+        }
+    }
+
+    let mut chosen: Vec<&Cypher> = Vec::new();
+    while chosen.len() < count {
+        categories.shuffle(rng);
+        let before = chosen.len();
+
+        for category in &categories {
+            if chosen.len() >= count {
+                break;
+            }
+            let remaining: Vec<&Cypher> = get_cyphers_by_category(cyphers, category)
+                .into_iter()
+                .filter(|c| !chosen.iter().any(|picked| picked.name == c.name))
+                .collect();
+            if let Some(cypher) = remaining.choose(rng) {
+                chosen.push(cypher);
+            }
+        }
+
+        // Every cypher has been used
+        if chosen.len() == before {
+            break;
+        }
+    }
+
+    chosen
+}
+
 /// Assign random weapons based on character type rules
 fn assign_random_weapons(
     rng: &mut impl Rng,
@@ -366,9 +627,7 @@ fn assign_random_weapons_by_category(
 
     for _ in 0..count {
         if let Some(weapon) = available_weapons.choose(rng) {
-            character
-                .equipment
-                .add_weapon(format!("{} ({} damage)", weapon.name, weapon.damage));
+            character.equipment.add_weapon(weapon.label());
         }
     }
 
@@ -460,6 +719,90 @@ fn distribute_bonus_points(rng: &mut impl Rng, total: i32) -> (i32, i32, i32) {
     (might, speed, intellect)
 }
 
+/// Check that type weights name loaded types and give at least one type a chance
+fn validate_type_weights(game_data: &GameData, weights: &HashMap<String, f64>) -> Result<()> {
+    if weights.is_empty() {
+        return Ok(());
+    }
+
+    for (name, weight) in weights {
+        if !game_data
+            .types
+            .iter()
+            .any(|t| t.name.eq_ignore_ascii_case(name))
+        {
+            anyhow::bail!("Type weight given for unknown type '{}'", name);
+        }
+        if !weight.is_finite() || *weight < 0.0 {
+            anyhow::bail!("Type weight for '{}' must be a non-negative number", name);
+        }
+    }
+
+    let total: f64 = game_data
+        .types
+        .iter()
+        .map(|t| type_weight(weights, &t.name))
+        .sum();
+    if total <= 0.0 {
+        anyhow::bail!("Type weights must sum to more than zero");
+    }
+
+    Ok(())
+}
+
+/// Weight for a type name, defaulting to 1.0 when not listed
+fn type_weight(weights: &HashMap<String, f64>, type_name: &str) -> f64 {
+    weights
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(type_name))
+        .map(|(_, weight)| *weight)
+        .unwrap_or(1.0)
+}
+
+/// Distribute bonus points with half of them leaning toward the type's primary stat
+fn distribute_bonus_points_favored(
+    rng: &mut impl Rng,
+    total: i32,
+    character_type: &crate::data::CharacterType,
+) -> (i32, i32, i32) {
+    let primary = primary_stat_index(character_type);
+    let mut stats = [0; 3];
+
+    for _ in 0..total.max(0) {
+        let idx = if rng.gen_bool(0.5) {
+            primary
+        } else {
+            rng.gen_range(0..3)
+        };
+        stats[idx] += 1;
+    }
+
+    (stats[0], stats[1], stats[2])
+}
+
+/// Distribute bonus points evenly, giving any remainder to distinct random stats
+fn distribute_bonus_points_balanced(rng: &mut impl Rng, total: i32) -> (i32, i32, i32) {
+    let total = total.max(0);
+    let mut stats = [total / 3; 3];
+
+    let mut order = [0, 1, 2];
+    order.shuffle(rng);
+    for &idx in order.iter().take((total % 3) as usize) {
+        stats[idx] += 1;
+    }
+
+    (stats[0], stats[1], stats[2])
+}
+
+/// Index (0=Might, 1=Speed, 2=Intellect) of the type's primary stat
+fn primary_stat_index(character_type: &crate::data::CharacterType) -> usize {
+    match primary_stat(character_type) {
+        "Might" => 0,
+        "Speed" => 1,
+        _ => 2,
+    }
+}
+
 /// Select random abilities from tier 1
 fn select_random_abilities(
     rng: &mut impl Rng,
@@ -494,6 +837,95 @@ fn select_random_abilities(
 // BATCH GENERATION
 // ==========================================
 
+/// Pool measure used to rank candidates in best-of generation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum PoolMetric {
+    Might,
+    Speed,
+    Intellect,
+    /// Sum of all three pools
+    #[default]
+    Total,
+}
+
+impl PoolMetric {
+    /// Score a sheet by its maximum pools
+    pub fn score(&self, sheet: &CharacterSheet) -> i32 {
+        let pools = &sheet.pools.maximum;
+        match self {
+            PoolMetric::Might => pools.might,
+            PoolMetric::Speed => pools.speed,
+            PoolMetric::Intellect => pools.intellect,
+            PoolMetric::Total => pools.total(),
+        }
+    }
+}
+
+/// Keep the highest-scoring sheet, returning it with the rejected alternatives
+///
+/// Ties go to the earliest sheet. Returns `None` for an empty list.
+pub fn select_best(
+    mut sheets: Vec<CharacterSheet>,
+    metric: PoolMetric,
+) -> Option<(CharacterSheet, Vec<CharacterSheet>)> {
+    if sheets.is_empty() {
+        return None;
+    }
+
+    let mut best = 0;
+    for (index, sheet) in sheets.iter().enumerate() {
+        if metric.score(sheet) > metric.score(&sheets[best]) {
+            best = index;
+        }
+    }
+    let chosen = sheets.remove(best);
+    Some((chosen, sheets))
+}
+
+/// Generate `candidates` characters from consecutive seeds and keep the best
+pub fn generate_best_of(
+    game_data: &GameData,
+    options: &GenerationOptions,
+    base_seed: u64,
+    candidates: usize,
+    metric: PoolMetric,
+) -> Result<(CharacterSheet, Vec<CharacterSheet>)> {
+    let sheets = (0..candidates.max(1))
+        .map(|i| generate_from_seed(game_data, options, base_seed.wrapping_add(i as u64)))
+        .collect::<Result<Vec<_>>>()?;
+    select_best(sheets, metric).context("No candidates generated")
+}
+
+/// Warnings a finished random character may deserve a reroll for
+///
+/// Covers tier/advance mismatches and starting pools below `DEFAULT_POOL_FLOOR`.
+pub fn sheet_warnings(sheet: &CharacterSheet) -> Vec<String> {
+    let mut warnings = advancement_warnings(sheet);
+    warnings.extend(low_pool_warnings(sheet, DEFAULT_POOL_FLOOR));
+    warnings
+}
+
+/// Reroll from consecutive seeds until a character has no `sheet_warnings`
+///
+/// Gives up after `max_attempts` (at least one) and returns the last character
+/// with its warnings. The kept character records its seed for reproduction.
+pub fn generate_random_valid(
+    game_data: &GameData,
+    options: &GenerationOptions,
+    max_attempts: usize,
+) -> Result<(CharacterSheet, Vec<String>)> {
+    let base_seed: u64 = rand::random();
+    let mut attempt = 0;
+    loop {
+        let sheet = generate_from_seed(game_data, options, base_seed.wrapping_add(attempt))?;
+        let warnings = sheet_warnings(&sheet);
+        attempt += 1;
+        if warnings.is_empty() || attempt >= max_attempts.max(1) as u64 {
+            return Ok((sheet, warnings));
+        }
+    }
+}
+
 /// Generate multiple random characters
 pub fn generate_batch(game_data: &GameData, count: usize) -> Vec<Result<CharacterSheet>> {
     (0..count).map(|_| generate_random(game_data)).collect()
@@ -510,6 +942,69 @@ pub fn generate_batch_with_type(
         .collect()
 }
 
+/// Generate multiple random characters across threads
+///
+/// Each character gets its own RNG seeded from a random base seed plus its
+/// index. Results are returned in index order.
+#[cfg(feature = "parallel")]
+pub fn generate_batch_parallel(game_data: &GameData, count: usize) -> Vec<Result<CharacterSheet>> {
+    generate_batch_parallel_seeded(game_data, count, rand::random())
+}
+
+/// Generate multiple random characters across threads from a fixed base seed
+#[cfg(feature = "parallel")]
+pub fn generate_batch_parallel_seeded(
+    game_data: &GameData,
+    count: usize,
+    base_seed: u64,
+) -> Vec<Result<CharacterSheet>> {
+    use rayon::prelude::*;
+
+    (0..count)
+        .into_par_iter()
+        .map(|index| seeded_character(game_data, base_seed, index))
+        .collect()
+}
+
+/// Generate multiple random characters sequentially from a fixed base seed
+///
+/// Produces the same characters as `generate_batch_parallel_seeded` for the
+/// same seed.
+pub fn generate_batch_seeded(
+    game_data: &GameData,
+    count: usize,
+    base_seed: u64,
+) -> Vec<Result<CharacterSheet>> {
+    (0..count)
+        .map(|index| seeded_character(game_data, base_seed, index))
+        .collect()
+}
+
+/// Generate the character for a batch index, seeded from the base seed
+fn seeded_character(game_data: &GameData, base_seed: u64, index: usize) -> Result<CharacterSheet> {
+    let seed = base_seed.wrapping_add(index as u64);
+    generate_from_seed(game_data, &GenerationOptions::default(), seed)
+}
+
+/// Base seed for `generate_examples`; the Nth type uses `EXAMPLES_SEED + N`
+pub const EXAMPLES_SEED: u64 = 1975;
+
+/// Generate one reproducible character of each loaded type, in data order
+pub fn generate_examples(game_data: &GameData) -> Result<Vec<CharacterSheet>> {
+    game_data
+        .types
+        .iter()
+        .enumerate()
+        .map(|(index, char_type)| {
+            let options = GenerationOptions {
+                character_type: Some(char_type.name.clone()),
+                ..Default::default()
+            };
+            generate_from_seed(game_data, &options, EXAMPLES_SEED + index as u64)
+        })
+        .collect()
+}
+
 // ==========================================
 // TESTS
 // ==========================================
@@ -551,4 +1046,202 @@ mod tests {
 
         assert_eq!(might + speed + intellect, 0);
     }
+
+    #[test]
+    fn test_distribute_bonus_points_balanced() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(distribute_bonus_points_balanced(&mut rng, 6), (2, 2, 2));
+
+        let (might, speed, intellect) = distribute_bonus_points_balanced(&mut rng, 7);
+        assert_eq!(might + speed + intellect, 7);
+        assert!([might, speed, intellect].iter().all(|&p| p == 2 || p == 3));
+    }
+
+    #[test]
+    fn test_allocation_split() {
+        assert_eq!(Allocation::Balanced.split(6), (2, 2, 2));
+        assert_eq!(Allocation::Balanced.split(7), (3, 2, 2));
+        assert_eq!(Allocation::AllMight.split(6), (6, 0, 0));
+        assert_eq!(Allocation::Caster.split(6), (1, 1, 4));
+        assert_eq!(Allocation::Caster.split(3), (0, 0, 3));
+
+        // Every point is spent, whatever the weights and total
+        let allocations = [
+            Allocation::AllMight,
+            Allocation::AllSpeed,
+            Allocation::AllIntellect,
+            Allocation::Balanced,
+            Allocation::Brawler,
+            Allocation::Skirmisher,
+            Allocation::Caster,
+        ];
+        for allocation in allocations {
+            for total in 0..=12 {
+                let (might, speed, intellect) = allocation.split(total);
+                assert_eq!(might + speed + intellect, total, "{:?}", allocation);
+                assert!(might >= 0 && speed >= 0 && intellect >= 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distribute_bonus_points_favored_sums_to_total() {
+        let mut rng = rand::thread_rng();
+        let character_type = test_character_type();
+
+        assert_eq!(primary_stat_index(&character_type), 2);
+        let (might, speed, intellect) =
+            distribute_bonus_points_favored(&mut rng, 6, &character_type);
+        assert_eq!(might + speed + intellect, 6);
+    }
+
+    fn test_character_type() -> crate::data::CharacterType {
+        use crate::data::*;
+
+        CharacterType {
+            name: "Nano".to_string(),
+            source: "Discovery".to_string(),
+            tagline: "Wizard".to_string(),
+            stat_pools: StatPools {
+                might: 7,
+                speed: 9,
+                intellect: 12,
+                bonus_points: 6,
+            },
+            edge: EdgeValues {
+                might: 0,
+                speed: 0,
+                intellect: 1,
+            },
+            starting_tier: StartingTier {
+                effort: 1,
+                cypher_limit: 3,
+            },
+            intrusions: PlayerIntrusions {
+                cost: "1 XP".to_string(),
+                examples: vec![],
+            },
+            equipment: TypeEquipment {
+                weapons: vec![],
+                armor: None,
+                explorer_pack: false,
+                shins: 0,
+                other: vec![],
+                options: vec![],
+            },
+            skills: TypeSkills {
+                trained: vec![],
+                specialized: vec![],
+                inabilities: vec![],
+            },
+            special_abilities: vec![],
+            tier_abilities: vec![],
+            tier_progression: vec![],
+        }
+    }
+
+    fn test_cypher(name: &str, category: &str) -> Cypher {
+        Cypher {
+            name: name.to_string(),
+            level_formula: "1d6".to_string(),
+            cypher_type: "Subtle".to_string(),
+            category: category.to_string(),
+            effect: String::new(),
+            form: String::new(),
+            duration: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_choose_varied_cyphers() {
+        let cyphers = vec![
+            test_cypher("Detonation", "Combat"),
+            test_cypher("Ray Emitter", "Combat"),
+            test_cypher("Stim", "Healing"),
+            test_cypher("Visage Changer", "Utility"),
+        ];
+        let mut rng = rand::thread_rng();
+
+        let chosen = choose_varied_cyphers(&mut rng, &cyphers, 3);
+        let mut categories: Vec<&str> = chosen.iter().map(|c| c.category.as_str()).collect();
+        categories.sort();
+        assert_eq!(categories, vec!["Combat", "Healing", "Utility"]);
+
+        // Asking for more than exist yields each cypher once
+        let chosen = choose_varied_cyphers(&mut rng, &cyphers, 10);
+        let mut names: Vec<&str> = chosen.iter().map(|c| c.name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(chosen.len(), 4);
+        assert_eq!(names.len(), 4);
+    }
+
+    #[test]
+    fn test_validate_type_weights() {
+        let mut game_data = GameData::new();
+        game_data.types.push(test_character_type());
+
+        let mut weights = HashMap::new();
+        assert!(validate_type_weights(&game_data, &weights).is_ok());
+
+        weights.insert("nano".to_string(), 2.0);
+        assert!(validate_type_weights(&game_data, &weights).is_ok());
+        assert_eq!(type_weight(&weights, "Nano"), 2.0);
+        assert_eq!(type_weight(&weights, "Glaive"), 1.0);
+
+        weights.insert("Nano".to_string(), 0.0);
+        weights.remove("nano");
+        assert!(validate_type_weights(&game_data, &weights).is_err());
+
+        weights.insert("Gunslinger".to_string(), 1.0);
+        assert!(validate_type_weights(&game_data, &weights).is_err());
+    }
+
+    #[test]
+    fn test_generate_random_empty_data() {
+        let game_data = GameData::new();
+
+        let result = generate_random(&game_data);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("No character types loaded"));
+    }
+
+    #[test]
+    fn test_select_best() {
+        let sheet = |name: &str, might, speed, intellect| {
+            let mut sheet = CharacterSheet::new(name.to_string());
+            sheet.pools = crate::character::CharacterPools::new(crate::character::Pools::new(
+                might, speed, intellect,
+            ));
+            sheet
+        };
+        let sheets = vec![
+            sheet("Strong", 14, 9, 9),
+            sheet("Quick", 9, 14, 9),
+            sheet("Even", 11, 11, 11),
+            sheet("Also Even", 11, 11, 11),
+        ];
+
+        let (best, rejected) = select_best(sheets.clone(), PoolMetric::Speed).unwrap();
+        assert_eq!(best.name, "Quick");
+        assert_eq!(rejected.len(), 3);
+
+        // Ties keep the earliest candidate
+        let (best, _) = select_best(sheets, PoolMetric::Total).unwrap();
+        assert_eq!(best.name, "Even");
+
+        assert!(select_best(Vec::new(), PoolMetric::Might).is_none());
+    }
+
+    #[test]
+    fn test_generate_batch_empty_data() {
+        let game_data = GameData::new();
+
+        let results = generate_batch(&game_data, 3);
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.is_err()));
+    }
 }
@@ -0,0 +1,174 @@
+// src/generator/report.rs
+// Summary of a batch of generated characters, for spotting outliers
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::character::{CharacterSheet, PoolKind, Pools};
+
+/// A character is specialized when its largest pool is at least this many times its smallest
+pub const SPECIALIZED_RATIO: i32 = 2;
+
+// ==========================================
+// BATCH REPORT
+// ==========================================
+
+/// Analytics over a batch of generated characters
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BatchReport {
+    pub characters: usize,
+    pub by_type: BTreeMap<String, usize>,
+    /// Smallest and largest maximum of each pool; `None` for an empty batch
+    pub pool_ranges: Option<PoolRanges>,
+    /// "Glass cannons" and other characters with lopsided pools
+    pub specialized: Vec<SpecializedCharacter>,
+}
+
+/// Smallest and largest value of one pool across a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolRange {
+    pub min: i32,
+    pub max: i32,
+}
+
+/// Per-pool ranges across a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolRanges {
+    pub might: PoolRange,
+    pub speed: PoolRange,
+    pub intellect: PoolRange,
+}
+
+/// A character whose largest pool dwarfs its smallest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecializedCharacter {
+    pub name: String,
+    pub high: PoolKind,
+    pub high_value: i32,
+    pub low: PoolKind,
+    pub low_value: i32,
+}
+
+/// Summarize a batch of characters
+pub fn batch_report(sheets: &[CharacterSheet]) -> BatchReport {
+    let mut report = BatchReport {
+        characters: sheets.len(),
+        ..Default::default()
+    };
+
+    for sheet in sheets {
+        *report
+            .by_type
+            .entry(sheet.character_type.clone())
+            .or_default() += 1;
+    }
+
+    report.pool_ranges = pool_ranges(sheets);
+    report.specialized = sheets
+        .iter()
+        .filter_map(|sheet| specialization(&sheet.name, &sheet.pools.maximum))
+        .collect();
+
+    report
+}
+
+// ==========================================
+// POOL SPREAD
+// ==========================================
+
+/// Min and max of each pool maximum across the batch
+fn pool_ranges(sheets: &[CharacterSheet]) -> Option<PoolRanges> {
+    let range = |pool: fn(&Pools) -> i32| {
+        let values = sheets.iter().map(|s| pool(&s.pools.maximum));
+        Some(PoolRange {
+            min: values.clone().min()?,
+            max: values.max()?,
+        })
+    };
+
+    Some(PoolRanges {
+        might: range(|p| p.might)?,
+        speed: range(|p| p.speed)?,
+        intellect: range(|p| p.intellect)?,
+    })
+}
+
+/// The high and low pools of a character whose spread is at least `SPECIALIZED_RATIO`
+fn specialization(name: &str, pools: &Pools) -> Option<SpecializedCharacter> {
+    let by_pool = [
+        (PoolKind::Might, pools.might),
+        (PoolKind::Speed, pools.speed),
+        (PoolKind::Intellect, pools.intellect),
+    ];
+    let (high, high_value) = by_pool.iter().copied().max_by_key(|(_, v)| *v)?;
+    let (low, low_value) = by_pool.iter().copied().min_by_key(|(_, v)| *v)?;
+
+    (high_value > low_value && high_value >= SPECIALIZED_RATIO * low_value).then(|| {
+        SpecializedCharacter {
+            name: name.to_string(),
+            high,
+            high_value,
+            low,
+            low_value,
+        }
+    })
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::CharacterPools;
+
+    fn sheet(
+        name: &str,
+        character_type: &str,
+        might: i32,
+        speed: i32,
+        intellect: i32,
+    ) -> CharacterSheet {
+        let mut sheet = CharacterSheet::new(name.to_string());
+        sheet.character_type = character_type.to_string();
+        sheet.pools = CharacterPools::new(Pools::new(might, speed, intellect));
+        sheet
+    }
+
+    #[test]
+    fn test_lopsided_character_is_flagged() {
+        let sheets = vec![
+            sheet("Balanced", "Glaive", 11, 10, 9),
+            sheet("Glass Cannon", "Nano", 5, 9, 16),
+            sheet("Steady", "Jack", 10, 10, 10),
+        ];
+
+        let report = batch_report(&sheets);
+
+        assert_eq!(report.characters, 3);
+        assert_eq!(report.by_type.get("Nano"), Some(&1));
+        assert_eq!(
+            report.specialized,
+            vec![SpecializedCharacter {
+                name: "Glass Cannon".to_string(),
+                high: PoolKind::Intellect,
+                high_value: 16,
+                low: PoolKind::Might,
+                low_value: 5,
+            }]
+        );
+
+        let ranges = report.pool_ranges.unwrap();
+        assert_eq!(ranges.might, PoolRange { min: 5, max: 11 });
+        assert_eq!(ranges.intellect, PoolRange { min: 9, max: 16 });
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let report = batch_report(&[]);
+        assert_eq!(report.characters, 0);
+        assert!(report.pool_ranges.is_none());
+        assert!(report.specialized.is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+// src/generator/sentence.rs
+// Resolve a character sentence ("Charming Glaive who Masters Weaponry") to names
+
+use anyhow::Result;
+
+use crate::data::GameData;
+
+/// Resolve a character sentence to (type, descriptor or species, focus) names
+pub fn parse_sentence(game_data: &GameData, sentence: &str) -> Result<(String, String, String)> {
+    let text = normalize(sentence);
+    let text = ["i am an ", "i am a ", "an ", "a "]
+        .iter()
+        .find_map(|prefix| text.strip_prefix(prefix))
+        .unwrap_or(&text);
+
+    // Split "<descriptor> <type>" from "<focus>"
+    let (noun_phrase, focus_text) = [" who ", " that "]
+        .iter()
+        .find_map(|sep| text.split_once(sep))
+        .unwrap_or((text, ""));
+
+    let mut unmatched = Vec::new();
+
+    // The type is the last word(s) of the noun phrase
+    let character_type = game_data
+        .types
+        .iter()
+        .find(|t| {
+            let name = normalize(&t.name);
+            noun_phrase == name || noun_phrase.ends_with(&format!(" {}", name))
+        })
+        .map(|t| t.name.clone());
+
+    let descriptor_text = match &character_type {
+        Some(type_name) => noun_phrase[..noun_phrase.len() - normalize(type_name).len()].trim(),
+        None => {
+            unmatched.push(format!("type in '{}'", noun_phrase));
+            ""
+        }
+    };
+
+    let descriptor_names = game_data
+        .descriptors
+        .iter()
+        .map(|d| d.name.as_str())
+        .chain(game_data.species.iter().map(|s| s.name.as_str()));
+    let descriptor = match_name(descriptor_text, descriptor_names);
+    if descriptor.is_none() && character_type.is_some() {
+        unmatched.push(format!("descriptor or species '{}'", descriptor_text));
+    }
+
+    let focus = match_name(focus_text, game_data.foci.iter().map(|f| f.name.as_str()));
+    if focus.is_none() {
+        unmatched.push(format!("focus '{}'", focus_text));
+    }
+
+    match (character_type, descriptor, focus) {
+        (Some(t), Some(d), Some(f)) => Ok((t, d, f)),
+        _ => anyhow::bail!(
+            "Could not resolve sentence '{}': unmatched {}",
+            sentence.trim(),
+            unmatched.join(", ")
+        ),
+    }
+}
+
+/// Lowercase, drop punctuation and collapse whitespace
+fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '\'' {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Find the name matching `input` exactly, or the single name whose words it abbreviates
+fn match_name<'a>(input: &str, names: impl Iterator<Item = &'a str>) -> Option<String> {
+    let input = normalize(input);
+    if input.is_empty() {
+        return None;
+    }
+
+    let names: Vec<&str> = names.collect();
+    if let Some(name) = names.iter().find(|n| normalize(n) == input) {
+        return Some(name.to_string());
+    }
+
+    // Tolerate word-level differences like "Master Weaponry" vs "Masters Weaponry"
+    let input_words: Vec<&str> = input.split(' ').collect();
+    let close: Vec<&&str> = names
+        .iter()
+        .filter(|n| {
+            let name = normalize(n);
+            let name_words: Vec<&str> = name.split(' ').collect();
+            name_words.len() == input_words.len()
+                && name_words
+                    .iter()
+                    .zip(&input_words)
+                    .all(|(a, b)| a.starts_with(b) || b.starts_with(a))
+        })
+        .collect();
+
+    match close.as_slice() {
+        [name] => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("  I am a Charming,  Glaive! "),
+            "i am a charming glaive"
+        );
+    }
+
+    #[test]
+    fn test_match_name() {
+        let names = [
+            "Masters Weaponry",
+            "Masters Defense",
+            "Bears a Halo of Fire",
+        ];
+
+        assert_eq!(
+            match_name("masters weaponry", names.iter().copied()),
+            Some("Masters Weaponry".to_string())
+        );
+        assert_eq!(
+            match_name("Master Weaponry", names.iter().copied()),
+            Some("Masters Weaponry".to_string())
+        );
+        // Ambiguous abbreviations don't match
+        assert_eq!(match_name("master", names.iter().copied()), None);
+        assert_eq!(match_name("", names.iter().copied()), None);
+    }
+}
@@ -4,6 +4,7 @@
 // Module declarations
 pub mod character;
 pub mod data;
+pub mod error;
 pub mod generator;
 pub mod output;
 pub mod tui;
@@ -28,6 +29,7 @@ pub use crate::data::loader::{validate_all_comprehensive, ValidationReport};
 // Re-export commonly used types at library root
 pub use character::CharacterSheet;
 pub use data::GameData;
+pub use error::ChargenError;
 pub use generator::{generate_random, run_interactive};
 pub use output::save_character_sheet;
 
@@ -36,11 +38,39 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const NAME: &str = env!("CARGO_PKG_NAME");
 
 /// Initialize the application and validate data files
-pub fn init() -> anyhow::Result<()> {
+pub fn init() -> error::Result<()> {
     data::validate_data_files()?;
     Ok(())
 }
 
+/// Load, validate, generate: one call for scripts and downstream tests
+///
+/// Reads the default data directory (see `data::loader::data_dir`) on first
+/// use and caches the validated data for later calls. `type_name` picks the
+/// character type and `seed` makes the result reproducible; `None` leaves
+/// either to chance.
+pub fn quickgen(type_name: Option<&str>, seed: Option<u64>) -> error::Result<CharacterSheet> {
+    let game_data = cached_game_data()?;
+    let options = generator::GenerationOptions {
+        character_type: type_name.map(str::to_string),
+        ..Default::default()
+    };
+    generator::generate_from_seed(game_data, &options, seed.unwrap_or_else(rand::random))
+        .map_err(ChargenError::Generation)
+}
+
+/// Game data loaded and validated once per process for `quickgen`
+fn cached_game_data() -> error::Result<&'static GameData> {
+    static GAME_DATA: std::sync::OnceLock<GameData> = std::sync::OnceLock::new();
+
+    if let Some(game_data) = GAME_DATA.get() {
+        return Ok(game_data);
+    }
+    let game_data = data::load_all_data()?;
+    data::validate_game_data(&game_data)?;
+    Ok(GAME_DATA.get_or_init(|| game_data))
+}
+
 /// Get application information string
 pub fn app_info() -> String {
     format!(
@@ -66,4 +96,15 @@ mod tests {
         let result = init();
         assert!(result.is_ok() || result.is_err());
     }
+
+    #[test]
+    fn test_quickgen() {
+        let sheet = quickgen(Some("Glaive"), Some(42)).unwrap();
+        assert_eq!(sheet.character_type, "Glaive");
+        assert_eq!(sheet.generation_seed, Some(42));
+
+        let again = quickgen(Some("Glaive"), Some(42)).unwrap();
+        assert_eq!(again.name, sheet.name);
+        assert_eq!(again.focus, sheet.focus);
+    }
 }
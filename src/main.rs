@@ -2,10 +2,17 @@
 // Numenera Character Generator CLI
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use colored::Colorize;
+use std::path::{Path, PathBuf};
 
-use numenera_chargen::{prelude::*, validate_all_comprehensive};
+use numenera_chargen::character::Advance;
+use numenera_chargen::data::{find_focus, library_stats, CONTENT_FILES, NAME_CATEGORIES};
+use numenera_chargen::generator::{
+    audit_combinations, Allocation, BonusStrategy, GenerationOptions, PoolMetric, Preset,
+};
+use numenera_chargen::output::{MarkdownOptions, NameTemplate, OutputFormat};
+use numenera_chargen::{prelude::*, validate_all_comprehensive, ChargenError};
 
 // ==========================================
 // CLI STRUCTURE
@@ -18,32 +25,102 @@ use numenera_chargen::{prelude::*, validate_all_comprehensive};
 #[command(about = "Generate Numenera characters from Discovery and Destiny", long_about = None)]
 struct Cli {
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
+
+    /// List the supported output formats and exit
+    #[arg(long)]
+    list_formats: bool,
 
     /// Output directory for generated character sheets
     #[arg(short, long, default_value = "output", global = true)]
     output: String,
+
+    /// Prepend YAML front matter (title, type, tags, ...) to markdown output
+    #[arg(long, global = true)]
+    frontmatter: bool,
+
+    /// Replace existing character files instead of saving under a new name
+    #[arg(long, global = true)]
+    overwrite: bool,
+
+    /// Wrap long effect and ability text in markdown and compact output to this width
+    #[arg(long, global = true, value_name = "WIDTH")]
+    wrap: Option<usize>,
+
+    /// Link a portrait image (path or URL) under the markdown and trifold sheet headers
+    #[arg(long, global = true, value_name = "PATH")]
+    portrait: Option<String>,
+
+    /// Append a task difficulty reference table to markdown sheets
+    #[arg(long, global = true)]
+    with_reference: bool,
+
+    /// Name saved files from a template, e.g. "{type}_{name}_{tier}"
+    ///
+    /// Fields: {name}, {type}, {descriptor}, {focus}, {tier}, {timestamp}
+    #[arg(long, global = true, value_name = "TEMPLATE")]
+    name_template: Option<NameTemplate>,
+
+    /// Only print requested output (no banner, progress or summaries)
+    #[arg(short, long, global = true)]
+    quiet: bool,
+    /// Directory to read data files from (overrides the embedded or default data)
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Interactive character creation (step-by-step)
-    Interactive,
-    Tui,
+    Interactive {
+        /// Tier the character starts at (1-6)
+        #[arg(long, default_value = "1")]
+        start_tier: u32,
+
+        /// Spread bonus points by a named allocation instead of asking
+        #[arg(long, value_enum)]
+        allocation: Option<AllocationArg>,
+
+        /// Formats to save, comma-separated (md, json, compact)
+        #[arg(long, value_enum, value_delimiter = ',', default_value = "md")]
+        formats: Vec<OutputFormat>,
+    },
+    Tui {
+        /// Tier the character starts at (1-6)
+        #[arg(long, default_value = "1")]
+        start_tier: u32,
+
+        /// Disable cosmetic animations
+        #[arg(long)]
+        no_animations: bool,
+
+        /// Show pool and damage track status as text and symbols, not just colour
+        #[arg(long)]
+        accessible: bool,
+
+        /// Favorites file to pin and star entries from [default: favorites.toml in the data directory]
+        #[arg(long, value_name = "PATH")]
+        favorites: Option<PathBuf>,
+    },
 
     /// Generate a random character
-    Random {
-        /// Character type to generate (Glaive, Nano, Jack, Arkus, Wright, Delve)
-        #[arg(short, long)]
-        r#type: Option<String>,
+    Random(Box<RandomArgs>),
 
-        /// Descriptor or species name
-        #[arg(short, long)]
-        descriptor: Option<String>,
+    /// Print a saved character (JSON) to stdout
+    Show {
+        /// Path to a character JSON file, or - to read from stdin
+        file: PathBuf,
 
-        /// Number of characters to generate
-        #[arg(short, long, default_value = "1")]
-        count: usize,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Markdown)]
+        format: OutputFormat,
+    },
+
+    /// List saved characters in the output directory
+    Characters {
+        /// Only list characters with this tag
+        #[arg(long)]
+        tag: Option<String>,
     },
 
     /// List all available options
@@ -52,8 +129,67 @@ enum Commands {
         category: ListCategory,
     },
 
+    /// Print selectable names, one per line, for shell completion
+    Completions {
+        /// Only print names in this category
+        #[arg(value_parser = clap::builder::PossibleValuesParser::new(NAME_CATEGORIES))]
+        category: Option<String>,
+    },
+
+    /// Generate one seeded example character of each type into <output>/examples
+    Examples,
+
+    /// Summarize the content library (foci per type, pools, cypher categories, ...)
+    Stats {
+        /// Print the summary as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Build every type with every suitable focus and report combinations that fail
+    Audit {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Set one field of a content entry, keeping the file's comments and layout
+    ///
+    /// Example: set foci "Bears a Halo of Fire" theme Flame
+    Set {
+        /// Content kind to edit
+        #[arg(value_parser = clap::builder::PossibleValuesParser::new(
+            CONTENT_FILES.iter().map(|(kind, _, _)| *kind)
+        ))]
+        kind: String,
+
+        /// Name of the entry to edit
+        name: String,
+
+        /// Field to set; use dots for nested tables, e.g. stat_pools.might
+        field: String,
+
+        /// New value, parsed as the field's current type
+        value: String,
+    },
+
     /// Validate data files
-    Validate,
+    Validate {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check the data directory, output directory and terminal for common setup problems
+    Doctor,
+
+    /// Print JSON Schema for the TOML data files
+    #[cfg(feature = "schema")]
+    Schema {
+        /// Only print the schema for one file (types, descriptors, foci, ...)
+        #[arg(short, long)]
+        file: Option<String>,
+    },
 
     /// Show application info
     Info,
@@ -66,54 +202,356 @@ enum ListCategory {
     /// List all descriptors
     Descriptors,
     /// List all foci
-    Foci,
+    Foci {
+        /// Only show foci whose theme mentions this text (case-insensitive)
+        #[arg(long)]
+        theme: Option<String>,
+    },
+    /// List the distinct focus themes
+    Themes,
     /// List all species
     Species,
     /// List everything
     All,
 }
 
+/// Options for the `random` command, boxed since they dwarf the other commands
+#[derive(Args)]
+struct RandomArgs {
+    /// Character type to generate (Glaive, Nano, Jack, Arkus, Wright, Delve)
+    #[arg(short, long)]
+    r#type: Option<String>,
+
+    /// Descriptor or species name
+    #[arg(short, long)]
+    descriptor: Option<String>,
+
+    /// Focus name
+    #[arg(short, long)]
+    focus: Option<String>,
+
+    /// Character sentence to resolve, e.g. "Charming Glaive who Masters Weaponry"
+    #[arg(long, conflicts_with_all = ["type", "descriptor", "focus"])]
+    from_sentence: Option<String>,
+
+    /// Number of characters to generate
+    #[arg(short, long, default_value = "1")]
+    count: usize,
+
+    /// How to spread bonus stat points [default: uniform, or the preset's]
+    #[arg(long, value_enum)]
+    bonus_strategy: Option<BonusStrategy>,
+
+    /// Fixed spread of bonus stat points, e.g. balanced or caster
+    #[arg(long, value_enum, conflicts_with = "bonus_strategy")]
+    allocation: Option<AllocationArg>,
+
+    /// Archetype from the presets file to constrain generation, e.g. "Frontline Tank"
+    #[arg(long)]
+    preset: Option<String>,
+
+    /// Presets file to read --preset from [default: presets.toml in the data directory]
+    #[arg(long, requires = "preset")]
+    presets_file: Option<PathBuf>,
+
+    /// Draw starting cyphers from different categories
+    #[arg(long)]
+    cypher_variety: bool,
+
+    /// Roll an age, height, build and notable feature for each character
+    #[arg(long)]
+    with_description: bool,
+
+    /// Tag the characters for later filtering, e.g. --tag town-guard (repeatable)
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+
+    /// Relative odds for a random type, e.g. --type-weight Arkus=3 (repeatable)
+    #[arg(long, value_parser = parse_type_weight)]
+    type_weight: Vec<(String, f64)>,
+
+    /// XP the characters start with
+    #[arg(long, default_value = "0")]
+    xp: u32,
+
+    /// Advance the characters have already taken, e.g. "Skill Training: Climbing" (repeatable)
+    #[arg(long = "advance")]
+    advances: Vec<Advance>,
+
+    /// Generate N candidates per character and keep the best one
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    best_of: Option<u32>,
+
+    /// Pool that --best-of maximizes
+    #[arg(long, value_enum, default_value_t = PoolMetric::Total, requires = "best_of")]
+    by: PoolMetric,
+
+    /// Seed for reproducible generation (characters after the first use seed+1, seed+2, ...)
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Print the characters to stdout instead of saving them
+    #[arg(long)]
+    stdout: bool,
+
+    /// Formats to save, comma-separated (md, json, compact)
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "md")]
+    formats: Vec<OutputFormat>,
+
+    /// Format used with --stdout
+    #[arg(long, value_enum, default_value_t = OutputFormat::Markdown)]
+    format: OutputFormat,
+}
+
+/// Command-line names for `Allocation`
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum AllocationArg {
+    /// Every point into Might
+    AllMight,
+    /// Every point into Speed
+    AllSpeed,
+    /// Every point into Intellect
+    AllIntellect,
+    /// Points spread evenly, any remainder going to Might, then Speed
+    Balanced,
+    /// Most points into Might
+    Brawler,
+    /// Most points into Speed
+    Skirmisher,
+    /// Most points into Intellect
+    Caster,
+}
+
+impl From<AllocationArg> for Allocation {
+    fn from(arg: AllocationArg) -> Self {
+        match arg {
+            AllocationArg::AllMight => Allocation::AllMight,
+            AllocationArg::AllSpeed => Allocation::AllSpeed,
+            AllocationArg::AllIntellect => Allocation::AllIntellect,
+            AllocationArg::Balanced => Allocation::Balanced,
+            AllocationArg::Brawler => Allocation::Brawler,
+            AllocationArg::Skirmisher => Allocation::Skirmisher,
+            AllocationArg::Caster => Allocation::Caster,
+        }
+    }
+}
+
 // ==========================================
 // MAIN FUNCTION
 // ==========================================
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    if cli.list_formats {
+        print_formats();
+        return Ok(());
+    }
+    let Some(command) = cli.command.take() else {
+        use clap::CommandFactory;
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::MissingSubcommand,
+                "a subcommand is required unless --list-formats is given",
+            )
+            .exit();
+    };
+    // Completion output is read by shells, so keep it free of banners and progress
+    if matches!(command, Commands::Completions { .. }) {
+        cli.quiet = true;
+    }
+    // Doctor reports broken data instead of failing to load it
+    if matches!(command, Commands::Doctor) {
+        return doctor_mode(cli.data_dir.as_deref(), &cli.output);
+    }
+    let markdown_options = MarkdownOptions {
+        frontmatter: cli.frontmatter,
+        wrap_width: cli.wrap,
+        overwrite: cli.overwrite,
+        portrait: cli.portrait.clone(),
+        reference: cli.with_reference,
+        name_template: cli.name_template.clone(),
+    };
 
     // Print banner
-    print_banner();
-
-    // Initialize and load data
-    numenera_chargen::init()?;
+    if !cli.quiet {
+        print_banner();
+    }
 
-    println!("{}", "Loading game data...".cyan());
-    let game_data = load_all_data()?;
-    println!("{}", "✓ Game data loaded successfully!".green());
-    println!();
+    // Load data
+    if !cli.quiet {
+        eprintln!("{}", "Loading game data...".cyan());
+    }
+    let data_dir = cli.data_dir.clone();
+    let game_data = load_game_data(data_dir.as_deref(), cli.quiet)?;
+    if !cli.quiet {
+        eprintln!("{}", "✓ Game data loaded successfully!".green());
+        eprintln!();
+    }
 
     // Execute command
-    match cli.command {
-        Commands::Interactive => {
-            interactive_mode(&game_data, &cli.output)?;
-        }
-        Commands::Tui => {
-            numenera_chargen::tui::run(&game_data)?;
+    match command {
+        Commands::Interactive {
+            start_tier,
+            allocation,
+            formats,
+        } => {
+            interactive_mode(
+                &game_data,
+                &cli.output,
+                &formats,
+                &markdown_options,
+                start_tier,
+                allocation.map(Allocation::from),
+            )?;
         }
-        Commands::Random {
-            r#type,
-            descriptor,
-            count,
+        Commands::Tui {
+            start_tier,
+            no_animations,
+            accessible,
+            favorites,
         } => {
-            random_mode(&game_data, &cli.output, r#type, descriptor, count)?;
+            let favorites_path = favorites.unwrap_or_else(|| {
+                data_dir
+                    .clone()
+                    .unwrap_or_else(numenera_chargen::data::data_dir)
+                    .join("favorites.toml")
+            });
+            let options = numenera_chargen::tui::TuiOptions {
+                start_tier,
+                animations: !no_animations,
+                output_dir: cli.output.clone(),
+                accessible,
+                favorites_path: Some(favorites_path),
+                name_template: cli.name_template.clone(),
+            };
+            numenera_chargen::tui::run_with_options(&game_data, &options)?;
+        }
+        Commands::Random(args) => {
+            let RandomArgs {
+                r#type,
+                descriptor,
+                focus,
+                from_sentence,
+                count,
+                bonus_strategy,
+                allocation,
+                preset,
+                presets_file,
+                cypher_variety,
+                with_description,
+                tags,
+                type_weight,
+                xp,
+                advances,
+                best_of,
+                by,
+                seed,
+                stdout,
+                formats,
+                format,
+            } = *args;
+            let (r#type, descriptor, focus) = match from_sentence {
+                Some(sentence) => {
+                    let (t, d, f) =
+                        numenera_chargen::generator::parse_sentence(&game_data, &sentence)?;
+                    (Some(t), Some(d), Some(f))
+                }
+                None => (r#type, descriptor, focus),
+            };
+            let mut options = GenerationOptions {
+                character_type: r#type,
+                descriptor,
+                focus,
+                allocation: allocation.map(Allocation::from),
+                cypher_variety,
+                with_description,
+                tags,
+                type_weights: type_weight.into_iter().collect(),
+                starting_xp: xp,
+                starting_advances: advances,
+                ..Default::default()
+            };
+            if let Some(name) = preset {
+                let (source, presets) = load_preset_list(presets_file, data_dir.as_deref())?;
+                apply_preset(&game_data, &presets, &source, &name, &mut options)?;
+            }
+            // An explicit strategy wins over the preset's
+            if let Some(strategy) = bonus_strategy {
+                options.bonus_strategy = strategy;
+            }
+            let stdout_format = stdout.then_some(format);
+            random_mode(
+                &game_data,
+                &cli.output,
+                &options,
+                count,
+                best_of.map(|n| (n as usize, by)),
+                seed,
+                stdout_format,
+                &formats,
+                &markdown_options,
+                cli.quiet,
+            )?;
+        }
+        Commands::Show { file, format } => {
+            let mut character = numenera_chargen::output::read_sheet_source(&file)?;
+            // Sheets saved before sources were recorded
+            if character.sources.is_empty() {
+                character.sources = character.required_sources(&game_data);
+            }
+            println!("{}", format.render_with(&character, &markdown_options)?);
+        }
+        Commands::Characters { tag } => {
+            characters_mode(&cli.output, tag.as_deref())?;
         }
         Commands::List { category } => {
             list_mode(&game_data, category)?;
         }
-        Commands::Validate => {
+        Commands::Completions { category } => {
+            completions_mode(&game_data, category.as_deref());
+        }
+        Commands::Examples => {
+            examples_mode(&game_data, &cli.output, &markdown_options, cli.quiet)?;
+        }
+        Commands::Stats { json } => {
+            stats_mode(&game_data, json)?;
+        }
+        Commands::Audit { json } => {
+            audit_mode(&game_data, json, cli.quiet)?;
+        }
+        Commands::Set {
+            kind,
+            name,
+            field,
+            value,
+        } => {
+            let dir = data_dir
+                .clone()
+                .unwrap_or_else(numenera_chargen::data::data_dir);
+            set_mode(&dir, &kind, &name, &field, &value, cli.quiet)?;
+        }
+        Commands::Validate { json: true } => {
+            let report = validate_all_comprehensive(&load_game_data(data_dir.as_deref(), true)?)?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            if report.has_errors() {
+                std::process::exit(1);
+            }
+        }
+        Commands::Validate { json: false } if cli.quiet => {
+            // Silent on success; errors go to stderr
+            let report = validate_all_comprehensive(&load_game_data(data_dir.as_deref(), true)?)?;
+            for error in &report.errors {
+                eprintln!("{}", error);
+            }
+            if report.has_errors() {
+                std::process::exit(1);
+            }
+        }
+        Commands::Validate { json: false } => {
             println!("Validating data files...\n");
 
             // Load all data
-            match load_all_data() {
+            match load_game_data(data_dir.as_deref(), true) {
                 Ok(data) => {
                     println!("✓ All data files loaded successfully\n");
 
@@ -177,9 +615,18 @@ fn main() -> Result<()> {
                 }
             }
         }
+        #[cfg(feature = "schema")]
+        Commands::Schema { file } => {
+            let schema = match file {
+                Some(file) => numenera_chargen::data::schema::file_schema(&file)?,
+                None => numenera_chargen::data::schema::all_schemas()?,
+            };
+            println!("{}", serde_json::to_string_pretty(&schema)?);
+        }
         Commands::Info => {
-            info_mode();
+            info_mode(&game_data, cli.quiet);
         }
+        Commands::Doctor => unreachable!("handled before loading data"),
     }
 
     Ok(())
@@ -189,8 +636,21 @@ fn main() -> Result<()> {
 // COMMAND IMPLEMENTATIONS
 // ==========================================
 
-fn interactive_mode(game_data: &GameData, output_dir: &str) -> Result<()> {
-    let character = run_interactive(game_data)?;
+fn interactive_mode(
+    game_data: &GameData,
+    output_dir: &str,
+    formats: &[OutputFormat],
+    markdown_options: &MarkdownOptions,
+    start_tier: u32,
+    allocation: Option<Allocation>,
+) -> Result<()> {
+    // Ctrl+C or end of input abandons the half-made character
+    ctrlc::set_handler(|| exit_cancelled())?;
+    let character =
+        match numenera_chargen::generator::run_with_allocation(game_data, start_tier, allocation) {
+            Err(e) if matches!(e.downcast_ref(), Some(ChargenError::Cancelled)) => exit_cancelled(),
+            result => result?,
+        };
 
     // Display character summary
     println!(
@@ -211,57 +671,142 @@ fn interactive_mode(game_data: &GameData, output_dir: &str) -> Result<()> {
     println!();
 
     // Save to file
-    let filepath = save_character_sheet(&character, output_dir)?;
-    println!(
-        "{}",
-        format!("✓ Character saved to: {}", filepath).green().bold()
-    );
+    let filepaths = numenera_chargen::output::save_character_formats(
+        &character,
+        output_dir,
+        formats,
+        markdown_options,
+    )?;
+    for filepath in filepaths {
+        println!(
+            "{}",
+            format!("✓ Character saved to: {}", filepath).green().bold()
+        );
+    }
     println!();
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn random_mode(
     game_data: &GameData,
     output_dir: &str,
-    character_type: Option<String>,
-    descriptor: Option<String>,
+    options: &GenerationOptions,
     count: usize,
+    best_of: Option<(usize, PoolMetric)>,
+    seed: Option<u64>,
+    stdout_format: Option<OutputFormat>,
+    formats: &[OutputFormat],
+    markdown_options: &MarkdownOptions,
+    quiet: bool,
 ) -> Result<()> {
-    println!(
-        "{}",
-        format!("Generating {} random character(s)...", count).cyan()
-    );
-    println!();
+    let base_seed = seed.unwrap_or_else(rand::random);
+
+    // Validate the pinned focus up front
+    if let Some(focus_name) = &options.focus {
+        if find_focus(&game_data.foci, focus_name).is_none() {
+            anyhow::bail!(
+                "Focus '{}' not found. Use 'list foci' to see available foci.",
+                focus_name
+            );
+        }
+
+        if let Some(type_name) = &options.character_type {
+            if !numenera_chargen::generator::is_focus_suitable(game_data, type_name, focus_name) {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "⚠ Warning: focus '{}' is not listed as suitable for {}",
+                        focus_name, type_name
+                    )
+                    .yellow()
+                );
+                eprintln!();
+            }
+        }
+    }
+
+    // Each character draws its candidates from its own run of seeds
+    let (candidates, metric) = best_of.unwrap_or((1, PoolMetric::Total));
+    let generate = |i: usize| -> Result<CharacterSheet> {
+        let (character, rejected) = numenera_chargen::generator::generate_best_of(
+            game_data,
+            options,
+            base_seed.wrapping_add((i * candidates) as u64),
+            candidates,
+            metric,
+        )?;
+        if !quiet && !rejected.is_empty() {
+            print_rejected_candidates(&character, &rejected, metric);
+        }
+        Ok(character)
+    };
+
+    // Piped output: print the sheets and nothing else
+    if let Some(format) = stdout_format {
+        let characters = (0..count).map(generate).collect::<Result<Vec<_>>>()?;
+        print_generation_warnings(&characters);
+        println!("{}", format.render_all_with(&characters, markdown_options)?);
+        return Ok(());
+    }
+
+    if !quiet {
+        eprintln!(
+            "{}",
+            format!("Generating {} random character(s)...", count).cyan()
+        );
+        eprintln!();
+    }
 
     let mut characters = Vec::new();
 
     for i in 0..count {
-        let character = match (&character_type, &descriptor) {
-            (Some(t), Some(d)) => {
-                numenera_chargen::generator::generate_random_with_type_and_descriptor(
-                    game_data, t, d,
-                )?
-            }
-            (Some(t), None) => {
-                numenera_chargen::generator::generate_random_with_type(game_data, t)?
-            }
-            _ => generate_random(game_data)?,
-        };
+        let character = generate(i)?;
+
+        if quiet {
+            characters.push(character);
+            continue;
+        }
 
         println!(
             "{}",
             format!("Character {} of {}:", i + 1, count).yellow().bold()
         );
         println!("{}", character.character_sentence().bold());
+        if let Some(seed) = character.generation_seed {
+            println!("{}", format!("Seed: {}", seed).dimmed());
+        }
         println!();
 
         characters.push(character);
     }
+    print_generation_warnings(&characters);
+    if !quiet && count > 1 {
+        print_batch_report(&numenera_chargen::generator::batch_report(&characters));
+    }
 
     // Save all characters
-    println!("{}", "Saving characters...".cyan());
-    let filepaths = numenera_chargen::output::save_multiple_sheets(&characters, output_dir)?;
+    if !quiet {
+        eprintln!("{}", "Saving characters...".cyan());
+    }
+    let mut filepaths = Vec::new();
+    for character in &characters {
+        filepaths.extend(numenera_chargen::output::save_character_formats(
+            character,
+            output_dir,
+            formats,
+            markdown_options,
+        )?);
+    }
+
+    // Quiet mode prints bare paths for scripts
+    if quiet {
+        for path in filepaths {
+            println!("{}", path);
+        }
+        return Ok(());
+    }
 
     println!();
     println!("{}", "✓ Characters saved:".green().bold());
@@ -273,6 +818,170 @@ fn random_mode(
     Ok(())
 }
 
+/// Load game data from --data-dir, the embedded copy, or the default data directory
+fn load_game_data(data_dir: Option<&Path>, quiet: bool) -> Result<GameData> {
+    let progress = |file: &str| {
+        if !quiet {
+            eprintln!("  {}", format!("Loading {}...", file).dimmed());
+        }
+    };
+
+    let data = match data_dir {
+        Some(dir) => numenera_chargen::data::load_all_data_from_dir(dir, progress)?,
+        #[cfg(feature = "embed-data")]
+        None => numenera_chargen::data::load_all_data_embedded()?,
+        #[cfg(not(feature = "embed-data"))]
+        None => {
+            numenera_chargen::init()?;
+            numenera_chargen::data::load_all_data_with_progress(progress)?
+        }
+    };
+    Ok(data)
+}
+
+/// Read presets from --presets-file, --data-dir, or the copy the data was loaded from
+///
+/// Returns a description of where they came from, for error messages.
+fn load_preset_list(
+    presets_file: Option<PathBuf>,
+    data_dir: Option<&Path>,
+) -> Result<(String, Vec<Preset>)> {
+    let path = match (presets_file, data_dir) {
+        (Some(path), _) => path,
+        (None, Some(dir)) => dir.join("presets.toml"),
+        #[cfg(feature = "embed-data")]
+        (None, None) => {
+            let text = numenera_chargen::data::embedded_file("presets.toml").unwrap_or_default();
+            let presets = numenera_chargen::generator::parse_presets(text)?;
+            return Ok(("the embedded presets".to_string(), presets));
+        }
+        #[cfg(not(feature = "embed-data"))]
+        (None, None) => numenera_chargen::data::data_dir().join("presets.toml"),
+    };
+    let presets = numenera_chargen::generator::load_presets(&path)?;
+    Ok((path.display().to_string(), presets))
+}
+
+fn apply_preset(
+    game_data: &GameData,
+    presets: &[Preset],
+    source: &str,
+    name: &str,
+    options: &mut GenerationOptions,
+) -> Result<()> {
+    let preset = numenera_chargen::generator::find_preset(presets, name).ok_or_else(|| {
+        let names: Vec<&str> = presets.iter().map(|p| p.name.as_str()).collect();
+        anyhow::anyhow!(
+            "Preset '{}' not found in {}. Available: {}",
+            name,
+            source,
+            names.join(", ")
+        )
+    })?;
+    numenera_chargen::generator::validate_preset(preset, game_data)?;
+    preset.apply(options);
+    Ok(())
+}
+
+/// Exit status for a run interrupted by the user (128 + SIGINT)
+const EXIT_INTERRUPTED: i32 = 130;
+
+/// Report a cancelled interactive creation and exit without saving
+fn exit_cancelled() -> ! {
+    eprintln!();
+    eprintln!("{}", ChargenError::Cancelled.to_string().yellow());
+    std::process::exit(EXIT_INTERRUPTED);
+}
+
+/// Show which best-of candidates were discarded in favour of the kept one
+fn print_rejected_candidates(
+    kept: &CharacterSheet,
+    rejected: &[CharacterSheet],
+    metric: PoolMetric,
+) {
+    let metric_name = format!("{:?}", metric).to_lowercase();
+    eprintln!(
+        "{}",
+        format!(
+            "Best of {} by {}: kept {} ({})",
+            rejected.len() + 1,
+            metric_name,
+            kept.character_sentence(),
+            metric.score(kept)
+        )
+        .cyan()
+    );
+    for sheet in rejected {
+        eprintln!(
+            "{}",
+            format!(
+                "  ✗ {} ({})",
+                sheet.character_sentence(),
+                metric.score(sheet)
+            )
+            .dimmed()
+        );
+    }
+}
+
+/// Warn (once per message) about sheets whose tier and advances disagree
+/// or whose starting pools fell below the playable floor
+fn print_generation_warnings(characters: &[CharacterSheet]) {
+    use numenera_chargen::character::{
+        advancement_warnings, low_pool_warnings, DEFAULT_POOL_FLOOR,
+    };
+
+    let mut shown: Vec<String> = Vec::new();
+    for warning in characters.iter().flat_map(|c| {
+        advancement_warnings(c).into_iter().chain(
+            low_pool_warnings(c, DEFAULT_POOL_FLOOR)
+                .into_iter()
+                .map(move |w| format!("{}: {}", c.name, w)),
+        )
+    }) {
+        if !shown.contains(&warning) {
+            eprintln!("{}", format!("⚠ Warning: {}", warning).yellow());
+            shown.push(warning);
+        }
+    }
+}
+
+/// Print the batch summary: type counts, pool ranges and specialized characters
+fn print_batch_report(report: &numenera_chargen::generator::BatchReport) {
+    println!("{}", "═══ BATCH REPORT ═══".cyan().bold());
+    println!();
+    for (character_type, count) in &report.by_type {
+        println!("{:<12} {:>4}", character_type, count);
+    }
+    println!();
+
+    // Pool spread
+    if let Some(ranges) = &report.pool_ranges {
+        println!("{:<12} {:>4} {:>4}", "Pool", "Min", "Max");
+        for (pool, range) in [
+            ("Might", ranges.might),
+            ("Speed", ranges.speed),
+            ("Intellect", ranges.intellect),
+        ] {
+            println!("{:<12} {:>4} {:>4}", pool, range.min, range.max);
+        }
+        println!();
+    }
+    for c in &report.specialized {
+        println!(
+            "{}",
+            format!(
+                "Specialized: {} ({} {} vs {} {})",
+                c.name, c.high, c.high_value, c.low, c.low_value
+            )
+            .yellow()
+        );
+    }
+    if !report.specialized.is_empty() {
+        println!();
+    }
+}
+
 fn list_mode(game_data: &GameData, category: ListCategory) -> Result<()> {
     match category {
         ListCategory::Types => {
@@ -299,10 +1008,21 @@ fn list_mode(game_data: &GameData, category: ListCategory) -> Result<()> {
                 );
             }
         }
-        ListCategory::Foci => {
+        ListCategory::Foci { theme } => {
             println!("{}", "═══ FOCI ═══".cyan().bold());
             println!();
-            for focus in &game_data.foci {
+            let foci: Vec<_> = game_data
+                .foci
+                .iter()
+                .filter(|f| theme.as_deref().is_none_or(|t| f.matches_theme(t)))
+                .collect();
+            if let (Some(theme), true) = (&theme, foci.is_empty()) {
+                println!(
+                    "No foci with a theme matching '{}'. Use 'list themes' to see available themes.",
+                    theme
+                );
+            }
+            for focus in foci {
                 println!(
                     "{} - {} ({})",
                     focus.name.bold(),
@@ -315,6 +1035,13 @@ fn list_mode(game_data: &GameData, category: ListCategory) -> Result<()> {
                 );
             }
         }
+        ListCategory::Themes => {
+            println!("{}", "═══ FOCUS THEMES ═══".cyan().bold());
+            println!();
+            for theme in game_data.focus_themes() {
+                println!("{}", theme);
+            }
+        }
         ListCategory::Species => {
             println!("{}", "═══ SPECIES ═══".cyan().bold());
             println!();
@@ -328,7 +1055,7 @@ fn list_mode(game_data: &GameData, category: ListCategory) -> Result<()> {
             println!();
             list_mode(game_data, ListCategory::Descriptors)?;
             println!();
-            list_mode(game_data, ListCategory::Foci)?;
+            list_mode(game_data, ListCategory::Foci { theme: None })?;
             println!();
             list_mode(game_data, ListCategory::Species)?;
         }
@@ -338,61 +1065,439 @@ fn list_mode(game_data: &GameData, category: ListCategory) -> Result<()> {
     Ok(())
 }
 
-fn info_mode() {
+fn completions_mode(game_data: &GameData, category: Option<&str>) {
+    let names = match category {
+        Some(category) => game_data.names_in(category),
+        None => game_data
+            .all_names()
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect(),
+    };
+    for name in names {
+        println!("{}", name);
+    }
+}
+
+/// Save one reproducible character per type, replacing the previous examples
+fn examples_mode(
+    game_data: &GameData,
+    output_dir: &str,
+    markdown_options: &MarkdownOptions,
+    quiet: bool,
+) -> Result<()> {
+    let characters = numenera_chargen::generator::generate_examples(game_data)?;
+    let examples_dir = Path::new(output_dir).join("examples");
+    let options = MarkdownOptions {
+        overwrite: true,
+        ..markdown_options.clone()
+    };
+
+    for character in &characters {
+        let paths = numenera_chargen::output::save_character_formats(
+            character,
+            &examples_dir.to_string_lossy(),
+            &[OutputFormat::Markdown],
+            &options,
+        )?;
+        if quiet {
+            paths.iter().for_each(|path| println!("{}", path));
+        } else {
+            println!("{}", character.character_sentence());
+        }
+    }
+
+    if !quiet {
+        println!();
+        println!(
+            "{} {}",
+            "✓ Examples saved to".green().bold(),
+            examples_dir.display()
+        );
+    }
+    Ok(())
+}
+
+fn characters_mode(output_dir: &str, tag: Option<&str>) -> Result<()> {
+    let characters = numenera_chargen::output::find_saved_characters(Path::new(output_dir), tag)?;
+    if characters.is_empty() {
+        match tag {
+            Some(tag) => println!("No saved characters tagged '{}' in {}", tag, output_dir),
+            None => println!("No saved characters in {}", output_dir),
+        }
+        return Ok(());
+    }
+
+    for (path, sheet) in &characters {
+        let tags = if sheet.tags.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", sheet.tags.join(", "))
+        };
+        println!(
+            "{}  {}: {}{}",
+            path.display().to_string().dimmed(),
+            sheet.name.bold(),
+            sheet.character_sentence(),
+            tags.cyan()
+        );
+    }
+    Ok(())
+}
+
+fn stats_mode(game_data: &GameData, json: bool) -> Result<()> {
+    let stats = library_stats(game_data);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    println!("{}", "═══ CHARACTER TYPES ═══".cyan().bold());
+    println!();
+    println!(
+        "{:<10} {:>5} {:>6} {:>6} {:>9} {:>6} {:>6}",
+        "Type", "Foci", "Might", "Speed", "Intellect", "Bonus", "Total"
+    );
+    for t in &stats.types {
+        println!(
+            "{:<10} {:>5} {:>6} {:>6} {:>9} {:>6} {:>6}",
+            t.name, t.foci, t.might, t.speed, t.intellect, t.bonus_points, t.total_pool
+        );
+    }
+    println!(
+        "Starting pool total: average {:.1}, median {:.1}",
+        stats.average_starting_pool, stats.median_starting_pool
+    );
+    println!();
+
+    println!("{}", "═══ CYPHERS BY CATEGORY ═══".cyan().bold());
+    println!();
+    for (category, count) in &stats.cyphers_by_category {
+        println!("{:<20} {:>4}", category, count);
+    }
+    println!();
+
+    println!("{}", "═══ ARTIFACT LEVELS ═══".cyan().bold());
+    println!();
+    for (formula, count) in &stats.artifact_level_formulas {
+        println!("{:<20} {:>4}", formula, count);
+    }
+    println!();
+
+    println!("{}", "═══ ODDITIES ═══".cyan().bold());
+    println!();
+    match stats.oddity_values {
+        Some(range) => println!("Value: {}-{} shins", range.min, range.max),
+        None => println!("No oddities loaded"),
+    }
+    println!();
+    Ok(())
+}
+
+fn audit_mode(game_data: &GameData, json: bool, quiet: bool) -> Result<()> {
+    let report = audit_combinations(game_data);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for failure in &report.failures {
+            let combination = if failure.focus.is_empty() {
+                failure.character_type.clone()
+            } else {
+                format!("{} + {}", failure.character_type, failure.focus)
+            };
+            eprintln!("{} {}: {}", "✗".red(), combination, failure.error);
+        }
+        if !quiet {
+            println!(
+                "Audited {} type/focus combinations, {} failed",
+                report.checked,
+                report.failures.len()
+            );
+        }
+    }
+
+    if !report.is_clean() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Edit one field in a content file, then reload and validate the data directory
+///
+/// An edit that stops the data loading is rolled back.
+fn set_mode(
+    dir: &Path,
+    kind: &str,
+    name: &str,
+    field: &str,
+    value: &str,
+    quiet: bool,
+) -> Result<()> {
+    let (file, key) = numenera_chargen::data::content_file(kind)
+        .ok_or_else(|| anyhow::anyhow!("Unknown content kind '{}'", kind))?;
+    let path = dir.join(file);
+    let original = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+
+    let edited = numenera_chargen::data::set_field(&original, key, name, field, value)?;
+    std::fs::write(&path, &edited)?;
+
+    let data = match numenera_chargen::data::load_all_data_from_dir(dir, |_| {}) {
+        Ok(data) => data,
+        Err(e) => {
+            std::fs::write(&path, &original)?;
+            anyhow::bail!("Edit rolled back, data no longer loads: {}", e);
+        }
+    };
+
+    let report = validate_all_comprehensive(&data)?;
+    for error in &report.errors {
+        eprintln!("{} {}", "✗".red(), error);
+    }
+    if !quiet {
+        println!(
+            "{} Set {} of '{}' in {}",
+            "✓".green(),
+            field,
+            name,
+            path.display()
+        );
+        println!("{}", report.summary());
+    }
+    if report.has_errors() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Smallest terminal, in columns and rows, the TUI lays out cleanly in
+const MIN_TUI_SIZE: (u16, u16) = (80, 24);
+
+/// Run the setup checks, print a pass/fail checklist and exit 1 if any failed
+fn doctor_mode(data_dir: Option<&Path>, output_dir: &str) -> Result<()> {
+    let mut failed = 0;
+    let mut report =
+        |name: &str, result: std::result::Result<String, String>, hint: &str| match result {
+            Ok(detail) => println!("{} {}: {}", "✓".green(), name, detail),
+            Err(detail) => {
+                failed += 1;
+                println!("{} {}: {}", "✗".red(), name, detail);
+                println!("    {} {}", "→".yellow(), hint);
+            }
+        };
+
+    // Data directory and required files
+    let dir = data_dir
+        .map(Path::to_path_buf)
+        .unwrap_or_else(numenera_chargen::data::data_dir);
+    let embedded = cfg!(feature = "embed-data") && data_dir.is_none();
+    let readable = std::fs::read_dir(&dir).map(|_| ());
+    report(
+        "Data directory",
+        match (&readable, embedded) {
+            (Ok(()), _) => Ok(dir.display().to_string()),
+            (Err(_), true) => Ok("using the data embedded in this build".to_string()),
+            (Err(e), false) => Err(format!("{} ({})", dir.display(), e)),
+        },
+        "Run from the folder that contains data/, or pass --data-dir",
+    );
+    if readable.is_ok() {
+        let missing = numenera_chargen::data::missing_data_files(&dir);
+        report(
+            "Required files",
+            if missing.is_empty() {
+                Ok(format!(
+                    "all {} present",
+                    numenera_chargen::data::REQUIRED_DATA_FILES.len()
+                ))
+            } else {
+                let names: Vec<String> = missing.iter().map(|p| p.display().to_string()).collect();
+                Err(format!("missing {}", names.join(", ")))
+            },
+            "Restore the missing files from the release archive",
+        );
+    }
+
+    // Data parses and validates
+    match load_game_data(data_dir, true) {
+        Ok(game_data) => {
+            let counts = game_data.counts();
+            report(
+                "Data loads",
+                Ok(format!(
+                    "{} types, {} descriptors, {} foci",
+                    counts.types, counts.descriptors, counts.foci
+                )),
+                "",
+            );
+            let validation = validate_all_comprehensive(&game_data)
+                .map_err(|e| e.to_string())
+                .and_then(|v| match v.has_errors() {
+                    false => Ok(format!("{} warnings", v.warnings.len())),
+                    true => Err(format!("{} errors", v.errors.len())),
+                });
+            report(
+                "Data validates",
+                validation,
+                "Run 'validate' to see each problem",
+            );
+        }
+        Err(e) => report(
+            "Data loads",
+            Err(e.to_string()),
+            "Fix the file named above, or run 'validate' for details",
+        ),
+    }
+
+    // Output directory is writable
+    let probe = Path::new(output_dir).join(".doctor-write-test");
+    let writable = std::fs::create_dir_all(output_dir)
+        .and_then(|_| std::fs::write(&probe, b""))
+        .and_then(|_| std::fs::remove_file(&probe));
+    report(
+        "Output directory",
+        writable
+            .map(|_| format!("{} is writable", output_dir))
+            .map_err(|e| format!("{} ({})", output_dir, e)),
+        "Pass --output with a directory you can write to",
+    );
+
+    // Terminal can run the TUI
+    use crossterm::tty::IsTty;
+    let terminal = if !std::io::stdout().is_tty() {
+        Err("stdout is not a terminal".to_string())
+    } else {
+        match crossterm::terminal::size() {
+            Ok((cols, rows)) if cols >= MIN_TUI_SIZE.0 && rows >= MIN_TUI_SIZE.1 => {
+                let colors = crossterm::style::available_color_count();
+                if colors >= 8 {
+                    Ok(format!("{}x{}, {} colors", cols, rows, colors))
+                } else {
+                    Err(format!("only {} colors", colors))
+                }
+            }
+            Ok((cols, rows)) => Err(format!(
+                "{}x{} is smaller than {}x{}",
+                cols, rows, MIN_TUI_SIZE.0, MIN_TUI_SIZE.1
+            )),
+            Err(e) => Err(format!("size unknown ({})", e)),
+        }
+    };
+    report(
+        "Terminal",
+        terminal,
+        "Run 'tui' in a colour terminal of at least 80x24, or use 'interactive'",
+    );
+
+    println!();
+    if failed > 0 {
+        println!("{} {} check(s) failed", "✗".red(), failed);
+        std::process::exit(1);
+    }
+    println!("{} All checks passed", "✓".green());
+    Ok(())
+}
+
+/// Print each output format with its file extension and description
+fn print_formats() {
+    for format in OutputFormat::all() {
+        println!(
+            "{:<10} .{:<5} {}",
+            format.name(),
+            format.extension(),
+            format.description()
+        );
+    }
+}
+
+fn info_mode(game_data: &GameData, quiet: bool) {
+    if quiet {
+        println!("{}", numenera_chargen::app_info());
+        return;
+    }
+
+    let counts = game_data.counts();
+
     println!("{}", numenera_chargen::app_info());
     println!();
     println!("{}", "Features:".yellow().bold());
     println!("  • Interactive character creation");
     println!("  • Random character generation");
-    println!("  • 6 character types (Discovery + Destiny)");
-    println!("  • 42 descriptors");
-    println!("  • 51 foci");
-    println!("  • Species options (Varjellen, Lattimor, Mutant)");
+    println!("  • {} character types (Discovery + Destiny)", counts.types);
+    println!("  • {} descriptors", counts.descriptors);
+    println!("  • {} foci", counts.foci);
+    println!("  • {} species options", counts.species);
+    println!(
+        "  • {} cyphers, {} artifacts, {} oddities",
+        counts.cyphers, counts.artifacts, counts.oddities
+    );
     println!("  • Markdown export");
     println!();
+    println!(
+        "{} {} types, hash {}",
+        "Content:".yellow().bold(),
+        counts.types,
+        game_data.content_hash()
+    );
+    println!();
     println!("{}", "Usage:".yellow().bold());
     println!("  numenera-chargen interactive         # Step-by-step creation");
     println!("  numenera-chargen random              # Generate random character");
     println!("  numenera-chargen random -t Glaive    # Random Glaive");
+    println!("  numenera-chargen random -f \"Leads\"   # Random character with a focus");
     println!("  numenera-chargen random -c 5         # Generate 5 characters");
+    println!("  numenera-chargen random --formats md,json   # Save markdown and JSON");
+    println!("  numenera-chargen random --stdout --format json | jq   # Pipe a character");
     println!("  numenera-chargen list types          # List all types");
     println!("  numenera-chargen validate            # Validate data files");
     println!();
 }
 
+/// Parse a `NAME=WEIGHT` type weight argument
+fn parse_type_weight(arg: &str) -> Result<(String, f64), String> {
+    let (name, weight) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=WEIGHT, got '{}'", arg))?;
+    let weight = weight
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("invalid weight '{}'", weight))?;
+    Ok((name.trim().to_string(), weight))
+}
+
 // ==========================================
 // UI HELPERS
 // ==========================================
 
 fn print_banner() {
-    println!();
-    println!(
+    eprintln!();
+    eprintln!(
         "{}",
         "╔══════════════════════════════════════════════════════════╗".cyan()
     );
-    println!(
+    eprintln!(
         "{}",
         "║                                                          ║".cyan()
     );
-    println!(
+    eprintln!(
         "{}",
         "║          NUMENERA CHARACTER GENERATOR                    ║"
             .cyan()
             .bold()
     );
-    println!(
+    eprintln!(
         "{}",
         "║          Discovery + Destiny                             ║".cyan()
     );
-    println!(
+    eprintln!(
         "{}",
         "║                                                          ║".cyan()
     );
-    println!(
+    eprintln!(
         "{}",
         "╚══════════════════════════════════════════════════════════╝".cyan()
     );
-    println!();
+    eprintln!();
 }
 
 // ==========================================
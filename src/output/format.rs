@@ -0,0 +1,359 @@
+// src/output/format.rs
+// Output format selection for printing character sheets
+
+use clap::ValueEnum;
+
+use crate::character::CharacterSheet;
+use crate::error::{ChargenError, Result};
+
+use super::markdown::{
+    file_stem, format_character_sheet_with, format_compact_with, MarkdownOptions,
+};
+use super::trifold::export_party_trifold_with;
+
+/// Format used when printing character sheets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Full markdown character sheet
+    #[default]
+    #[value(alias = "md")]
+    Markdown,
+    /// Pretty-printed JSON
+    Json,
+    /// Compact one-page summary
+    Compact,
+    /// Printable HTML party handout, three characters per page
+    Trifold,
+}
+
+impl OutputFormat {
+    /// Every supported format, in the order `--list-formats` prints them
+    pub fn all() -> &'static [OutputFormat] {
+        Self::value_variants()
+    }
+
+    /// Name accepted on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Json => "json",
+            OutputFormat::Compact => "compact",
+            OutputFormat::Trifold => "trifold",
+        }
+    }
+
+    /// One-line description shown by `--list-formats`
+    pub fn description(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "Full markdown character sheet",
+            OutputFormat::Json => "Pretty-printed JSON, loadable by show, diff and the TUI",
+            OutputFormat::Compact => "Compact one-page summary",
+            OutputFormat::Trifold => "Printable HTML party handout, three characters per page",
+        }
+    }
+
+    /// File extension used when saving in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "md",
+            OutputFormat::Json => "json",
+            OutputFormat::Compact => "txt",
+            OutputFormat::Trifold => "html",
+        }
+    }
+
+    /// Render one character sheet in this format
+    pub fn render(&self, sheet: &CharacterSheet) -> Result<String> {
+        self.render_with(sheet, &MarkdownOptions::default())
+    }
+
+    /// Render one character sheet, applying markdown options to markdown output
+    /// and the portrait to the trifold
+    pub fn render_with(&self, sheet: &CharacterSheet, options: &MarkdownOptions) -> Result<String> {
+        Ok(match self {
+            OutputFormat::Markdown => format_character_sheet_with(sheet, options),
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(sheet).map_err(ChargenError::Serialize)?
+            }
+            OutputFormat::Compact => format_compact_with(sheet, options),
+            OutputFormat::Trifold => {
+                export_party_trifold_with(std::slice::from_ref(sheet), options)
+            }
+        })
+    }
+
+    /// Render several sheets; JSON becomes an array, the trifold one party document,
+    /// and text formats are separated by rules
+    pub fn render_all(&self, sheets: &[CharacterSheet]) -> Result<String> {
+        self.render_all_with(sheets, &MarkdownOptions::default())
+    }
+
+    /// Render several sheets, applying markdown options to markdown output
+    /// and the portrait to the trifold
+    pub fn render_all_with(
+        &self,
+        sheets: &[CharacterSheet],
+        options: &MarkdownOptions,
+    ) -> Result<String> {
+        match (self, sheets) {
+            (_, [sheet]) => self.render_with(sheet, options),
+            (OutputFormat::Json, _) => {
+                serde_json::to_string_pretty(sheets).map_err(ChargenError::Serialize)
+            }
+            (OutputFormat::Trifold, _) => Ok(export_party_trifold_with(sheets, options)),
+            _ => {
+                let rendered = sheets
+                    .iter()
+                    .map(|sheet| self.render_with(sheet, options))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(rendered.join("\n---\n\n"))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = ChargenError;
+
+    /// Parse a format name or alias (case-insensitive)
+    fn from_str(s: &str) -> Result<Self> {
+        <Self as ValueEnum>::from_str(s, true).map_err(|_| {
+            let names: Vec<&str> = Self::all().iter().map(|f| f.name()).collect();
+            ChargenError::UnknownFormat {
+                name: s.to_string(),
+                supported: names.join(", "),
+            }
+        })
+    }
+}
+
+/// Save a character sheet once per requested format, returning the written paths
+pub fn save_character_formats(
+    sheet: &CharacterSheet,
+    output_dir: &str,
+    formats: &[OutputFormat],
+    options: &MarkdownOptions,
+) -> Result<Vec<String>> {
+    std::fs::create_dir_all(output_dir).map_err(|source| ChargenError::Write {
+        path: output_dir.into(),
+        source,
+    })?;
+
+    // One stem for every format, so the files of a character stay together
+    let extensions: Vec<&str> = formats.iter().map(|f| f.extension()).collect();
+    let filename = file_stem(
+        &options.file_name(sheet),
+        std::path::Path::new(output_dir),
+        &extensions,
+        options.overwrite,
+    );
+    let mut saved_paths = Vec::new();
+
+    for (i, format) in formats.iter().enumerate() {
+        // Asking for the same format twice writes it once
+        if formats[..i].contains(format) {
+            continue;
+        }
+
+        let filepath =
+            std::path::Path::new(output_dir).join(format!("{}.{}", filename, format.extension()));
+        std::fs::write(&filepath, format.render_with(sheet, options)?).map_err(|source| {
+            ChargenError::Write {
+                path: filepath.clone(),
+                source,
+            }
+        })?;
+        saved_paths.push(filepath.to_string_lossy().to_string());
+    }
+
+    Ok(saved_paths)
+}
+
+/// Load a character sheet previously saved as JSON
+pub fn load_character_json(path: &std::path::Path) -> Result<CharacterSheet> {
+    let file = std::fs::File::open(path).map_err(|source| ChargenError::Read {
+        name: path.display().to_string(),
+        source,
+    })?;
+    read_character_json(file, &path.display().to_string())
+}
+
+/// Load a character sheet from a path, or from stdin when the path is `-`
+pub fn read_sheet_source(arg: &std::path::Path) -> Result<CharacterSheet> {
+    if arg == std::path::Path::new("-") {
+        read_character_json(std::io::stdin().lock(), "stdin")
+    } else {
+        load_character_json(arg)
+    }
+}
+
+/// Parse a character JSON document from any reader; `source` names it in errors
+pub fn read_character_json(mut reader: impl std::io::Read, source: &str) -> Result<CharacterSheet> {
+    let mut content = String::new();
+    reader
+        .read_to_string(&mut content)
+        .map_err(|e| ChargenError::Read {
+            name: source.to_string(),
+            source: e,
+        })?;
+    let mut sheet: CharacterSheet =
+        serde_json::from_str(&content).map_err(|e| ChargenError::CharacterJson {
+            name: source.to_string(),
+            source: e,
+        })?;
+    sheet.update_damage_track();
+    Ok(sheet)
+}
+
+/// How many folders deep `find_saved_characters` looks below the directory it is given
+const SAVED_CHARACTER_MAX_DEPTH: usize = 3;
+
+/// Saved character JSON files under `dir`, sorted by path, optionally only those tagged `tag`
+///
+/// A missing directory holds no characters. JSON files that aren't character
+/// sheets and folders that can't be read are skipped.
+pub fn find_saved_characters(
+    dir: &std::path::Path,
+    tag: Option<&str>,
+) -> Result<Vec<(std::path::PathBuf, CharacterSheet)>> {
+    let mut found = Vec::new();
+    let mut pending = vec![(dir.to_path_buf(), 0)];
+
+    while let Some((folder, depth)) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&folder) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                if depth < SAVED_CHARACTER_MAX_DEPTH {
+                    pending.push((path, depth + 1));
+                }
+            } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
+                let Ok(sheet) = load_character_json(&path) else {
+                    continue;
+                };
+                if tag.is_none_or(|tag| sheet.has_tag(tag)) {
+                    found.push((path, sheet));
+                }
+            }
+        }
+    }
+
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_json_round_trips() {
+        let sheet = CharacterSheet::new("Test Hero".to_string());
+
+        let json = OutputFormat::Json.render(&sheet).unwrap();
+        let parsed: CharacterSheet = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.name, "Test Hero");
+
+        let many = OutputFormat::Json
+            .render_all(&[sheet.clone(), sheet])
+            .unwrap();
+        let parsed: Vec<CharacterSheet> = serde_json::from_str(&many).unwrap();
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[test]
+    fn test_render_all_trifold_is_one_document() {
+        let sheets: Vec<CharacterSheet> = (1..=4)
+            .map(|i| CharacterSheet::new(format!("Hero {}", i)))
+            .collect();
+
+        let html = OutputFormat::Trifold.render_all(&sheets).unwrap();
+        assert_eq!(html.matches("<!DOCTYPE html>").count(), 1);
+        assert_eq!(html.matches("<div class=\"page\">").count(), 2);
+    }
+
+    #[test]
+    fn test_every_format_renders_and_parses() {
+        let sheet = CharacterSheet::new("Test Hero".to_string());
+
+        for format in OutputFormat::all() {
+            let rendered = format.render(&sheet).unwrap();
+            assert!(rendered.contains("Test Hero"), "{} output", format.name());
+            assert_eq!(format.name().parse::<OutputFormat>().unwrap(), *format);
+        }
+
+        assert_eq!(
+            "MD".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Markdown
+        );
+        let err = "pdf".parse::<OutputFormat>().unwrap_err().to_string();
+        assert!(err.contains("supported: markdown, json, compact, trifold"));
+    }
+
+    #[test]
+    fn test_read_character_json_from_reader() {
+        let sheet = CharacterSheet::new("Piped Hero".to_string());
+        let json = OutputFormat::Json.render(&sheet).unwrap();
+
+        let parsed = read_character_json(json.as_bytes(), "stdin").unwrap();
+        assert_eq!(parsed.name, "Piped Hero");
+
+        let err = read_character_json("not json".as_bytes(), "stdin").unwrap_err();
+        assert!(err.to_string().contains("stdin is not a saved character"));
+    }
+
+    #[test]
+    fn test_save_character_formats() {
+        let dir = std::env::temp_dir().join(format!("chargen_formats_{}", std::process::id()));
+        let dir = dir.to_string_lossy().to_string();
+        let sheet = CharacterSheet::new("Test Hero".to_string());
+
+        let formats = [
+            OutputFormat::Markdown,
+            OutputFormat::Json,
+            OutputFormat::Markdown,
+        ];
+        let paths =
+            save_character_formats(&sheet, &dir, &formats, &MarkdownOptions::default()).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("Test_Hero.md"));
+        assert!(paths[1].ends_with("Test_Hero.json"));
+        let loaded = load_character_json(std::path::Path::new(&paths[1])).unwrap();
+        assert_eq!(loaded.name, "Test Hero");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_find_saved_characters_by_tag() {
+        let dir = std::env::temp_dir().join(format!("chargen_tags_{}", std::process::id()));
+        let campaign = dir.join("campaign");
+        std::fs::create_dir_all(&campaign).unwrap();
+
+        let mut guard = CharacterSheet::new("Guard".to_string());
+        guard.add_tag("town-guard");
+        let merchant = CharacterSheet::new("Merchant".to_string());
+        std::fs::write(
+            campaign.join("guard.json"),
+            OutputFormat::Json.render(&guard).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("merchant.json"),
+            OutputFormat::Json.render(&merchant).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.json"), "{\"not\": \"a sheet\"}").unwrap();
+
+        assert_eq!(find_saved_characters(&dir, None).unwrap().len(), 2);
+        let tagged = find_saved_characters(&dir, Some("Town-Guard")).unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].0, campaign.join("guard.json"));
+        assert_eq!(tagged[0].1.tags, vec!["town-guard"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(find_saved_characters(&dir, None).unwrap().is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 // src/output/markdown.rs
 // Format character sheets as markdown
 
-use crate::character::CharacterSheet;
+use super::naming::NameTemplate;
+use super::text::{truncate_text, wrap_with_prefix};
+use crate::character::{target_number, CharacterSheet, DIFFICULTY_NAMES, RECOVERY_ACTIONS};
 use anyhow::Result;
 use std::fs;
 use std::path::Path;
@@ -10,17 +12,95 @@ use std::path::Path;
 // MARKDOWN FORMATTING
 // ==========================================
 
+/// Options for markdown output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownOptions {
+    /// Prepend YAML front matter for static site generators
+    pub frontmatter: bool,
+    /// Wrap long effect and ability lines to this many columns
+    ///
+    /// The compact format keeps one line per cypher and artifact, cut to this width.
+    pub wrap_width: Option<usize>,
+    /// Replace existing files on save instead of picking a free name
+    pub overwrite: bool,
+    /// Path or URL of a portrait image to link under the header
+    pub portrait: Option<String>,
+    /// Append a task difficulty reference table worked out from Edge and Effort
+    pub reference: bool,
+    /// Build saved file names from this template instead of the character name
+    pub name_template: Option<NameTemplate>,
+}
+
+impl MarkdownOptions {
+    /// Name a saved sheet is filed under, before sanitizing and de-duplication
+    pub(crate) fn file_name(&self, sheet: &CharacterSheet) -> String {
+        match &self.name_template {
+            Some(template) => template.expand(sheet),
+            None => sheet.name.clone(),
+        }
+    }
+
+    /// Format a list item, wrapping continuation lines under the item text
+    fn item(&self, prefix: &str, text: &str) -> String {
+        match self.wrap_width {
+            Some(width) => {
+                let indent = " ".repeat(prefix.chars().count());
+                wrap_with_prefix(prefix, &indent, text, width)
+            }
+            None => format!("{}{}\n", prefix, text),
+        }
+    }
+}
+
 /// Format a character sheet as markdown
 pub fn format_character_sheet(character: &CharacterSheet) -> String {
+    format_character_sheet_with(character, &MarkdownOptions::default())
+}
+
+/// Format a character sheet as markdown with the given options
+pub fn format_character_sheet_with(
+    character: &CharacterSheet,
+    options: &MarkdownOptions,
+) -> String {
     let mut markdown = String::new();
 
+    if options.frontmatter {
+        markdown.push_str(&format_frontmatter(character));
+    }
+
     // Header
     markdown.push_str(&format!("# {}\n\n", character.name));
     markdown.push_str(&format!("*{}*\n\n", character.character_sentence()));
+    if let Some(portrait) = &options.portrait {
+        markdown.push_str(&format!(
+            "![Portrait of {}]({})\n\n",
+            character.name, portrait
+        ));
+    }
     markdown.push_str(&format!(
         "**Tier:** {} | **XP:** {}\n\n",
         character.tier, character.xp
     ));
+    if let Some(seed) = character.generation_seed {
+        markdown.push_str(&format!("**Seed:** {}\n\n", seed));
+    }
+    if !character.sources.is_empty() {
+        markdown.push_str(&format!(
+            "**Requires:** {}\n\n",
+            character.sources.join(", ")
+        ));
+    }
+
+    if let Some(description) = &character.physical_description {
+        markdown.push_str("## Appearance\n\n");
+        markdown.push_str(&format!("- **Age:** {}\n", description.age));
+        markdown.push_str(&format!("- **Height:** {}\n", description.height));
+        markdown.push_str(&format!("- **Build:** {}\n", description.build));
+        markdown.push_str(&format!(
+            "- **Notable feature:** {}\n\n",
+            description.notable_feature
+        ));
+    }
 
     // Stat Pools
     markdown.push_str("## Stat Pools\n\n");
@@ -40,6 +120,22 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
         "\n**Effort:** {} | **Armor:** {}\n\n",
         character.effort.max_effort, character.armor
     ));
+    markdown.push_str(&format!("{}\n\n", recovery_line(character)));
+    markdown.push_str(&format!(
+        "**Status:** {} - {}\n\n",
+        character.damage_track,
+        character.damage_track.description()
+    ));
+
+    if let Some(mode) = character.current_species_mode() {
+        match &mode.favored_pool {
+            Some(pool) => markdown.push_str(&format!(
+                "**Species Mode:** {} (favors {})\n\n",
+                mode.name, pool
+            )),
+            None => markdown.push_str(&format!("**Species Mode:** {}\n\n", mode.name)),
+        }
+    }
 
     // Skills (abbreviated for brevity)
     markdown.push_str("## Skills\n\n");
@@ -52,6 +148,10 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
     // Equipment (complete)
     markdown.push_str("## Equipment\n\n");
     markdown.push_str(&format!("**Shins:** {}\n\n", character.equipment.shins));
+    let bulk = character.total_bulk();
+    if bulk > 0 {
+        markdown.push_str(&format!("**Bulk:** {}\n\n", bulk));
+    }
 
     if !character.equipment.weapons.is_empty() {
         markdown.push_str("**Weapons:** ");
@@ -69,7 +169,7 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
 
     if !character.equipment.gear.is_empty() {
         markdown.push_str("**Gear:** ");
-        markdown.push_str(&character.equipment.gear.join(", "));
+        markdown.push_str(&character.gear_display().join(", "));
         markdown.push_str("\n\n");
     }
 
@@ -90,7 +190,7 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
             ));
             markdown.push_str(&format!("   - *Form:* {}\n", cypher.form));
             markdown.push_str(&format!("   - *Duration:* {}\n", cypher.duration));
-            markdown.push_str(&format!("   - *Effect:* {}\n", cypher.effect));
+            markdown.push_str(&options.item("   - *Effect:* ", &cypher.effect));
             markdown.push('\n');
         }
     }
@@ -108,7 +208,7 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
             ));
             markdown.push_str(&format!("   - *Depletion:* {}\n", artifact.depletion));
             markdown.push_str(&format!("   - *Form:* {}\n", artifact.form));
-            markdown.push_str(&format!("   - *Effect:* {}\n", artifact.effect));
+            markdown.push_str(&options.item("   - *Effect:* ", &artifact.effect));
             markdown.push('\n');
         }
     }
@@ -123,31 +223,179 @@ pub fn format_character_sheet(character: &CharacterSheet) -> String {
                 oddity.name,
                 oddity.value_shins
             ));
-            markdown.push_str(&format!("   - {}\n", oddity.description));
+            markdown.push_str(&options.item("   - ", &oddity.description));
             markdown.push('\n');
         }
     }
 
+    // ========== TYPE ABILITIES ==========
+    if !character.type_abilities.is_empty() {
+        markdown.push_str("## Type Abilities\n\n");
+        for ability in &character.type_abilities {
+            markdown.push_str(&options.item("- ", &ability.to_string()));
+        }
+        markdown.push('\n');
+    }
+
     // Abilities, background, etc...
     markdown.push_str("## Special Abilities\n\n");
     for ability in &character.special_abilities {
         markdown.push_str(&format!("- {}\n", ability));
     }
+    if let Some(note) = &character.intrusion_note {
+        markdown.push('\n');
+        markdown.push_str(&options.item("**Player Intrusion:** ", note));
+    }
+
+    if options.reference {
+        markdown.push('\n');
+        markdown.push_str(&format_reference(character));
+    }
+
+    markdown
+}
+
+/// Highest difficulty shown in the reference table
+const REFERENCE_MAX_DIFFICULTY: u32 = 6;
+
+/// Target numbers for common difficulties and what each level of Effort costs
+fn format_reference(character: &CharacterSheet) -> String {
+    let effort = character.effort;
+    let levels: Vec<u32> = (1..=effort.max_effort).collect();
+
+    let mut markdown = String::from("## Task Difficulty Reference\n\n");
+    markdown.push_str(
+        "Roll a d20 and meet the target number (difficulty × 3). \
+         Each level of Effort eases a task by one step.\n\n",
+    );
+
+    markdown.push_str("| Difficulty | Target |");
+    for level in &levels {
+        markdown.push_str(&format!(" {} Effort |", level));
+    }
+    markdown.push_str("\n|------------|--------|");
+    markdown.push_str(&"----------|".repeat(levels.len()));
+    markdown.push('\n');
+    for difficulty in 1..=REFERENCE_MAX_DIFFICULTY {
+        markdown.push_str(&format!(
+            "| {} {} | {} |",
+            difficulty,
+            DIFFICULTY_NAMES[difficulty as usize],
+            target_number(difficulty, 0)
+        ));
+        for &level in &levels {
+            match target_number(difficulty, level) {
+                0 => markdown.push_str(" Auto |"),
+                target => markdown.push_str(&format!(" {} |", target)),
+            }
+        }
+        markdown.push('\n');
+    }
+
+    markdown.push_str("\n| Effort | Might | Speed | Intellect |\n");
+    markdown.push_str("|--------|-------|-------|-----------|\n");
+    for &level in &levels {
+        let cost = effort.calculate_cost(level);
+        markdown.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            level,
+            character.edge.apply_to_cost("might", cost),
+            character.edge.apply_to_cost("speed", cost),
+            character.edge.apply_to_cost("intellect", cost)
+        ));
+    }
+    if effort.max_effort > 0 {
+        markdown.push_str(&format!(
+            "\nPool costs are after Edge. With {} Effort you succeed automatically \
+             at difficulty {} or lower.\n",
+            effort.max_effort, effort.max_effort
+        ));
+    }
 
     markdown
 }
 
+/// YAML front matter block (Jekyll/Hugo) describing the character
+fn format_frontmatter(character: &CharacterSheet) -> String {
+    let descriptor = character
+        .species
+        .as_ref()
+        .or(character.descriptor.as_ref())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut slugs: Vec<String> = Vec::new();
+    let names = [
+        "numenera",
+        &character.character_type,
+        &descriptor,
+        &character.focus,
+    ];
+    for name in names
+        .into_iter()
+        .chain(character.tags.iter().map(String::as_str))
+    {
+        let slug = slugify(name);
+        if !slug.is_empty() && !slugs.contains(&slug) {
+            slugs.push(slug);
+        }
+    }
+    let tags: Vec<String> = slugs.iter().map(|s| yaml_string(s)).collect();
+
+    let mut yaml = String::from("---\n");
+    yaml.push_str(&format!("title: {}\n", yaml_string(&character.name)));
+    yaml.push_str(&format!(
+        "type: {}\n",
+        yaml_string(&character.character_type)
+    ));
+    yaml.push_str(&format!("descriptor: {}\n", yaml_string(&descriptor)));
+    yaml.push_str(&format!("focus: {}\n", yaml_string(&character.focus)));
+    yaml.push_str(&format!("tier: {}\n", character.tier));
+    yaml.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+    yaml.push_str("---\n\n");
+    yaml
+}
+
+/// Double-quoted YAML scalar
+fn yaml_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Lowercase, hyphen-separated tag
+fn slugify(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 /// Save a character sheet to a markdown file
 pub fn save_character_sheet(sheet: &CharacterSheet, output_dir: &str) -> Result<String> {
+    save_character_sheet_with(sheet, output_dir, &MarkdownOptions::default())
+}
+
+/// Save a character sheet to a markdown file with the given options
+pub fn save_character_sheet_with(
+    sheet: &CharacterSheet,
+    output_dir: &str,
+    options: &MarkdownOptions,
+) -> Result<String> {
     // Ensure output directory exists
     fs::create_dir_all(output_dir)?;
 
-    // Generate filename from character name (sanitized)
-    let filename = sanitize_filename(&sheet.name);
+    // Generate filename from the name template or character name, avoiding existing sheets
+    let filename = file_stem(
+        &options.file_name(sheet),
+        Path::new(output_dir),
+        &["md"],
+        options.overwrite,
+    );
     let filepath = Path::new(output_dir).join(format!("{}.md", filename));
 
     // Format the character sheet
-    let markdown = format_character_sheet(sheet);
+    let markdown = format_character_sheet_with(sheet, options);
 
     // Write to file
     fs::write(&filepath, markdown)?;
@@ -157,10 +405,19 @@ pub fn save_character_sheet(sheet: &CharacterSheet, output_dir: &str) -> Result<
 
 /// Save multiple character sheets to markdown files
 pub fn save_multiple_sheets(sheets: &[CharacterSheet], output_dir: &str) -> Result<Vec<String>> {
+    save_multiple_sheets_with(sheets, output_dir, &MarkdownOptions::default())
+}
+
+/// Save multiple character sheets to markdown files with the given options
+pub fn save_multiple_sheets_with(
+    sheets: &[CharacterSheet],
+    output_dir: &str,
+    options: &MarkdownOptions,
+) -> Result<Vec<String>> {
     let mut saved_paths = Vec::new();
 
     for sheet in sheets {
-        let path = save_character_sheet(sheet, output_dir)?;
+        let path = save_character_sheet_with(sheet, output_dir, options)?;
         saved_paths.push(path);
     }
 
@@ -171,11 +428,51 @@ pub fn save_multiple_sheets(sheets: &[CharacterSheet], output_dir: &str) -> Resu
 // HELPER FUNCTIONS
 // ==========================================
 
+/// Filename stem for a character, without extension
+///
+/// Unless `overwrite` is set, a numeric suffix ("Bob_2") is added while a file
+/// with that stem and any of `extensions` already exists in `dir`. Empty names
+/// become "character".
+pub(crate) fn file_stem(name: &str, dir: &Path, extensions: &[&str], overwrite: bool) -> String {
+    let base = match sanitize_filename(name.trim()) {
+        stem if stem.is_empty() => "character".to_string(),
+        stem => stem,
+    };
+    if overwrite {
+        return base;
+    }
+
+    let taken = |stem: &str| {
+        extensions
+            .iter()
+            .any(|ext| dir.join(format!("{}.{}", stem, ext)).exists())
+    };
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}_{}", base, n))
+        .find(|stem| !taken(stem))
+        .unwrap_or(base)
+}
+
+/// Recovery roll and the day's four recovery slots as checkboxes
+pub(crate) fn recovery_line(sheet: &CharacterSheet) -> String {
+    let slots: Vec<String> = RECOVERY_ACTIONS
+        .iter()
+        .map(|a| format!("☐ {}", a))
+        .collect();
+    format!("**Recovery:** {} | {}", sheet.recovery(), slots.join(" "))
+}
+
 /// Sanitize a string to be a valid filename
-fn sanitize_filename(name: &str) -> String {
+///
+/// Letters and digits in any script are kept, so "Zoë" stays "Zoë" rather than "Zo-".
+pub(crate) fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            c if c.is_alphanumeric() => c,
+            '-' | '_' => c,
             ' ' => '_',
             _ => '-',
         })
@@ -186,13 +483,24 @@ fn sanitize_filename(name: &str) -> String {
 // COMPACT FORMAT
 // ==========================================
 
+/// Width of compact cypher and artifact lines when no wrap width is set
+const COMPACT_LINE_WIDTH: usize = 80;
+
 /// Format a character sheet in a compact one-page format
 pub fn format_compact(sheet: &CharacterSheet) -> String {
+    format_compact_with(sheet, &MarkdownOptions::default())
+}
+
+/// Format a character sheet in the compact format with the given options
+pub fn format_compact_with(sheet: &CharacterSheet, options: &MarkdownOptions) -> String {
     let mut output = String::new();
 
     // Header
     output.push_str(&format!("# {} (Tier {})\n\n", sheet.name, sheet.tier));
     output.push_str(&format!("*{}*\n\n", sheet.character_sentence()));
+    if let Some(description) = &sheet.physical_description {
+        output.push_str(&format!("**Appearance:** {}\n\n", description));
+    }
 
     // Stats in one line
     output.push_str(&format!(
@@ -209,6 +517,7 @@ pub fn format_compact(sheet: &CharacterSheet) -> String {
         sheet.effort.max_effort,
         sheet.armor
     ));
+    output.push_str(&format!("{}\n\n", recovery_line(sheet)));
 
     // Skills - condensed
     if !sheet.skills.trained.is_empty() || !sheet.skills.specialized.is_empty() {
@@ -251,7 +560,12 @@ pub fn format_compact(sheet: &CharacterSheet) -> String {
     }
 
     if !sheet.equipment.gear.is_empty() {
-        output.push_str(&format!("**Gear:** {}\n", sheet.equipment.gear.join(", ")));
+        output.push_str(&format!("**Gear:** {}\n", sheet.gear_display().join(", ")));
+    }
+
+    let bulk = sheet.total_bulk();
+    if bulk > 0 {
+        output.push_str(&format!("**Bulk:** {}\n", bulk));
     }
 
     output.push_str(&format!(
@@ -261,11 +575,33 @@ pub fn format_compact(sheet: &CharacterSheet) -> String {
         sheet.cypher_limit
     ));
 
+    // Cyphers and artifacts - one line each
+    let width = options.wrap_width.unwrap_or(COMPACT_LINE_WIDTH);
+    if !sheet.cyphers.is_empty() {
+        output.push_str("**Cyphers:**\n");
+        for cypher in &sheet.cyphers {
+            let line = format!("- {} (L{}): {}", cypher.name, cypher.level, cypher.effect);
+            output.push_str(&format!("{}\n", truncate_text(&line, width)));
+        }
+        output.push('\n');
+    }
+    if !sheet.artifacts.is_empty() {
+        output.push_str("**Artifacts:**\n");
+        for artifact in &sheet.artifacts {
+            let line = format!(
+                "- {} (L{}, {}): {}",
+                artifact.name, artifact.level, artifact.depletion, artifact.effect
+            );
+            output.push_str(&format!("{}\n", truncate_text(&line, width)));
+        }
+        output.push('\n');
+    }
+
     // Abilities - brief
     output.push_str("**Abilities:**\n");
-    output.push_str(&format!("- Focus: {}\n", sheet.focus_ability));
+    output.push_str(&options.item("- Focus: ", &sheet.focus_ability));
     for ability in &sheet.type_abilities {
-        output.push_str(&format!("- {}\n", ability));
+        output.push_str(&options.item("- ", &ability.to_string()));
     }
 
     output
@@ -306,14 +642,175 @@ mod tests {
     }
 
     #[test]
-    fn test_format_compact() {
+    fn test_format_character_sheet_type_abilities() {
+        let mut sheet = create_test_sheet();
+        sheet.type_abilities = vec![crate::character::SelectedAbility {
+            name: "Fleet of Foot".to_string(),
+            cost: "1+ Speed point".to_string(),
+            ability_type: "Action".to_string(),
+            description: "You move a short distance.".to_string(),
+        }];
+
+        let markdown = format_character_sheet(&sheet);
+        assert!(markdown.contains(
+            "## Type Abilities\n\n- Fleet of Foot (1+ Speed point, Action): You move a short distance.\n"
+        ));
+    }
+
+    #[test]
+    fn test_format_character_sheet_reference() {
+        let mut sheet = create_test_sheet();
+        sheet.effort = Effort::new(2);
+        assert!(!format_character_sheet(&sheet).contains("Task Difficulty Reference"));
+
+        let options = MarkdownOptions {
+            reference: true,
+            ..Default::default()
+        };
+        let markdown = format_character_sheet_with(&sheet, &options);
+        assert!(markdown.contains("## Task Difficulty Reference"));
+        assert!(markdown.contains("| Difficulty | Target | 1 Effort | 2 Effort |"));
+        assert!(markdown.contains("| 2 Standard | 6 | 3 | Auto |"));
+        // Might Edge 1 takes a point off each Effort cost
+        assert!(markdown.contains("| 1 | 2 | 2 | 3 |"));
+        assert!(markdown.contains("| 2 | 4 | 4 | 5 |"));
+    }
+
+    #[test]
+    fn test_format_appearance() {
+        let mut sheet = create_test_sheet();
+        assert!(!format_character_sheet(&sheet).contains("## Appearance"));
+
+        sheet.physical_description = Some(crate::character::PhysicalDescription {
+            age: 34,
+            height: "Tall".to_string(),
+            build: "Wiry".to_string(),
+            notable_feature: "a jagged scar across one cheek".to_string(),
+        });
+        let markdown = format_character_sheet(&sheet);
+        assert!(markdown.contains("## Appearance\n\n- **Age:** 34\n- **Height:** Tall\n"));
+        assert!(format_compact(&sheet)
+            .contains("**Appearance:** Age 34, tall, wiry; a jagged scar across one cheek"));
+    }
+
+    #[test]
+    fn test_format_recovery_line() {
+        let mut sheet = create_test_sheet();
+        sheet.tier = 2;
+
+        let expected =
+            "**Recovery:** 1d6+2 (6) | ☐ One action ☐ Ten minutes ☐ One hour ☐ Ten hours";
+        assert!(format_character_sheet(&sheet).contains(expected));
+        assert!(format_compact(&sheet).contains("**Recovery:** 1d6+2"));
+    }
+
+    #[test]
+    fn test_format_character_sheet_status() {
+        let mut sheet = create_test_sheet();
+        assert!(format_character_sheet(&sheet).contains("**Status:** Hale - Healthy and unharmed"));
+
+        sheet.adjust_current_pool("might", -12);
+        let markdown = format_character_sheet(&sheet);
+        assert!(markdown.contains("**Status:** Impaired - "));
+    }
+
+    #[test]
+    fn test_format_character_sheet_frontmatter() {
+        let mut sheet = create_test_sheet();
+        sheet.name = "Test \"Quoted\" Character".to_string();
+
+        let plain = format_character_sheet(&sheet);
+        assert!(!plain.starts_with("---"));
+
+        let options = MarkdownOptions {
+            frontmatter: true,
+            ..Default::default()
+        };
+        let markdown = format_character_sheet_with(&sheet, &options);
+        let lines: Vec<&str> = markdown.lines().collect();
+        let closing = lines.iter().skip(1).position(|l| *l == "---").unwrap() + 1;
+
+        assert_eq!(lines[0], "---");
+        assert!(lines[1..closing].contains(&"title: \"Test \\\"Quoted\\\" Character\""));
+        assert!(lines[1..closing].contains(&"type: \"Glaive\""));
+        assert!(lines[1..closing].contains(&"tier: 1"));
+        assert!(lines[1..closing]
+            .contains(&"tags: [\"numenera\", \"glaive\", \"charming\", \"masters-weaponry\"]"));
+        assert!(lines[closing + 2].starts_with("# Test"));
+
+        sheet.add_tag("Town Guard");
+        sheet.add_tag("glaive");
+        let markdown = format_character_sheet_with(&sheet, &options);
+        assert!(markdown.contains(
+            "tags: [\"numenera\", \"glaive\", \"charming\", \"masters-weaponry\", \"town-guard\"]"
+        ));
+    }
+
+    #[test]
+    fn test_format_character_sheet_portrait() {
         let sheet = create_test_sheet();
+        assert!(!format_character_sheet(&sheet).contains("!["));
+
+        let options = MarkdownOptions {
+            portrait: Some("art/test.png".to_string()),
+            ..Default::default()
+        };
+        let markdown = format_character_sheet_with(&sheet, &options);
+        assert!(markdown.contains(&format!("![Portrait of {}](art/test.png)\n", sheet.name)));
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let mut sheet = create_test_sheet();
         let compact = format_compact(&sheet);
 
         assert!(compact.contains("Test Character"));
         assert!(compact.contains("Tier 1"));
         assert!(compact.contains("Pools:"));
         assert!(compact.contains("Edge:"));
+        assert!(!compact.contains("**Cyphers:**\n"));
+
+        sheet.cyphers.push(crate::data::CypherInstance {
+            instance_id: 0,
+            name: "Detonation".to_string(),
+            level: 4,
+            cypher_type: "Anoetic".to_string(),
+            effect: "Explodes in an immediate radius, ".repeat(5),
+            form: "Handheld".to_string(),
+            duration: "Instant".to_string(),
+        });
+        let compact = format_compact(&sheet);
+        let line = compact.lines().find(|l| l.contains("Detonation")).unwrap();
+        assert!(line.starts_with("- Detonation (L4): Explodes"));
+        assert!(line.chars().count() <= COMPACT_LINE_WIDTH);
+    }
+
+    #[test]
+    fn test_format_character_sheet_wraps_effects() {
+        let mut sheet = create_test_sheet();
+        sheet.cyphers.push(crate::data::CypherInstance {
+            instance_id: 0,
+            name: "Force Screen".to_string(),
+            level: 3,
+            cypher_type: "Anoetic".to_string(),
+            effect: "word ".repeat(40).trim_end().to_string(),
+            form: "Pill".to_string(),
+            duration: "One hour".to_string(),
+        });
+        let options = MarkdownOptions {
+            wrap_width: Some(40),
+            ..Default::default()
+        };
+
+        let markdown = format_character_sheet_with(&sheet, &options);
+        let effect_lines: Vec<&str> = markdown
+            .lines()
+            .skip_while(|l| !l.contains("*Effect:*"))
+            .take_while(|l| !l.is_empty())
+            .collect();
+        assert!(effect_lines.len() > 1);
+        assert!(effect_lines.iter().all(|l| l.chars().count() <= 40));
+        assert!(effect_lines[1].starts_with(&format!("{}word", " ".repeat(15))));
     }
 
     #[test]
@@ -322,14 +819,34 @@ mod tests {
         assert_eq!(sanitize_filename("Bob's Hero!"), "Bob-s_Hero-");
         assert_eq!(sanitize_filename("Jean-Luc"), "Jean-Luc");
         assert_eq!(sanitize_filename("Tester_123"), "Tester_123");
+        assert_eq!(sanitize_filename("Zoë 李白"), "Zoë_李白");
+        assert_eq!(sanitize_filename("a/b\\c:é"), "a-b-c-é");
     }
 
     #[test]
     fn test_save_character_sheet() {
+        let dir = std::env::temp_dir().join(format!("chargen_save_{}", std::process::id()));
+        let dir = dir.to_string_lossy().to_string();
+        let _ = fs::remove_dir_all(&dir);
         let sheet = create_test_sheet();
-        let result = save_character_sheet(&sheet, "output");
 
-        // Should succeed or fail gracefully
-        assert!(result.is_ok() || result.is_err());
+        let first = save_character_sheet(&sheet, &dir).unwrap();
+        let second = save_character_sheet(&sheet, &dir).unwrap();
+
+        assert!(first.ends_with("Test_Character.md"));
+        assert!(second.ends_with("Test_Character_2.md"));
+        assert!(Path::new(&first).exists() && Path::new(&second).exists());
+
+        let options = MarkdownOptions {
+            overwrite: true,
+            ..Default::default()
+        };
+        let replaced = save_character_sheet_with(&sheet, &dir, &options).unwrap();
+        assert_eq!(replaced, first);
+
+        let unnamed = save_character_sheet(&CharacterSheet::new(String::new()), &dir).unwrap();
+        assert!(unnamed.ends_with("character.md"));
+
+        let _ = fs::remove_dir_all(&dir);
     }
 }
@@ -1,8 +1,21 @@
 // src/output/mod.rs
 // Output module - formatting and saving character sheets
 
+pub mod format;
 pub mod markdown;
+pub mod naming;
+pub mod text;
+pub mod trifold;
 
+pub use format::{
+    find_saved_characters, load_character_json, read_character_json, read_sheet_source,
+    save_character_formats, OutputFormat,
+};
 pub use markdown::{
-    format_character_sheet, format_compact, save_character_sheet, save_multiple_sheets,
+    format_character_sheet, format_character_sheet_with, format_compact, format_compact_with,
+    save_character_sheet, save_character_sheet_with, save_multiple_sheets,
+    save_multiple_sheets_with, MarkdownOptions,
 };
+pub use naming::{NameTemplate, TEMPLATE_FIELDS};
+pub use text::{truncate_text, wrap_text};
+pub use trifold::{export_party_trifold, export_party_trifold_with};
@@ -0,0 +1,154 @@
+// src/output/naming.rs
+// Filename templates for saved character sheets, e.g. "{type}_{name}_{tier}"
+
+use anyhow::Result;
+
+use crate::character::CharacterSheet;
+
+use super::markdown::sanitize_filename;
+
+/// Fields a template may reference
+pub const TEMPLATE_FIELDS: &[&str] = &["name", "type", "descriptor", "focus", "tier", "timestamp"];
+
+// ==========================================
+// NAME TEMPLATE
+// ==========================================
+
+/// One piece of a parsed template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(&'static str),
+}
+
+/// A validated filename template such as "{type}_{name}_{tier}"
+///
+/// Field values are sanitized like character names before they are inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameTemplate {
+    source: String,
+    parts: Vec<Part>,
+}
+
+impl NameTemplate {
+    /// The template as written
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Expand the template for a sheet, without an extension
+    ///
+    /// `{timestamp}` is the local time of the call, as "2024-05-01_18-30-00".
+    pub fn expand(&self, sheet: &CharacterSheet) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.clone(),
+                Part::Field(field) => sanitize_filename(field_value(sheet, field).trim()),
+            })
+            .collect()
+    }
+}
+
+impl std::str::FromStr for NameTemplate {
+    type Err = anyhow::Error;
+
+    /// Parse a template, rejecting unknown fields and unbalanced braces
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut rest = s;
+
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                anyhow::bail!("Unmatched '}}' in name template '{}'", s);
+            }
+            if open > 0 {
+                parts.push(Part::Text(rest[..open].to_string()));
+            }
+            let close = rest[open..]
+                .find('}')
+                .ok_or_else(|| anyhow::anyhow!("Unclosed '{{' in name template '{}'", s))?;
+            let name = &rest[open + 1..open + close];
+            let field = TEMPLATE_FIELDS
+                .iter()
+                .find(|f| f.eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Unknown field '{{{}}}' in name template (known: {})",
+                        name,
+                        TEMPLATE_FIELDS.join(", ")
+                    )
+                })?;
+            parts.push(Part::Field(field));
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+
+        if !parts.iter().any(|p| matches!(p, Part::Field(_))) {
+            anyhow::bail!(
+                "Name template '{}' uses no fields (known: {})",
+                s,
+                TEMPLATE_FIELDS.join(", ")
+            );
+        }
+
+        Ok(Self {
+            source: s.to_string(),
+            parts,
+        })
+    }
+}
+
+/// The raw value of a known field
+fn field_value(sheet: &CharacterSheet, field: &str) -> String {
+    match field {
+        "name" => sheet.name.clone(),
+        "type" => sheet.character_type.clone(),
+        "descriptor" => sheet
+            .descriptor
+            .clone()
+            .or_else(|| sheet.species.clone())
+            .unwrap_or_default(),
+        "focus" => sheet.focus.clone(),
+        "tier" => sheet.tier.to_string(),
+        "timestamp" => chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string(),
+        _ => String::new(),
+    }
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_template() {
+        let mut sheet = CharacterSheet::new("Kel the Bold".to_string());
+        sheet.character_type = "Glaive".to_string();
+        sheet.descriptor = Some("Strong".to_string());
+        sheet.focus = "Masters Weaponry".to_string();
+        sheet.tier = 3;
+
+        let template: NameTemplate = "{type}_{name}_{tier}".parse().unwrap();
+        assert_eq!(template.expand(&sheet), "Glaive_Kel_the_Bold_3");
+
+        let template: NameTemplate = "{descriptor}-{focus}".parse().unwrap();
+        assert_eq!(template.expand(&sheet), "Strong-Masters_Weaponry");
+        assert_eq!(template.as_str(), "{descriptor}-{focus}");
+    }
+
+    #[test]
+    fn test_template_rejects_unknown_fields() {
+        let err = "{type}_{level}".parse::<NameTemplate>().unwrap_err();
+        assert!(err.to_string().contains("Unknown field '{level}'"));
+
+        assert!("{name".parse::<NameTemplate>().is_err());
+        assert!("name}".parse::<NameTemplate>().is_err());
+        assert!("hero".parse::<NameTemplate>().is_err());
+    }
+}
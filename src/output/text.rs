@@ -0,0 +1,114 @@
+// src/output/text.rs
+// Plain-text helpers shared by the exporters
+
+/// Greedily wrap text into lines of at most `width` characters
+///
+/// Words longer than `width` are split. A width of 0 returns the text as one line.
+pub fn wrap_text(s: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![s.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in s.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        // Split words that can never fit on a line
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        if word.is_empty() {
+            continue;
+        }
+
+        let line_len = line.chars().count();
+        if line_len > 0 && line_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Collapse text onto one line of at most `width` characters, ending in "..." when cut
+pub fn truncate_text(s: &str, width: usize) -> String {
+    let line = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= width {
+        return line;
+    }
+    let kept: String = line.chars().take(width.saturating_sub(3)).collect();
+    format!("{}...", kept.trim_end())
+}
+
+/// Wrap text to `width` total columns, prefixing the first line and indenting the rest
+pub(crate) fn wrap_with_prefix(prefix: &str, indent: &str, text: &str, width: usize) -> String {
+    let available = width.saturating_sub(prefix.chars().count()).max(1);
+    wrap_text(text, available)
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let lead = if i == 0 { prefix } else { indent };
+            format!("{}{}\n", lead, line)
+        })
+        .collect()
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_long_effect() {
+        let effect = "Creates a shimmering field of force around the user that deflects \
+                      incoming projectiles and blasts, granting +2 Armor against ranged \
+                      attacks for one hour, after which the device crumbles into fine dust.";
+        assert!(effect.len() >= 200);
+
+        let lines = wrap_text(effect, 40);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| l.chars().count() <= 40));
+        assert_eq!(
+            lines.join(" "),
+            effect.split_whitespace().collect::<Vec<_>>().join(" ")
+        );
+    }
+
+    #[test]
+    fn test_wrap_text_edge_cases() {
+        assert_eq!(wrap_text("", 10), vec![""]);
+        assert_eq!(wrap_text("short", 0), vec!["short"]);
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_text("a bb ccc", 4), vec!["a bb", "ccc"]);
+    }
+
+    #[test]
+    fn test_truncate_text() {
+        assert_eq!(truncate_text("Short effect", 20), "Short effect");
+        assert_eq!(truncate_text("Spans\n  two lines", 20), "Spans two lines");
+        let cut = truncate_text("Deals 5 points of damage to everything nearby", 20);
+        assert_eq!(cut, "Deals 5 points of...");
+        assert!(cut.chars().count() <= 20);
+    }
+
+    #[test]
+    fn test_wrap_with_prefix() {
+        let wrapped = wrap_with_prefix("   - ", "     ", "one two three four", 14);
+        assert_eq!(wrapped, "   - one two\n     three\n     four\n");
+    }
+}
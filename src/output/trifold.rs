@@ -0,0 +1,231 @@
+// src/output/trifold.rs
+// Printable party handout: character summaries laid out three to a page
+
+use crate::character::{CharacterSheet, RECOVERY_ACTIONS};
+
+use super::markdown::MarkdownOptions;
+
+/// Characters printed side by side on one page
+pub const CHARACTERS_PER_PAGE: usize = 3;
+
+const STYLE: &str = "\
+body { font-family: Georgia, serif; font-size: 10pt; margin: 0; }
+.page { display: grid; grid-template-columns: repeat(3, 1fr); gap: 0.4in; padding: 0.4in; }
+.character { border-right: 1px dashed #999; padding-right: 0.3in; }
+.character:last-child { border-right: none; }
+.character h2 { margin: 0 0 0.1in 0; font-size: 14pt; }
+.sentence { font-style: italic; margin-top: 0; }
+.portrait { max-width: 100%; max-height: 2in; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #999; padding: 2px 4px; text-align: center; }
+h3 { font-size: 11pt; margin: 0.15in 0 0.05in 0; }
+ul { margin: 0; padding-left: 1.2em; }
+@media print {
+  @page { size: landscape; margin: 0; }
+  .page { break-after: page; page-break-after: always; }
+  .page:last-child { break-after: auto; page-break-after: auto; }
+}
+";
+
+// ==========================================
+// EXPORT
+// ==========================================
+
+/// Render a party as one HTML document, three characters per printed page
+///
+/// Each page is a row of columns, so a table of six pre-gens prints as two
+/// landscape pages that can be folded or cut apart.
+pub fn export_party_trifold(sheets: &[CharacterSheet]) -> String {
+    export_party_trifold_with(sheets, &MarkdownOptions::default())
+}
+
+/// Render a party handout, adding each character's portrait when `options` sets one
+pub fn export_party_trifold_with(sheets: &[CharacterSheet], options: &MarkdownOptions) -> String {
+    let mut output = String::new();
+
+    output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str("<title>Numenera Party</title>\n");
+    output.push_str(&format!("<style>\n{}</style>\n", STYLE));
+    output.push_str("</head>\n<body>\n");
+
+    for page in sheets.chunks(CHARACTERS_PER_PAGE) {
+        output.push_str("<div class=\"page\">\n");
+        for sheet in page {
+            output.push_str(&format_column(sheet, options));
+        }
+        output.push_str("</div>\n");
+    }
+
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// One character's column
+fn format_column(sheet: &CharacterSheet, options: &MarkdownOptions) -> String {
+    let mut output = String::new();
+
+    output.push_str("<section class=\"character\">\n");
+    output.push_str(&format!(
+        "<h2>{} <small>(Tier {})</small></h2>\n",
+        escape(&sheet.name),
+        sheet.tier
+    ));
+    output.push_str(&format!(
+        "<p class=\"sentence\">{}</p>\n",
+        escape(&sheet.character_sentence())
+    ));
+    if let Some(portrait) = &options.portrait {
+        output.push_str(&format!(
+            "<img class=\"portrait\" src=\"{}\" alt=\"Portrait of {}\">\n",
+            escape(portrait),
+            escape(&sheet.name)
+        ));
+    }
+    if let Some(description) = &sheet.physical_description {
+        output.push_str(&format!("<p>{}</p>\n", escape(&description.to_string())));
+    }
+
+    // Pools and Edge
+    output.push_str("<table>\n<tr><th></th><th>Might</th><th>Speed</th><th>Intellect</th></tr>\n");
+    output.push_str(&format!(
+        "<tr><th>Pool</th><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+        sheet.pools.maximum.might, sheet.pools.maximum.speed, sheet.pools.maximum.intellect
+    ));
+    output.push_str(&format!(
+        "<tr><th>Edge</th><td>{}</td><td>{}</td><td>{}</td></tr>\n</table>\n",
+        sheet.edge.might, sheet.edge.speed, sheet.edge.intellect
+    ));
+    output.push_str(&format!(
+        "<p><strong>Effort:</strong> {} &middot; <strong>Armor:</strong> {}</p>\n",
+        sheet.effort.max_effort, sheet.armor
+    ));
+    let slots: Vec<String> = RECOVERY_ACTIONS
+        .iter()
+        .map(|a| format!("&#9744; {}", a))
+        .collect();
+    output.push_str(&format!(
+        "<p><strong>Recovery:</strong> {}<br>{}</p>\n",
+        sheet.recovery(),
+        slots.join(" ")
+    ));
+
+    // Skills
+    let skills: Vec<String> = sheet
+        .skills
+        .specialized
+        .iter()
+        .map(|s| format!("{} (specialized)", s))
+        .chain(sheet.skills.trained.iter().cloned())
+        .collect();
+    push_list(&mut output, "Skills", &skills);
+    push_list(&mut output, "Inabilities", &sheet.skills.inabilities);
+
+    // Abilities
+    let mut abilities: Vec<String> = sheet
+        .type_abilities
+        .iter()
+        .map(|a| match a.cost.as_str() {
+            "" => a.name.clone(),
+            cost => format!("{} ({})", a.name, cost),
+        })
+        .collect();
+    if !sheet.focus_ability.is_empty() {
+        abilities.push(sheet.focus_ability.clone());
+    }
+    push_list(&mut output, "Abilities", &abilities);
+
+    // Cyphers and equipment
+    let cyphers: Vec<String> = sheet
+        .cyphers
+        .iter()
+        .map(|c| format!("{} (level {})", c.name, c.level))
+        .collect();
+    push_list(&mut output, "Cyphers", &cyphers);
+
+    let mut equipment = sheet.equipment.weapons.clone();
+    equipment.extend(sheet.equipment.armor.iter().cloned());
+    equipment.extend(sheet.equipment.shield.iter().cloned());
+    push_list(&mut output, "Equipment", &equipment);
+
+    output.push_str("</section>\n");
+    output
+}
+
+/// A headed bullet list, omitted when there is nothing to list
+fn push_list(output: &mut String, heading: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    output.push_str(&format!("<h3>{}</h3>\n<ul>\n", heading));
+    for item in items {
+        output.push_str(&format!("<li>{}</li>\n", escape(item)));
+    }
+    output.push_str("</ul>\n");
+}
+
+/// Escape text for use in HTML content
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ==========================================
+// TESTS
+// ==========================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_six_characters_print_on_two_pages() {
+        let sheets: Vec<CharacterSheet> = (1..=6)
+            .map(|i| CharacterSheet::new(format!("Pre-gen {}", i)))
+            .collect();
+
+        let html = export_party_trifold(&sheets);
+
+        assert_eq!(html.matches("<div class=\"page\">").count(), 2);
+        assert_eq!(html.matches("<section class=\"character\">").count(), 6);
+        assert!(html.contains("@media print"));
+        assert!(html.contains("page-break-after: always"));
+        for i in 1..=6 {
+            assert!(html.contains(&format!("Pre-gen {}", i)));
+        }
+    }
+
+    #[test]
+    fn test_names_are_escaped() {
+        let sheet = CharacterSheet::new("Kel <the> & \"Bold\"".to_string());
+        let html = export_party_trifold(&[sheet]);
+
+        assert!(html.contains("Kel &lt;the&gt; &amp; &quot;Bold&quot;"));
+        assert_eq!(html.matches("<div class=\"page\">").count(), 1);
+    }
+
+    #[test]
+    fn test_portrait_image_per_character() {
+        let sheets = vec![
+            CharacterSheet::new("Kel".to_string()),
+            CharacterSheet::new("Ora".to_string()),
+        ];
+        assert!(!export_party_trifold(&sheets).contains("<img"));
+
+        let options = MarkdownOptions {
+            portrait: Some("art/party.png".to_string()),
+            ..Default::default()
+        };
+        let html = export_party_trifold_with(&sheets, &options);
+        assert_eq!(html.matches("<img class=\"portrait\"").count(), 2);
+        assert!(html.contains("src=\"art/party.png\" alt=\"Portrait of Ora\""));
+    }
+}
@@ -1,11 +1,25 @@
 // src/tui/app.rs
 // Application state management
 
-use crate::character::sheet::{CharacterSheet, Gender};
-use crate::data::GameData;
-use crate::data::{ArtifactInstance, CypherInstance, Oddity};
+use crate::character::sheet::{CharacterPools, CharacterSheet, Gender};
+use crate::character::{calculate_pools, Pools, DEFAULT_POOL_FLOOR};
+use crate::data::{
+    find_descriptor, find_focus, find_species, find_type, FavoriteKind, Favorites, GameData,
+};
+use crate::data::{Ability, ArtifactInstance, CypherInstance, Focus, Oddity};
+use crate::output::{NameTemplate, OutputFormat};
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// How long a freshly rolled cypher level stays highlighted
+pub const ROLL_FLASH_DURATION: Duration = Duration::from_millis(500);
+
+/// Shop price of a cypher; the data lists no cypher values, so they cost as much as an oddity
+pub const CYPHER_SHOP_PRICE: u32 = 10;
 
 /// Application state
 pub struct App {
@@ -21,19 +35,42 @@ pub struct App {
     pub shop_list_state: usize,
     pub shop_cart: Vec<ShopItem>,
     pub shop_selected_category_index: usize,
+    pub shop_focus: ShopFocus,
+    pub shop_cart_selected: usize,
     pub last_saved_file: Option<String>,
 
     // ========== ADD LOADER STATE ==========
     pub loader_files: Vec<String>,                  // List of .json files
     pub loader_list_state: usize,                   // Selected file index
     pub loader_scroll_offset: usize,                // Scroll position
+    pub loader_sheets: Vec<Option<CharacterSheet>>, // Parsed loader_files; None if unreadable
+    pub loader_show_info: bool,                     // Show the stat line under each file
     // ======================================
 
     // ========== ADD EDIT MODE STATE ==========
     pub is_edit_mode: bool,                         // Are we in edit mode?
     pub editing_character: Option<CharacterSheet>,  // Character being edited
     pub edit_original_filename: Option<String>,     // Original filename for saving
+    pub edit_stats_backup: Option<CharacterPools>,  // Pools to restore on cancel
+    pub edit_oddity_slot: Option<usize>,            // Starting oddity slot being edited
     // =========================================
+
+    pub status_message: Option<String>,             // Error shown in the help bar
+    pub start_tier: u32,                            // Tier new characters start at
+    pub needs_redraw: bool,                         // Redraw before waiting for input
+    pub animations: bool,                           // Cosmetic animations enabled
+    pub last_roll_flash: Option<(u64, Instant)>,    // Cypher instance whose level was just rolled
+    pub theme: crate::tui::ui::Theme,               // Shared colours
+    pub accessible: bool,                           // Add text/symbol cues alongside colour
+    pub previous_screen: Option<Screen>,            // Screen to return to after a preview peek
+    pub peek_scroll: (usize, usize),                // Preview scroll (left, right) kept between peeks
+    pub focus_theme_filter: Option<String>,         // Theme the focus list is narrowed to
+    pub shop_range_filter: Option<String>,          // Weapon range the shop is narrowed to
+    pub output_dir: String,                         // Where characters are saved and loaded
+    pub favorites: Favorites,                       // Types, descriptors and foci pinned to the top
+    pub favorites_path: Option<PathBuf>,            // File favorites are rewritten to on toggle
+    pub name_template: Option<NameTemplate>,        // Saved file names; None uses name + timestamp
+    pub tag_input: Option<String>,                  // Tag being typed on the preview
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -48,6 +85,9 @@ impl ShopItem {
     }
 }
 
+/// Weapon ranges the shop can be filtered by, nearest first
+const WEAPON_RANGES: [&str; 3] = ["immediate", "short", "long"];
+
 /// Current screen in the UI
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
@@ -81,6 +121,15 @@ pub enum ShopCategory {
     Gear,
     Consumables,
     Clothing,
+    Cyphers,
+    Oddities,
+}
+
+/// Which shop list the arrow keys and R act on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShopFocus {
+    Items,
+    Cart,
 }
 
 #[derive(Debug, Clone)]
@@ -88,7 +137,8 @@ pub struct ShopItem {
     pub name: String,
     pub cost: u32,
     pub category: String,
-    pub quantity: u32, // For stackable items
+    pub quantity: u32,                // For stackable items
+    pub cyphers: Vec<CypherInstance>, // Cyphers bought, rolled once when carted
 }
 
 impl ShopCategory {
@@ -100,6 +150,8 @@ impl ShopCategory {
             ShopCategory::Gear,
             ShopCategory::Consumables,
             ShopCategory::Clothing,
+            ShopCategory::Cyphers,
+            ShopCategory::Oddities,
         ]
     }
 
@@ -111,6 +163,8 @@ impl ShopCategory {
             ShopCategory::Gear => "Gear",
             ShopCategory::Consumables => "Consumables",
             ShopCategory::Clothing => "Clothing",
+            ShopCategory::Cyphers => "Cyphers",
+            ShopCategory::Oddities => "Oddities",
         }
     }
 }
@@ -120,7 +174,7 @@ impl ShopCategory {
 pub struct CharacterBuilder {
     pub name: String,
     pub gender: Gender,
-    pub name_input_cursor: usize,
+    pub name_input_cursor: usize,                   // Cursor position in chars, not bytes
     pub character_type: Option<String>,
     pub descriptor_or_species: Option<String>,
     pub is_species: bool,
@@ -129,14 +183,21 @@ pub struct CharacterBuilder {
     pub bonus_speed: i32,
     pub bonus_intellect: i32,
     pub selected_abilities: Vec<String>,
+    pub ability_tier: u32,                          // Tier currently being picked
+    pub higher_tier_abilities: Vec<Vec<String>>,    // Picks for tier 2 and up
+    pub equipment_option: usize,                    // Index into the type's equipment options
 
     pub selected_cyphers: Vec<CypherInstance>,
     pub selected_artifacts: Vec<ArtifactInstance>,
     pub selected_oddities: Vec<Oddity>,
+    pub cypher_sources: HashMap<u64, usize>,        // Cypher instance id -> cypher list index
+    pub artifact_sources: HashMap<u64, usize>,      // Artifact instance id -> artifact list index
 
     pub purchased_items: Vec<ShopItem>,
+    pub tags: Vec<String>,
     pub list_state: usize,
     pub scroll_offset: usize,
+    pub build_seed: u64,                            // Seed for oddity top-ups across redraws
 }
 
 impl App {
@@ -154,40 +215,142 @@ impl App {
             shop_list_state: 0,
             shop_cart: Vec::new(),
             shop_selected_category_index: 0,
+            shop_focus: ShopFocus::Items,
+            shop_cart_selected: 0,
             last_saved_file: None,
 
             // ========== ADD LOADER INITIALIZATION ==========
             loader_files: Vec::new(),
             loader_list_state: 0,
             loader_scroll_offset: 0,
+            loader_sheets: Vec::new(),
+            loader_show_info: true,
 
             // ========== INITIALIZE EDIT MODE ==========
             is_edit_mode: false,
             editing_character: None,
             edit_original_filename: None,
+            edit_stats_backup: None,
+            edit_oddity_slot: None,
             // ==========================================
+
+            status_message: None,
+            start_tier: 1,
+            needs_redraw: true,
+            animations: true,
+            last_roll_flash: None,
+            theme: crate::tui::ui::Theme::default(),
+            accessible: false,
+            previous_screen: None,
+            peek_scroll: (0, 0),
+            focus_theme_filter: None,
+            shop_range_filter: None,
+            output_dir: "output".to_string(),
+            favorites: Favorites::default(),
+            favorites_path: None,
+            name_template: None,
+            tag_input: None,
             }
     }
 
     /// Handle keyboard events
     pub fn handle_event(&mut self, event: crossterm::event::Event) -> Result<()> {
-        if let crossterm::event::Event::Key(key) = event {
-            self.handle_key_event(key)?;
+        match event {
+            crossterm::event::Event::Key(key) => {
+                self.handle_key_event(key)?;
+                self.needs_redraw = true;
+            }
+            crossterm::event::Event::Resize(_, _) => {
+                self.needs_redraw = true;
+            }
+            _ => {}
         }
         Ok(())
     }
 
+    /// Whether the UI animates and needs periodic ticks while idle
+    pub fn wants_ticks(&self) -> bool {
+        self.last_roll_flash.is_some()
+    }
+
+    /// Called when the tick interval passes without input
+    pub fn on_tick(&mut self) {
+        if self.wants_ticks() {
+            self.needs_redraw = true;
+        }
+
+        if let Some((_, started)) = &self.last_roll_flash {
+            if started.elapsed() >= ROLL_FLASH_DURATION {
+                self.last_roll_flash = None;
+            }
+        }
+    }
+
+    /// Instance id of the cypher whose roll is still being shown, if any
+    pub fn roll_flash(&self) -> Option<u64> {
+        self.last_roll_flash
+            .filter(|(_, started)| started.elapsed() < ROLL_FLASH_DURATION)
+            .map(|(id, _)| id)
+    }
+
+    /// Position in `selected_cyphers` of the instance picked from cypher list entry `index`
+    pub fn chosen_cypher(&self, index: usize) -> Option<usize> {
+        let builder = &self.character_builder;
+        builder
+            .selected_cyphers
+            .iter()
+            .position(|c| builder.cypher_sources.get(&c.instance_id) == Some(&index))
+    }
+
+    /// Position in `selected_artifacts` of the instance picked from artifact list entry `index`
+    pub fn chosen_artifact(&self, index: usize) -> Option<usize> {
+        let builder = &self.character_builder;
+        builder
+            .selected_artifacts
+            .iter()
+            .position(|a| builder.artifact_sources.get(&a.instance_id) == Some(&index))
+    }
+
+    /// Roll a new instance of cypher list entry `index` and select it
+    fn pick_cypher(&mut self, index: usize) -> Option<u64> {
+        let instance = crate::data::create_cypher_instance(self.game_data.cyphers.get(index)?);
+        let id = instance.instance_id;
+        self.character_builder.cypher_sources.insert(id, index);
+        self.character_builder.selected_cyphers.push(instance);
+        Some(id)
+    }
+
+    /// Roll a new instance of artifact list entry `index` and select it
+    fn pick_artifact(&mut self, index: usize) {
+        if let Some(artifact) = self.game_data.artifacts.get(index) {
+            let instance = crate::data::create_artifact_instance(artifact);
+            self.character_builder
+                .artifact_sources
+                .insert(instance.instance_id, index);
+            self.character_builder.selected_artifacts.push(instance);
+        }
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
         if key.kind != crossterm::event::KeyEventKind::Press {
             return Ok(());
         }
 
+        // Any key press dismisses the previous status message
+        self.status_message = None;
+
         // Global quit
         if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
             self.should_quit = true;
             return Ok(());
         }
 
+        // Global preview peek from the creation screens
+        if key.code == KeyCode::F(2) && self.is_creation_screen() {
+            self.peek_preview();
+            return Ok(());
+        }
+
         match self.current_screen {
             Screen::MainMenu => self.handle_main_menu_keys(key),
             Screen::CharacterLoader => self.handle_character_loader_keys(key),
@@ -212,6 +375,42 @@ impl App {
         }
     }
 
+    /// Whether the current screen is one of the new-character creation steps
+    fn is_creation_screen(&self) -> bool {
+        matches!(
+            self.current_screen,
+            Screen::NameInput
+                | Screen::GenderSelect
+                | Screen::TypeSelect
+                | Screen::DescriptorSelect
+                | Screen::FocusSelect
+                | Screen::StatAllocation
+                | Screen::AbilitySelect
+                | Screen::CypherSelect
+                | Screen::ArtifactSelect
+                | Screen::OdditySelect
+                | Screen::EquipmentShop
+        )
+    }
+
+    /// Jump to the preview with the partial character, remembering where we came from
+    fn peek_preview(&mut self) {
+        let builder = &self.character_builder;
+        if builder.character_type.is_none()
+            || builder.descriptor_or_species.is_none()
+            || builder.focus.is_none()
+        {
+            self.status_message = Some(
+                "Choose a type, descriptor or species, and focus before previewing".to_string(),
+            );
+            return;
+        }
+
+        self.previous_screen = Some(self.current_screen.clone());
+        (self.preview_left_scroll, self.preview_right_scroll) = self.peek_scroll;
+        self.current_screen = Screen::CharacterPreview;
+    }
+
     fn handle_main_menu_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Char('1') | KeyCode::Enter => {
@@ -225,7 +424,8 @@ impl App {
                         self.current_screen = Screen::CharacterPreview;
                     }
                     Err(e) => {
-                        eprintln!("Failed to generate random character: {}", e);
+                        self.status_message =
+                            Some(format!("Failed to generate random character: {}", e));
                     }
                 }
             }
@@ -244,30 +444,29 @@ impl App {
 
     fn handle_character_loader_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.loader_list_state > 0 {
-                    self.loader_list_state -= 1;
-                }
+            KeyCode::Up | KeyCode::Char('k') if self.loader_list_state > 0 => {
+                self.loader_list_state -= 1;
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.loader_list_state < self.loader_files.len().saturating_sub(1) {
-                    self.loader_list_state += 1;
-                }
+            KeyCode::Down | KeyCode::Char('j')
+                if self.loader_list_state < self.loader_files.len().saturating_sub(1) =>
+            {
+                self.loader_list_state += 1;
             }
-            KeyCode::Enter => {
-                if !self.loader_files.is_empty() {
-                    let filename = &self.loader_files[self.loader_list_state];
-                    match self.load_character_from_file(filename) {
-                        Ok(character) => {
-                            self.generated_character = Some(character);
-                            self.preview_left_scroll = 0;
-                            self.preview_right_scroll = 0;
-                            self.edit_original_filename = Some(filename.clone());
-                            self.current_screen = Screen::CharacterPreview;
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to load character: {}", e);
-                        }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.loader_show_info = !self.loader_show_info;
+            }
+            KeyCode::Enter if !self.loader_files.is_empty() => {
+                let filename = &self.loader_files[self.loader_list_state];
+                match self.load_character_from_file(filename) {
+                    Ok(character) => {
+                        self.generated_character = Some(character);
+                        self.preview_left_scroll = 0;
+                        self.preview_right_scroll = 0;
+                        self.edit_original_filename = Some(filename.clone());
+                        self.current_screen = Screen::CharacterPreview;
+                    }
+                    Err(e) => {
+                        self.status_message = Some(format!("Failed to load {}: {}", filename, e));
                     }
                 }
             }
@@ -285,7 +484,7 @@ impl App {
                 // Edit name
                 if let Some(character) = &self.editing_character {
                     self.character_builder.name = character.name.clone();
-                    self.character_builder.name_input_cursor = character.name.len();
+                    self.character_builder.name_input_cursor = character.name.chars().count();
                 }
                 self.current_screen = Screen::EditName;
             }
@@ -295,23 +494,43 @@ impl App {
             }
             KeyCode::Char('3') => {
                 // Edit stats
+                self.edit_stats_backup = self.editing_character.as_ref().map(|c| c.pools.clone());
                 self.current_screen = Screen::EditStats;
                 self.character_builder.reset_list_state();
             }
             KeyCode::Char('4') => {
                 // Edit cyphers
-                if let Some(character) = &self.editing_character {
-                    // Pre-populate with current cyphers
-                    self.character_builder.selected_cyphers = character.cyphers.clone();
+                if let Some(character) = &mut self.editing_character {
+                    // Pre-populate with current cyphers, linking each to its list entry
+                    let builder = &mut self.character_builder;
+                    builder.cypher_sources.clear();
+                    for cypher in &mut character.cyphers {
+                        if cypher.instance_id == 0 {
+                            cypher.instance_id = crate::data::new_instance_id();
+                        }
+                        if let Some(index) = self
+                            .game_data
+                            .cyphers
+                            .iter()
+                            .position(|c| c.name == cypher.name)
+                        {
+                            builder.cypher_sources.insert(cypher.instance_id, index);
+                        }
+                    }
+                    builder.selected_cyphers = character.cyphers.clone();
                 }
                 self.current_screen = Screen::EditCyphers;
                 self.character_builder.reset_list_state();
             }
             KeyCode::Char('5') => {
-                // Edit oddity
+                // Edit the starting oddity; granted and bought ones stay as they are
                 if let Some(character) = &self.editing_character {
-                    // Pre-populate with current oddity
-                    self.character_builder.selected_oddities = character.oddities.clone();
+                    self.edit_oddity_slot = character.starting_oddity_index(&self.game_data);
+                    self.character_builder.selected_oddities = self
+                        .edit_oddity_slot
+                        .map(|slot| character.oddities[slot].clone())
+                        .into_iter()
+                        .collect();
                 }
                 self.current_screen = Screen::EditOddity;
                 self.character_builder.reset_list_state();
@@ -331,42 +550,27 @@ impl App {
 
     fn handle_edit_name_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Enter => {
-                if !self.character_builder.name.is_empty() {
-                    // Apply name change to editing character
-                    if let Some(character) = &mut self.editing_character {
-                        character.name = self.character_builder.name.clone();
-                    }
-                    self.current_screen = Screen::EditMenu;
+            KeyCode::Enter if !self.character_builder.name.is_empty() => {
+                // Apply name change to editing character
+                if let Some(character) = &mut self.editing_character {
+                    character.name = self.character_builder.name.clone();
                 }
+                self.current_screen = Screen::EditMenu;
             }
-            KeyCode::Char(c) => {
+            KeyCode::Char(c)
                 if !key.modifiers.contains(KeyModifiers::CONTROL)
-                    && !key.modifiers.contains(KeyModifiers::ALT)
-                {
-                    self.character_builder
-                        .name
-                        .insert(self.character_builder.name_input_cursor, c);
-                    self.character_builder.name_input_cursor += 1;
-                }
+                    && !key.modifiers.contains(KeyModifiers::ALT) =>
+            {
+                self.character_builder.insert_name_char(c);
             }
             KeyCode::Backspace => {
-                if self.character_builder.name_input_cursor > 0 {
-                    self.character_builder.name_input_cursor -= 1;
-                    self.character_builder
-                        .name
-                        .remove(self.character_builder.name_input_cursor);
-                }
+                self.character_builder.delete_name_char();
             }
             KeyCode::Left => {
-                if self.character_builder.name_input_cursor > 0 {
-                    self.character_builder.name_input_cursor -= 1;
-                }
+                self.character_builder.move_name_cursor(-1);
             }
             KeyCode::Right => {
-                if self.character_builder.name_input_cursor < self.character_builder.name.len() {
-                    self.character_builder.name_input_cursor += 1;
-                }
+                self.character_builder.move_name_cursor(1);
             }
             KeyCode::Esc => {
                 self.current_screen = Screen::EditMenu;
@@ -405,68 +609,56 @@ impl App {
     }
 
     fn handle_edit_stats_keys(&mut self, key: KeyEvent) -> Result<()> {
+        const POOLS: [&str; 3] = ["might", "speed", "intellect"];
+
         if let Some(character) = &mut self.editing_character {
+            let pool_name = POOLS[self.character_builder.list_state.min(2)];
+
             match key.code {
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if self.character_builder.list_state > 0 {
-                        self.character_builder.list_state -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if self.character_builder.list_state > 0 => {
+                    self.character_builder.list_state -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => {
-                    if self.character_builder.list_state < 2 {
-                        self.character_builder.list_state += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab
+                    if self.character_builder.list_state < 2 =>
+                {
+                    self.character_builder.list_state += 1;
                 }
                 KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('=') => {
-                    // Increase current pool (cannot exceed maximum)
-                    match self.character_builder.list_state {
-                        0 => {
-                            if character.pools.current.might < character.pools.maximum.might {
-                                character.pools.current.might += 1;
-                            }
-                        }
-                        1 => {
-                            if character.pools.current.speed < character.pools.maximum.speed {
-                                character.pools.current.speed += 1;
-                            }
-                        }
-                        2 => {
-                            if character.pools.current.intellect < character.pools.maximum.intellect {
-                                character.pools.current.intellect += 1;
-                            }
-                        }
-                        _ => {}
-                    }
+                    // Recover (cannot exceed maximum)
+                    character.adjust_current_pool(pool_name, 1);
                 }
                 KeyCode::Left | KeyCode::Char('-') | KeyCode::Char('_') => {
-                    // Decrease current pool (cannot go below 0)
-                    match self.character_builder.list_state {
-                        0 => {
-                            if character.pools.current.might > 0 {
-                                character.pools.current.might -= 1;
-                            }
-                        }
-                        1 => {
-                            if character.pools.current.speed > 0 {
-                                character.pools.current.speed -= 1;
-                            }
-                        }
-                        2 => {
-                            if character.pools.current.intellect > 0 {
-                                character.pools.current.intellect -= 1;
-                            }
-                        }
-                        _ => {}
-                    }
+                    // Take damage (cannot go below 0)
+                    character.adjust_current_pool(pool_name, -1);
+                }
+                KeyCode::Char('r') | KeyCode::Char('R') => {
+                    // Full rest - restore all pools
+                    character.pools.reset();
+                    character.update_damage_track();
+                }
+                KeyCode::Char('v') | KeyCode::Char('V') => {
+                    // Roll a recovery; the player spreads the points with +/-
+                    let roll = crate::character::RecoveryRoll::roll(
+                        character.tier,
+                        &mut rand::thread_rng(),
+                    );
+                    self.status_message = Some(format!(
+                        "Recovery roll: {} + {} = {} points to recover",
+                        roll.roll,
+                        roll.tier,
+                        roll.total()
+                    ));
                 }
                 KeyCode::Enter => {
-                    // Save and return to edit menu
+                    // Keep changes and return to edit menu
+                    self.edit_stats_backup = None;
                     self.current_screen = Screen::EditMenu;
                 }
                 KeyCode::Esc => {
-                    // Cancel - restore original character
-                    if let Some(original) = &self.generated_character {
-                        self.editing_character = Some(original.clone());
+                    // Cancel - restore pools from when the screen was opened
+                    if let Some(backup) = self.edit_stats_backup.take() {
+                        character.pools = backup;
+                        character.update_damage_track();
                     }
                     self.current_screen = Screen::EditMenu;
                 }
@@ -498,26 +690,12 @@ impl App {
                 let idx = self.character_builder.list_state;
 
                 // Check if already selected
-                if let Some(pos) = self
-                    .character_builder
-                    .selected_cyphers
-                    .iter()
-                    .position(|c| {
-                        if let Some(cypher) = self.game_data.cyphers.get(idx) {
-                            c.name == cypher.name
-                        } else {
-                            false
-                        }
-                    })
-                {
+                if let Some(pos) = self.chosen_cypher(idx) {
                     // Remove it
                     self.character_builder.selected_cyphers.remove(pos);
                 } else if self.character_builder.selected_cyphers.len() < cypher_limit {
                     // Add new instance
-                    if let Some(cypher) = self.game_data.cyphers.get(idx) {
-                        let instance = crate::data::create_cypher_instance(cypher);
-                        self.character_builder.selected_cyphers.push(instance);
-                    }
+                    self.pick_cypher(idx);
                 }
             }
             KeyCode::Char('r') | KeyCode::Char('R') => {
@@ -531,10 +709,7 @@ impl App {
                     let idx = rng.gen_range(0..available.len());
                     let cypher_idx = available.remove(idx);
 
-                    if let Some(cypher) = self.game_data.cyphers.get(cypher_idx) {
-                        let instance = crate::data::create_cypher_instance(cypher);
-                        self.character_builder.selected_cyphers.push(instance);
-                    }
+                    self.pick_cypher(cypher_idx);
                 }
             }
             KeyCode::Char('c') | KeyCode::Char('C') => {
@@ -608,14 +783,20 @@ impl App {
             KeyCode::Char('c') | KeyCode::Char('C') => {
                 self.character_builder.selected_oddities.clear();
             }
+            // Apply changes to the starting slot only
             KeyCode::Enter => {
-                // Apply changes (must have exactly 1)
-                if self.character_builder.selected_oddities.len() == 1 {
-                    if let Some(character) = &mut self.editing_character {
-                        character.oddities = self.character_builder.selected_oddities.clone();
+                let Some(oddity) = self.character_builder.selected_oddities.first().cloned() else {
+                    self.status_message =
+                        Some("Select an oddity to replace the starting one".to_string());
+                    return Ok(());
+                };
+                if let Some(character) = &mut self.editing_character {
+                    match self.edit_oddity_slot {
+                        Some(slot) => character.oddities[slot] = oddity,
+                        None => character.add_oddity(oddity),
                     }
-                    self.current_screen = Screen::EditMenu;
                 }
+                self.current_screen = Screen::EditMenu;
             }
             KeyCode::Esc => {
                 // Cancel
@@ -628,40 +809,25 @@ impl App {
 
     fn handle_name_input_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Enter => {
-                if !self.character_builder.name.is_empty() {
-                    self.current_screen = Screen::GenderSelect;
-                    self.character_builder.reset_list_state();
-                }
+            KeyCode::Enter if !self.character_builder.name.is_empty() => {
+                self.current_screen = Screen::GenderSelect;
+                self.character_builder.reset_list_state();
             }
-            KeyCode::Char(c) => {
-                // Only insert if not a modifier key
+            // Only insert if not a modifier key
+            KeyCode::Char(c)
                 if !key.modifiers.contains(KeyModifiers::CONTROL)
-                    && !key.modifiers.contains(KeyModifiers::ALT)
-                {
-                    self.character_builder
-                        .name
-                        .insert(self.character_builder.name_input_cursor, c);
-                    self.character_builder.name_input_cursor += 1;
-                }
+                    && !key.modifiers.contains(KeyModifiers::ALT) =>
+            {
+                self.character_builder.insert_name_char(c);
             }
             KeyCode::Backspace => {
-                if self.character_builder.name_input_cursor > 0 {
-                    self.character_builder.name_input_cursor -= 1;
-                    self.character_builder
-                        .name
-                        .remove(self.character_builder.name_input_cursor);
-                }
+                self.character_builder.delete_name_char();
             }
             KeyCode::Left => {
-                if self.character_builder.name_input_cursor > 0 {
-                    self.character_builder.name_input_cursor -= 1;
-                }
+                self.character_builder.move_name_cursor(-1);
             }
             KeyCode::Right => {
-                if self.character_builder.name_input_cursor < self.character_builder.name.len() {
-                    self.character_builder.name_input_cursor += 1;
-                }
+                self.character_builder.move_name_cursor(1);
             }
             KeyCode::Esc => {
                 self.current_screen = Screen::MainMenu;
@@ -704,10 +870,33 @@ impl App {
                 self.character_builder.move_down(total_items);
             }
             KeyCode::Enter => {
-                let selected = &self.game_data.types[self.character_builder.list_state];
-                self.character_builder.character_type = Some(selected.name.clone());
-                self.current_screen = Screen::DescriptorSelect;
-                self.character_builder.reset_list_state();
+                let index = self
+                    .type_order()
+                    .get(self.character_builder.list_state)
+                    .copied();
+                match index.and_then(|i| self.game_data.types.get(i)) {
+                    Some(selected) => {
+                        if let Err(e) =
+                            crate::character::validate_start_tier(selected, self.start_tier)
+                        {
+                            self.status_message = Some(e.to_string());
+                            return Ok(());
+                        }
+                        self.character_builder.character_type = Some(selected.name.clone());
+                        self.character_builder.equipment_option = 0;
+                        self.character_builder.ability_tier = 1;
+                        self.character_builder.higher_tier_abilities =
+                            vec![Vec::new(); self.start_tier.saturating_sub(1) as usize];
+                        self.current_screen = Screen::DescriptorSelect;
+                        self.character_builder.reset_list_state();
+                    }
+                    None => {
+                        self.status_message = Some("No character types loaded".to_string());
+                    }
+                }
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.toggle_highlighted_favorite(FavoriteKind::Type);
             }
             KeyCode::Esc => {
                 self.current_screen = Screen::NameInput;
@@ -729,18 +918,27 @@ impl App {
             }
             KeyCode::Enter => {
                 let descriptor_count = self.game_data.descriptors.len();
-                if self.character_builder.list_state < descriptor_count {
-                    let selected = &self.game_data.descriptors[self.character_builder.list_state];
-                    self.character_builder.descriptor_or_species = Some(selected.name.clone());
-                    self.character_builder.is_species = false;
-                } else {
-                    let species_idx = self.character_builder.list_state - descriptor_count;
-                    let selected = &self.game_data.species[species_idx];
-                    self.character_builder.descriptor_or_species = Some(selected.name.clone());
-                    self.character_builder.is_species = true;
+                let idx = self.character_builder.list_state;
+                let selected = self
+                    .display_names(FavoriteKind::Descriptor)
+                    .get(idx)
+                    .map(|name| (name.clone(), idx >= descriptor_count));
+
+                match selected {
+                    Some((name, is_species)) => {
+                        self.character_builder.descriptor_or_species = Some(name);
+                        self.character_builder.is_species = is_species;
+                        self.current_screen = Screen::FocusSelect;
+                        self.character_builder.reset_list_state();
+                    }
+                    None => {
+                        self.status_message =
+                            Some("No descriptors or species loaded".to_string());
+                    }
                 }
-                self.current_screen = Screen::FocusSelect;
-                self.character_builder.reset_list_state();
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.toggle_highlighted_favorite(FavoriteKind::Descriptor);
             }
             KeyCode::Esc => {
                 self.current_screen = Screen::TypeSelect;
@@ -750,23 +948,130 @@ impl App {
         Ok(())
     }
 
-    fn handle_focus_select_keys(&mut self, key: KeyEvent) -> Result<()> {
-        // Get suitable foci (same filter as in render)
-        let character_type = self.character_builder.character_type.as_ref();
-        let suitable_foci: Vec<_> = if let Some(char_type) = character_type {
-            self.game_data
+    /// Foci suitable for the chosen type, narrowed by the theme filter, favorites first
+    pub fn visible_foci(&self) -> Vec<&Focus> {
+        let mut foci: Vec<&Focus> = self
+            .suitable_foci()
+            .into_iter()
+            .filter(|f| {
+                self.focus_theme_filter
+                    .as_deref()
+                    .is_none_or(|theme| f.theme.trim().eq_ignore_ascii_case(theme))
+            })
+            .collect();
+        foci.sort_by_key(|f| !self.favorites.contains(FavoriteKind::Focus, &f.name));
+        foci
+    }
+
+    /// Type list indices in display order, favorites first
+    pub fn type_order(&self) -> Vec<usize> {
+        let names = self.game_data.types.iter().map(|t| t.name.as_str());
+        self.favorites.pinned_order(FavoriteKind::Type, names)
+    }
+
+    /// Descriptor list indices in display order, favorites first
+    pub fn descriptor_order(&self) -> Vec<usize> {
+        let names = self.game_data.descriptors.iter().map(|d| d.name.as_str());
+        self.favorites.pinned_order(FavoriteKind::Descriptor, names)
+    }
+
+    /// Species list indices in display order, favorites first
+    pub fn species_order(&self) -> Vec<usize> {
+        let names = self.game_data.species.iter().map(|s| s.name.as_str());
+        self.favorites.pinned_order(FavoriteKind::Descriptor, names)
+    }
+
+    /// Names on a selection screen in the order they are shown
+    ///
+    /// The descriptor step lists descriptors and then species, each with
+    /// its own favorites pinned first.
+    fn display_names(&self, kind: FavoriteKind) -> Vec<String> {
+        match kind {
+            FavoriteKind::Type => self
+                .type_order()
+                .into_iter()
+                .map(|i| self.game_data.types[i].name.clone())
+                .collect(),
+            FavoriteKind::Descriptor => {
+                let descriptors = self
+                    .descriptor_order()
+                    .into_iter()
+                    .map(|i| self.game_data.descriptors[i].name.clone());
+                let species = self
+                    .species_order()
+                    .into_iter()
+                    .map(|i| self.game_data.species[i].name.clone());
+                descriptors.chain(species).collect()
+            }
+            FavoriteKind::Focus => self.visible_foci().iter().map(|f| f.name.clone()).collect(),
+        }
+    }
+
+    /// Toggle the highlighted entry's favorite status and rewrite the favorites file
+    ///
+    /// The cursor follows the entry to its new position in the list.
+    fn toggle_highlighted_favorite(&mut self, kind: FavoriteKind) {
+        let Some(name) = self
+            .display_names(kind)
+            .get(self.character_builder.list_state)
+            .cloned()
+        else {
+            return;
+        };
+
+        self.favorites.toggle(kind, &name);
+        if let Some(path) = &self.favorites_path {
+            if let Err(e) = crate::data::save_favorites(path, &self.favorites) {
+                self.status_message = Some(format!("Could not save favorites: {}", e));
+            }
+        }
+
+        if let Some(pos) = self.display_names(kind).iter().position(|n| *n == name) {
+            self.character_builder.list_state = pos;
+        }
+    }
+
+    /// Foci suitable for the chosen type (all foci before a type is picked)
+    fn suitable_foci(&self) -> Vec<&Focus> {
+        match &self.character_builder.character_type {
+            Some(char_type) => self
+                .game_data
                 .foci
                 .iter()
-                .filter(|f| {
-                    f.suitable_types
-                        .iter()
-                        .any(|t| t.eq_ignore_ascii_case(char_type))
-                })
-                .collect()
-        } else {
-            self.game_data.foci.iter().collect()
+                .filter(|f| f.suits_type(char_type))
+                .collect(),
+            None => self.game_data.foci.iter().collect(),
+        }
+    }
+
+    /// Step the focus theme filter to the next theme, wrapping back to no filter
+    fn cycle_focus_theme_filter(&mut self) {
+        let suitable = self.suitable_foci();
+        let themes: Vec<String> = self
+            .game_data
+            .focus_themes()
+            .into_iter()
+            .filter(|theme| {
+                suitable
+                    .iter()
+                    .any(|f| f.theme.trim().eq_ignore_ascii_case(theme))
+            })
+            .collect();
+
+        let next = match &self.focus_theme_filter {
+            None => 0,
+            Some(current) => themes
+                .iter()
+                .position(|t| t.eq_ignore_ascii_case(current))
+                .map_or(0, |i| i + 1),
         };
+        self.focus_theme_filter = themes.get(next).cloned();
+        self.character_builder.reset_list_state();
+    }
 
+    fn handle_focus_select_keys(&mut self, key: KeyEvent) -> Result<()> {
+        // Same list as the render
+        let suitable_foci = self.visible_foci();
         let total_items = suitable_foci.len();
 
         match key.code {
@@ -778,13 +1083,23 @@ impl App {
             }
             KeyCode::Enter => {
                 if self.character_builder.list_state < suitable_foci.len() {
-                    let selected = suitable_foci[self.character_builder.list_state];
-                    self.character_builder.focus = Some(selected.name.clone());
+                    let selected = suitable_foci[self.character_builder.list_state].name.clone();
+                    self.character_builder.focus = Some(selected);
+                    self.focus_theme_filter = None;
                     self.character_builder.reset_list_state();
                     self.current_screen = Screen::StatAllocation;
+                } else {
+                    self.status_message = Some("No suitable foci loaded".to_string());
                 }
             }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                self.cycle_focus_theme_filter();
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.toggle_highlighted_favorite(FavoriteKind::Focus);
+            }
             KeyCode::Esc => {
+                self.focus_theme_filter = None;
                 self.current_screen = Screen::DescriptorSelect;
             }
             _ => {}
@@ -802,49 +1117,47 @@ impl App {
         // Use list_state to track which stat (0=Might, 1=Speed, 2=Intellect)
         match key.code {
             // Accept multiple keys for increment
-            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => {
-                if remaining > 0 {
-                    match self.character_builder.list_state {
-                        0 => self.character_builder.bonus_might += 1,
-                        1 => self.character_builder.bonus_speed += 1,
-                        2 => self.character_builder.bonus_intellect += 1,
-                        _ => {}
-                    }
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right if remaining > 0 => {
+                match self.character_builder.list_state {
+                    0 => self.character_builder.bonus_might += 1,
+                    1 => self.character_builder.bonus_speed += 1,
+                    2 => self.character_builder.bonus_intellect += 1,
+                    _ => {}
                 }
             }
             // Accept multiple keys for decrement
             KeyCode::Char('-') | KeyCode::Char('_') | KeyCode::Left => {
                 match self.character_builder.list_state {
-                    0 => {
-                        if self.character_builder.bonus_might > 0 {
-                            self.character_builder.bonus_might -= 1;
-                        }
+                    0 if self.character_builder.bonus_might > 0 => {
+                        self.character_builder.bonus_might -= 1;
                     }
-                    1 => {
-                        if self.character_builder.bonus_speed > 0 {
-                            self.character_builder.bonus_speed -= 1;
-                        }
+                    1 if self.character_builder.bonus_speed > 0 => {
+                        self.character_builder.bonus_speed -= 1;
                     }
-                    2 => {
-                        if self.character_builder.bonus_intellect > 0 {
-                            self.character_builder.bonus_intellect -= 1;
-                        }
+                    2 if self.character_builder.bonus_intellect > 0 => {
+                        self.character_builder.bonus_intellect -= 1;
                     }
                     _ => {}
                 }
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.character_builder.list_state > 0 {
-                    self.character_builder.list_state -= 1;
-                }
+            KeyCode::Up | KeyCode::Char('k') if self.character_builder.list_state > 0 => {
+                self.character_builder.list_state -= 1;
             }
-            KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => {
-                if self.character_builder.list_state < 2 {
-                    self.character_builder.list_state += 1;
-                }
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab
+                if self.character_builder.list_state < 2 =>
+            {
+                self.character_builder.list_state += 1;
             }
-            KeyCode::Enter => {
-                if total_allocated == bonus_total {
+            KeyCode::Enter if total_allocated == bonus_total => {
+                // Catch a pool the build would reject here, not at the preview
+                let negative = (0..3).find(|&stat| {
+                    self.projected_pool(stat)
+                        .is_some_and(|(_, value)| value < 0)
+                });
+                if let Some(stat) = negative {
+                    self.character_builder.list_state = stat;
+                    self.status_message = self.pool_hint(stat);
+                } else {
                     self.current_screen = Screen::AbilitySelect;
                     self.character_builder.reset_list_state();
                 }
@@ -858,21 +1171,62 @@ impl App {
         Ok(())
     }
 
-    fn handle_ability_select_keys(&mut self, key: KeyEvent) -> Result<()> {
-        // Get character type and tier 1 abilities
-        let char_type_name = self.character_builder.character_type.as_ref();
-        let char_type = char_type_name.and_then(|name| {
-            self.game_data
-                .types
-                .iter()
-                .find(|t| t.name.eq_ignore_ascii_case(name))
-        });
+    /// Pools the current allocation produces, computed the way `build_character` does
+    pub fn projected_pools(&self) -> Option<Pools> {
+        let builder = &self.character_builder;
+        let char_type = find_type(&self.game_data.types, builder.character_type.as_deref()?)?;
+        let base = Pools::new(
+            char_type.stat_pools.might as i32,
+            char_type.stat_pools.speed as i32,
+            char_type.stat_pools.intellect as i32,
+        );
+
+        let mut descriptor_mods = Pools::new(0, 0, 0);
+        let mut species_mods = Pools::new(0, 0, 0);
+        if let Some(name) = &builder.descriptor_or_species {
+            if builder.is_species {
+                if let Some(species) = find_species(&self.game_data.species, name) {
+                    let mods = &species.stat_modifiers;
+                    species_mods = Pools::new(mods.might, mods.speed, mods.intellect);
+                }
+            } else if let Some(descriptor) = find_descriptor(&self.game_data.descriptors, name) {
+                let mods = &descriptor.stat_modifiers;
+                descriptor_mods = Pools::new(mods.might, mods.speed, mods.intellect);
+            }
+        }
 
-        let tier_1 = char_type.and_then(|t| t.tier_abilities.iter().find(|ta| ta.tier == 1));
+        let bonus = Pools::new(
+            builder.bonus_might,
+            builder.bonus_speed,
+            builder.bonus_intellect,
+        );
+        Some(calculate_pools(base, descriptor_mods, species_mods, bonus))
+    }
+
+    /// Stat name and projected pool for a stat index (0=Might, 1=Speed, 2=Intellect)
+    fn projected_pool(&self, stat: usize) -> Option<(&'static str, i32)> {
+        let pools = self.projected_pools()?;
+        [
+            ("Might", pools.might),
+            ("Speed", pools.speed),
+            ("Intellect", pools.intellect),
+        ]
+        .get(stat)
+        .copied()
+    }
+
+    /// Nudge toward a stat whose projected pool is below the playable floor
+    pub fn pool_hint(&self, stat: usize) -> Option<String> {
+        let (name, value) = self.projected_pool(stat)?;
+        (value < DEFAULT_POOL_FLOOR)
+            .then(|| format!("{} would be {} — consider allocating here", name, value))
+    }
+
+    fn handle_ability_select_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let tier = self.character_builder.ability_tier;
 
-        if let Some(tier_abilities) = tier_1 {
-            let required = tier_abilities.count as usize;
-            let total_abilities = tier_abilities.abilities.len();
+        if let Some((abilities, required)) = self.current_tier_ability_choices() {
+            let total_abilities = abilities.len();
 
             match key.code {
                 KeyCode::Up | KeyCode::Char('k') => {
@@ -883,40 +1237,108 @@ impl App {
                 }
                 KeyCode::Char(' ') => {
                     // Toggle selection
-                    if self.character_builder.list_state < total_abilities {
-                        let ability_name = tier_abilities.abilities
-                            [self.character_builder.list_state]
-                            .name
-                            .clone();
-
-                        if let Some(pos) = self
-                            .character_builder
-                            .selected_abilities
-                            .iter()
-                            .position(|a| a == &ability_name)
-                        {
-                            self.character_builder.selected_abilities.remove(pos);
-                        } else if self.character_builder.selected_abilities.len() < required {
-                            self.character_builder.selected_abilities.push(ability_name);
+                    if let Some(ability) = abilities.get(self.character_builder.list_state) {
+                        let ability_name = ability.name.clone();
+                        let selection = self.character_builder.current_tier_selection_mut();
+
+                        if let Some(pos) = selection.iter().position(|a| a == &ability_name) {
+                            selection.remove(pos);
+                        } else if selection.len() < required {
+                            selection.push(ability_name);
                         }
                     }
                 }
-                KeyCode::Enter => {
-                    if self.character_builder.selected_abilities.len() == required {
+                KeyCode::Enter
+                    if self.character_builder.current_tier_selection().len() == required =>
+                {
+                    if tier < self.start_tier {
+                        self.character_builder.ability_tier += 1;
+                    } else {
                         self.current_screen = Screen::CypherSelect; // CHANGED: was CharacterPreview
-                        self.character_builder.reset_list_state();
                     }
+                    self.character_builder.reset_list_state();
                 }
                 KeyCode::Esc => {
-                    self.current_screen = Screen::StatAllocation;
+                    if tier > 1 {
+                        self.character_builder.ability_tier -= 1;
+                        self.character_builder.reset_list_state();
+                    } else {
+                        self.current_screen = Screen::StatAllocation;
+                    }
                 }
                 _ => {}
             }
+        } else if key.code == KeyCode::Esc {
+            self.current_screen = Screen::StatAllocation;
+        } else {
+            self.status_message = Some(format!("No tier {} abilities loaded for this type", tier));
         }
         Ok(())
     }
 
+    /// Abilities offered for the tier being picked, and how many to choose
+    pub fn current_tier_ability_choices(&self) -> Option<(Vec<Ability>, usize)> {
+        let char_type_name = self.character_builder.character_type.as_ref()?;
+        let char_type = self
+            .game_data
+            .types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(char_type_name))?;
+        let tier = self.character_builder.ability_tier;
+
+        if tier == 1 {
+            let tier_1 = char_type.tier_abilities.iter().find(|ta| ta.tier == 1)?;
+            return Some((tier_1.abilities.clone(), tier_1.count as usize));
+        }
+
+        // Hide abilities already taken at another tier
+        let current = self.character_builder.current_tier_selection();
+        let taken: Vec<String> = self
+            .character_builder
+            .selected_abilities
+            .iter()
+            .chain(self.character_builder.higher_tier_abilities.iter().flatten())
+            .filter(|a| !current.contains(a))
+            .cloned()
+            .collect();
+
+        let choices: Vec<Ability> =
+            crate::character::available_tier_abilities(char_type, tier, &taken)
+                .into_iter()
+                .cloned()
+                .collect();
+
+        if choices.is_empty() {
+            return None;
+        }
+        Some((choices, crate::character::tier_ability_count(char_type, tier)))
+    }
+
     fn handle_preview_keys(&mut self, key: KeyEvent) -> Result<()> {
+        if self.tag_input.is_some() {
+            self.handle_tag_input_keys(key);
+            return Ok(());
+        }
+
+        // Return from a preview peek to the creation step it was opened from
+        if matches!(key.code, KeyCode::F(2) | KeyCode::Esc) {
+            if let Some(screen) = self.previous_screen.take() {
+                self.peek_scroll = (self.preview_left_scroll, self.preview_right_scroll);
+                self.current_screen = screen;
+            }
+            return Ok(());
+        }
+
+        // A peek only looks; saving and editing wait for the finished character
+        if self.previous_screen.is_some()
+            && matches!(
+                key.code,
+                KeyCode::Char('s' | 'S' | 'e' | 'E' | 'n' | 'N' | 'r' | 'R' | 't' | 'T')
+            )
+        {
+            return Ok(());
+        }
+
         match key.code {
             // Tab to switch panels
             KeyCode::Tab => {
@@ -987,12 +1409,12 @@ impl App {
             }
 
             // ========== ADD EDIT MODE TRIGGER ==========
-            KeyCode::Char('e') | KeyCode::Char('E') => {
-                // Only allow editing if we loaded a character
-                if self.generated_character.is_some() && self.last_saved_file.is_none() {
-                    // This is a loaded character (has generated_character but no last_saved_file)
-                    self.enter_edit_mode();
-                }
+            // Only allow editing if we loaded a character
+            KeyCode::Char('e') | KeyCode::Char('E')
+                if self.generated_character.is_some() && self.last_saved_file.is_none() =>
+            {
+                // This is a loaded character (has generated_character but no last_saved_file)
+                self.enter_edit_mode();
             }
 
             KeyCode::Char('n') | KeyCode::Char('N') => {
@@ -1002,9 +1424,26 @@ impl App {
                 self.preview_left_scroll = 0;
                 self.preview_right_scroll = 0;
                 self.last_saved_file = None; // ← Clear save status
+                self.previous_screen = None;
+                self.peek_scroll = (0, 0);
                 self.current_screen = Screen::MainMenu;
             }
 
+            KeyCode::Char('w') | KeyCode::Char('W') => self.cycle_equipment_option(),
+
+            KeyCode::Char('t') | KeyCode::Char('T') => self.tag_input = Some(String::new()),
+
+            // Generated and loaded characters can redraw their numenera in place
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                if let Some(character) = &mut self.generated_character {
+                    character.reroll_numenera(
+                        &self.game_data,
+                        &crate::character::RerollOptions::default(),
+                    );
+                    self.last_saved_file = None;
+                }
+            }
+
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 self.should_quit = true;
             }
@@ -1015,18 +1454,7 @@ impl App {
 
     fn handle_cypher_select_keys(&mut self, key: KeyEvent) -> Result<()> {
         let total_cyphers = self.game_data.cyphers.len();
-
-        // Determine cypher limit
-        let cypher_limit = if let Some(type_name) = &self.character_builder.character_type {
-            self.game_data
-                .types
-                .iter()
-                .find(|t| t.name.eq_ignore_ascii_case(type_name))
-                .map(|t| t.starting_tier.cypher_limit as usize)
-                .unwrap_or(2)
-        } else {
-            2
-        };
+        let cypher_limit = self.cypher_limit();
 
         match key.code {
             KeyCode::Up | KeyCode::Char('k') => {
@@ -1040,26 +1468,15 @@ impl App {
                 let idx = self.character_builder.list_state;
 
                 // Check if already selected
-                if let Some(pos) = self
-                    .character_builder
-                    .selected_cyphers
-                    .iter()
-                    .position(|c| {
-                        // Compare by name (since we can't compare instances directly)
-                        if let Some(cypher) = self.game_data.cyphers.get(idx) {
-                            c.name == cypher.name
-                        } else {
-                            false
-                        }
-                    })
-                {
+                if let Some(pos) = self.chosen_cypher(idx) {
                     // Remove it
                     self.character_builder.selected_cyphers.remove(pos);
                 } else if self.character_builder.selected_cyphers.len() < cypher_limit {
                     // Add new instance (roll level NOW and store it)
-                    if let Some(cypher) = self.game_data.cyphers.get(idx) {
-                        let instance = crate::data::create_cypher_instance(cypher);
-                        self.character_builder.selected_cyphers.push(instance);
+                    if let Some(id) = self.pick_cypher(idx) {
+                        if self.animations {
+                            self.last_roll_flash = Some((id, Instant::now()));
+                        }
                     }
                 }
             }
@@ -1074,10 +1491,7 @@ impl App {
                     let idx = rng.gen_range(0..available.len());
                     let cypher_idx = available.remove(idx);
 
-                    if let Some(cypher) = self.game_data.cyphers.get(cypher_idx) {
-                        let instance = crate::data::create_cypher_instance(cypher);
-                        self.character_builder.selected_cyphers.push(instance);
-                    }
+                    self.pick_cypher(cypher_idx);
                 }
             }
             KeyCode::Char('c') | KeyCode::Char('C') => {
@@ -1110,24 +1524,10 @@ impl App {
                 let idx = self.character_builder.list_state;
 
                 // Check if already selected
-                if let Some(pos) = self
-                    .character_builder
-                    .selected_artifacts
-                    .iter()
-                    .position(|a| {
-                        if let Some(artifact) = self.game_data.artifacts.get(idx) {
-                            a.name == artifact.name
-                        } else {
-                            false
-                        }
-                    })
-                {
+                if let Some(pos) = self.chosen_artifact(idx) {
                     self.character_builder.selected_artifacts.remove(pos);
                 } else if self.character_builder.selected_artifacts.len() < max_artifacts {
-                    if let Some(artifact) = self.game_data.artifacts.get(idx) {
-                        let instance = crate::data::create_artifact_instance(artifact);
-                        self.character_builder.selected_artifacts.push(instance);
-                    }
+                    self.pick_artifact(idx);
                 }
             }
             KeyCode::Char('r') | KeyCode::Char('R') => {
@@ -1141,10 +1541,7 @@ impl App {
                     let idx = rng.gen_range(0..available.len());
                     let artifact_idx = available.remove(idx);
 
-                    if let Some(artifact) = self.game_data.artifacts.get(artifact_idx) {
-                        let instance = crate::data::create_artifact_instance(artifact);
-                        self.character_builder.selected_artifacts.push(instance);
-                    }
+                    self.pick_artifact(artifact_idx);
                 }
             }
             KeyCode::Char('c') | KeyCode::Char('C') => {
@@ -1164,7 +1561,7 @@ impl App {
 
     fn handle_oddity_select_keys(&mut self, key: KeyEvent) -> Result<()> {
         let total_oddities = self.game_data.oddities.len();
-        let required_oddities = 1;
+        let required_oddities = crate::character::STARTING_ODDITIES;
 
         match key.code {
             KeyCode::Up | KeyCode::Char('k') => {
@@ -1221,15 +1618,16 @@ impl App {
             KeyCode::Char('c') | KeyCode::Char('C') => {
                 self.character_builder.selected_oddities.clear();
             }
-            KeyCode::Enter => {
-                // Require exactly 1 oddity
-                if self.character_builder.selected_oddities.len() == required_oddities {
-                    self.shop_category = ShopCategory::Weapons;
-                    self.shop_list_state = 0;
-                    self.shop_cart.clear();
-                    self.shop_selected_category_index = 0;
-                    self.current_screen = Screen::EquipmentShop;
-                }
+            // Require the starting number of oddities
+            KeyCode::Enter
+                if self.character_builder.selected_oddities.len() == required_oddities =>
+            {
+                self.shop_category = ShopCategory::Weapons;
+                self.shop_list_state = 0;
+                self.shop_cart.clear();
+                self.shop_selected_category_index = 0;
+                self.shop_focus = ShopFocus::Items;
+                self.current_screen = Screen::EquipmentShop;
             }
             KeyCode::Esc => {
                 self.current_screen = Screen::CypherSelect; // ← Skip artifact screen
@@ -1241,20 +1639,23 @@ impl App {
 
     fn handle_equipment_shop_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            // Switch between category list and item list
+            // Switch between the item list and the cart
             KeyCode::Tab => {
-                // Toggle focus between categories and items
-                // We'll use list_state position to determine focus
+                self.shop_focus = match self.shop_focus {
+                    ShopFocus::Items if !self.shop_cart.is_empty() => ShopFocus::Cart,
+                    _ => ShopFocus::Items,
+                };
+                // The cart may have shrunk since it was last focused
+                self.shop_cart_selected = self
+                    .shop_cart_selected
+                    .min(self.shop_cart.len().saturating_sub(1));
             }
 
             // Navigate categories (when focused on left)
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.shop_selected_category_index > 0 {
-                    self.shop_selected_category_index -= 1;
-                    self.shop_category =
-                        ShopCategory::all()[self.shop_selected_category_index].clone();
-                    self.shop_list_state = 0; // Reset item selection
-                }
+            KeyCode::Left | KeyCode::Char('h') if self.shop_selected_category_index > 0 => {
+                self.shop_selected_category_index -= 1;
+                self.shop_category = ShopCategory::all()[self.shop_selected_category_index].clone();
+                self.shop_list_state = 0; // Reset item selection
             }
             KeyCode::Right | KeyCode::Char('l') => {
                 let categories = ShopCategory::all();
@@ -1265,11 +1666,18 @@ impl App {
                 }
             }
 
+            // Navigate cart lines (when the cart has focus)
+            KeyCode::Up | KeyCode::Char('k') if self.shop_focus == ShopFocus::Cart => {
+                self.shop_cart_selected = self.shop_cart_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.shop_focus == ShopFocus::Cart => {
+                self.shop_cart_selected =
+                    (self.shop_cart_selected + 1).min(self.shop_cart.len().saturating_sub(1));
+            }
+
             // Navigate items (when focused on right)
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.shop_list_state > 0 {
-                    self.shop_list_state -= 1;
-                }
+            KeyCode::Up | KeyCode::Char('k') if self.shop_list_state > 0 => {
+                self.shop_list_state -= 1;
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 let item_count = self.get_shop_items_for_category().len();
@@ -1283,9 +1691,11 @@ impl App {
                 self.add_selected_item_to_cart();
             }
 
-            // Remove from cart
+            // Remove from cart: one of the highlighted line, or the last line
             KeyCode::Char('r') | KeyCode::Char('R') => {
-                if !self.shop_cart.is_empty() {
+                if self.shop_focus == ShopFocus::Cart {
+                    self.remove_from_cart(self.shop_cart_selected);
+                } else if !self.shop_cart.is_empty() {
                     self.shop_cart.pop();
                 }
             }
@@ -1293,22 +1703,30 @@ impl App {
             // Clear cart
             KeyCode::Char('c') | KeyCode::Char('C') => {
                 self.shop_cart.clear();
+                self.shop_cart_selected = 0;
+                self.shop_focus = ShopFocus::Items;
+            }
+
+            // Cycle the weapon range filter
+            KeyCode::Char('f') | KeyCode::Char('F')
+                if self.shop_category == ShopCategory::Weapons =>
+            {
+                self.cycle_shop_range_filter();
             }
 
-            // Checkout (buy items)
+            // Checkout (buy items); kept out of a guard so a refused checkout
+            // doesn't fall through to the other arms
+            #[allow(clippy::collapsible_match)]
             KeyCode::Enter => {
-                self.checkout_cart()?;
-                self.preview_left_scroll = 0;
-                self.preview_right_scroll = 0;
-                self.current_screen = Screen::CharacterPreview;
+                if self.checkout_cart() {
+                    self.leave_shop();
+                }
             }
 
             // Skip shop
             KeyCode::Esc => {
-                self.shop_cart.clear();
-                self.preview_left_scroll = 0;
-                self.preview_right_scroll = 0;
-                self.current_screen = Screen::CharacterPreview;
+                self.skip_shop();
+                self.leave_shop();
             }
 
             _ => {}
@@ -1316,6 +1734,30 @@ impl App {
         Ok(())
     }
 
+    /// Step the weapon range filter through immediate, short and long, then off
+    fn cycle_shop_range_filter(&mut self) {
+        let next = match self.shop_range_filter.as_deref() {
+            None => Some(WEAPON_RANGES[0]),
+            Some(current) => WEAPON_RANGES
+                .iter()
+                .position(|r| r.eq_ignore_ascii_case(current))
+                .and_then(|i| WEAPON_RANGES.get(i + 1))
+                .copied(),
+        };
+        self.shop_range_filter = next.map(str::to_string);
+        self.shop_list_state = 0;
+    }
+
+    /// Cypher limit of the selected type at the starting tier (2 before a type is chosen)
+    pub fn cypher_limit(&self) -> usize {
+        self.character_builder
+            .character_type
+            .as_deref()
+            .and_then(|name| find_type(&self.game_data.types, name))
+            .map(|t| crate::character::cypher_limit_at_tier(t, self.start_tier) as usize)
+            .unwrap_or(2)
+    }
+
     /// Get items for the current category
     pub fn get_shop_items_for_category(&self) -> Vec<(String, u32, String)> {
         match self.shop_category {
@@ -1324,11 +1766,16 @@ impl App {
                 .equipment
                 .weapons
                 .iter()
+                .filter(|w| {
+                    self.shop_range_filter
+                        .as_deref()
+                        .is_none_or(|range| w.range.eq_ignore_ascii_case(range))
+                })
                 .map(|w| {
                     (
                         w.name.clone(),
                         w.cost,
-                        format!("{} weapon, {} damage", w.category, w.damage),
+                        format!("{} weapon, {} damage, {} range", w.category, w.damage, w.range),
                     )
                 })
                 .collect(),
@@ -1376,6 +1823,24 @@ impl App {
                 .iter()
                 .map(|c| (c.name.clone(), c.cost, c.notes.clone()))
                 .collect(),
+            ShopCategory::Cyphers => self
+                .game_data
+                .cyphers
+                .iter()
+                .map(|c| {
+                    (
+                        c.name.clone(),
+                        CYPHER_SHOP_PRICE,
+                        format!("Level {}: {}", c.level_formula, c.effect),
+                    )
+                })
+                .collect(),
+            ShopCategory::Oddities => self
+                .game_data
+                .oddities
+                .iter()
+                .map(|o| (o.name.clone(), o.value_shins, o.description.clone()))
+                .collect(),
         }
     }
 
@@ -1383,20 +1848,100 @@ impl App {
     pub fn add_selected_item_to_cart(&mut self) {
         let items = self.get_shop_items_for_category();
         if let Some((name, cost, _)) = items.get(self.shop_list_state) {
+            // Bought cyphers share the limit with the ones granted and chosen
+            let mut rolled = None;
+            if self.shop_category == ShopCategory::Cyphers {
+                let carried = self.granted_cypher_count()
+                    + self.character_builder.selected_cyphers.len()
+                    + self
+                        .shop_cart
+                        .iter()
+                        .filter(|item| item.category == ShopCategory::Cyphers.name())
+                        .map(|item| item.quantity as usize)
+                        .sum::<usize>();
+                let limit = self.cypher_limit();
+                if carried >= limit {
+                    self.status_message =
+                        Some(format!("Cypher limit reached ({}/{})", carried, limit));
+                    return;
+                }
+
+                // Roll the level now, so the preview and the saved sheet agree
+                rolled = self
+                    .game_data
+                    .cyphers
+                    .iter()
+                    .find(|c| c.name == *name)
+                    .map(crate::data::create_cypher_instance);
+            }
+
             // Check if already in cart (for stackable items, increase quantity)
             if let Some(cart_item) = self.shop_cart.iter_mut().find(|item| item.name == *name) {
                 cart_item.quantity += 1;
+                cart_item.cyphers.extend(rolled);
             } else {
                 self.shop_cart.push(ShopItem {
                     name: name.clone(),
                     cost: *cost,
                     category: self.shop_category.name().to_string(),
                     quantity: 1,
+                    cyphers: rolled.into_iter().collect(),
                 });
             }
         }
     }
 
+    /// Cyphers the chosen descriptor and focus grant, which count toward the limit
+    fn granted_cypher_count(&self) -> usize {
+        let builder = &self.character_builder;
+        let descriptor = builder
+            .descriptor_or_species
+            .as_deref()
+            .filter(|_| !builder.is_species)
+            .and_then(|name| find_descriptor(&self.game_data.descriptors, name));
+        let focus = builder
+            .focus
+            .as_deref()
+            .and_then(|name| find_focus(&self.game_data.foci, name));
+
+        descriptor
+            .into_iter()
+            .flat_map(|d| &d.equipment.cyphers)
+            .chain(focus.into_iter().flat_map(|f| &f.cyphers))
+            .filter(|name| {
+                self.game_data
+                    .cyphers
+                    .iter()
+                    .any(|c| c.name.eq_ignore_ascii_case(name))
+            })
+            .count()
+    }
+
+    /// Take one of the cart line at `index`, dropping the line when none are left
+    ///
+    /// Returns false if there is no such line. Focus moves back to the item
+    /// list once the cart is empty.
+    pub fn remove_from_cart(&mut self, index: usize) -> bool {
+        let Some(item) = self.shop_cart.get_mut(index) else {
+            return false;
+        };
+
+        if item.quantity > 1 {
+            item.quantity -= 1;
+            item.cyphers.pop();
+        } else {
+            self.shop_cart.remove(index);
+        }
+
+        self.shop_cart_selected = self
+            .shop_cart_selected
+            .min(self.shop_cart.len().saturating_sub(1));
+        if self.shop_cart.is_empty() {
+            self.shop_focus = ShopFocus::Items;
+        }
+        true
+    }
+
     /// Calculate total cost of cart
     pub fn cart_total(&self) -> u32 {
         self.shop_cart
@@ -1441,109 +1986,207 @@ impl App {
     }
 
     /// Checkout and apply purchases to character
-    pub fn checkout_cart(&mut self) -> Result<()> {
+    ///
+    /// Returns false, keeping the cart, when it costs more than the character has.
+    pub fn checkout_cart(&mut self) -> bool {
         let total_cost = self.cart_total();
         let available = self.available_shins();
 
         if total_cost > available {
-            // Can't afford - just clear cart
-            self.shop_cart.clear();
-            return Ok(());
+            self.status_message = Some(format!(
+                "Cart costs {} shins but only {} are available",
+                total_cost, available
+            ));
+            return false;
         }
 
         // Store purchases in character builder
         self.character_builder.purchased_items = self.shop_cart.clone();
 
-        Ok(())
+        true
     }
 
-    fn save_character(&mut self) -> Result<String> {
-        use crate::character::build_character;
-        use chrono::Local;
+    /// Leave the shop without buying anything
+    ///
+    /// Also drops purchases from an earlier visit, so skipping ends up with
+    /// the same sheet as checking out an empty cart.
+    pub fn skip_shop(&mut self) {
+        self.shop_cart.clear();
+        self.character_builder.purchased_items.clear();
+    }
 
-        // Check if we have a pre-generated character or need to build one
-        let character = if let Some(ref generated) = self.generated_character {
-            generated.clone()
-        } else {
-            // Build character from builder
-            let mut char_sheet = build_character(
+    fn leave_shop(&mut self) {
+        self.shop_focus = ShopFocus::Items;
+        self.shop_cart_selected = 0;
+        self.preview_left_scroll = 0;
+        self.preview_right_scroll = 0;
+        self.current_screen = Screen::CharacterPreview;
+    }
+
+    /// Equipment options offered by the selected type (empty when it has none)
+    pub fn equipment_options(&self) -> Vec<String> {
+        self.character_builder
+            .character_type
+            .as_ref()
+            .and_then(|name| self.game_data.types.iter().find(|t| &t.name == name))
+            .map(|t| t.equipment.option_labels())
+            .unwrap_or_default()
+    }
+
+    /// Switch a manually built character to the type's next equipment option
+    pub fn cycle_equipment_option(&mut self) {
+        let count = self.equipment_options().len();
+        if self.generated_character.is_none() && count > 0 {
+            self.character_builder.equipment_option =
+                (self.character_builder.equipment_option + 1) % count;
+        }
+    }
+
+    /// Build the character chosen on the creation screens, with tier advances,
+    /// cyphers and shop purchases, returning warnings such as dropped cyphers
+    pub fn build_selected_character(&self) -> Result<(CharacterSheet, Vec<String>)> {
+        let (mut sheet, mut warnings) = self
+            .character_builder
+            .to_builder(&self.game_data)?
+            .build_checked_with_rng(
                 &self.game_data,
-                self.character_builder.name.clone(),
-                self.character_builder
-                    .character_type
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("No character type selected"))?,
-                self.character_builder
-                    .descriptor_or_species
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("No descriptor/species selected"))?,
-                self.character_builder
-                    .focus
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("No focus selected"))?,
-                self.character_builder.bonus_might,
-                self.character_builder.bonus_speed,
-                self.character_builder.bonus_intellect,
-                self.character_builder.selected_abilities.clone(),
+                &mut StdRng::seed_from_u64(self.character_builder.build_seed),
             )?;
 
-            // Set gender from builder
-            char_sheet.gender = self.character_builder.gender.clone();
+        // Advance to the starting tier
+        if let Some(char_type) = self
+            .character_builder
+            .character_type
+            .as_deref()
+            .and_then(|name| find_type(&self.game_data.types, name))
+        {
+            crate::character::advance_to_tier(
+                &mut sheet,
+                char_type,
+                &self.character_builder.higher_tier_abilities,
+            )?;
+        }
 
-            // Add selected cyphers
-            for cypher in &self.character_builder.selected_cyphers {
-                let _ = char_sheet.add_cypher(cypher.clone());
-            }
+        // Add selected cyphers after advancing, so the tier's limit applies
+        let dropped: Vec<String> = self
+            .character_builder
+            .selected_cyphers
+            .iter()
+            .filter(|cypher| sheet.add_cypher((*cypher).clone()).is_err())
+            .map(|cypher| cypher.name.clone())
+            .collect();
+        if !dropped.is_empty() {
+            warnings.push(format!(
+                "Over cypher limit, dropped: {}",
+                dropped.join(", ")
+            ));
+        }
+
+        warnings.extend(self.apply_shop_purchases(&mut sheet)?);
+        for tag in &self.character_builder.tags {
+            sheet.add_tag(tag);
+        }
+        Ok((sheet, warnings))
+    }
 
-            // Add selected artifacts
-            for artifact in &self.character_builder.selected_artifacts {
-                char_sheet.add_artifact(artifact.clone());
+    /// Tag the previewed character, generated or built; returns false for blank or repeated tags
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let added = match &mut self.generated_character {
+            Some(character) => character.add_tag(tag),
+            None => {
+                let tags = &self.character_builder.tags;
+                let tag = tag.trim();
+                if tag.is_empty() || tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    false
+                } else {
+                    self.character_builder.tags.push(tag.to_string());
+                    true
+                }
             }
+        };
+        // A tagged character differs from the one last saved
+        if added {
+            self.last_saved_file = None;
+        }
+        added
+    }
 
-            // Add selected oddities
-            for oddity in &self.character_builder.selected_oddities {
-                char_sheet.add_oddity(oddity.clone());
+    /// Typing a tag on the preview: Enter adds it, Esc cancels
+    fn handle_tag_input_keys(&mut self, key: KeyEvent) {
+        let Some(input) = &mut self.tag_input else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Enter => {
+                let tag = input.clone();
+                self.tag_input = None;
+                if !self.add_tag(&tag) && !tag.trim().is_empty() {
+                    self.status_message = Some(format!("Already tagged '{}'", tag.trim()));
+                }
             }
+            KeyCode::Esc => self.tag_input = None,
+            _ => {}
+        }
+    }
 
-            // Apply shop purchases
-            self.apply_shop_purchases(&mut char_sheet)?;
+    fn save_character(&mut self) -> Result<String> {
+        use chrono::Local;
 
+        // Check if we have a pre-generated character or need to build one
+        let character = if let Some(ref generated) = self.generated_character {
+            generated.clone()
+        } else {
+            let (char_sheet, warnings) = self.build_selected_character()?;
+            if !warnings.is_empty() {
+                self.status_message = Some(warnings.join("; "));
+            }
             char_sheet
         };
 
-        // Generate filename with timestamp
-        let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S");
-        let sanitized_name = character
-            .name
-            .chars()
-            .map(|c| match c {
-                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
-                ' ' => '_',
-                _ => '-',
-            })
-            .collect::<String>();
-
-        let base_filename = format!("{}_{}", sanitized_name, timestamp);
-
-        std::fs::create_dir_all("output")?;
-
-        // ========== SAVE MARKDOWN ==========
-        let md_filename = format!("{}.md", base_filename);
-        let md_path = format!("output/{}", md_filename);
-        let markdown = crate::output::format_character_sheet(&character);
-        std::fs::write(&md_path, markdown)?;
+        // Generate filename from the template, or the name with a timestamp
+        let output_dir = std::path::Path::new(&self.output_dir);
+        std::fs::create_dir_all(output_dir)?;
+
+        let base_filename = match &self.name_template {
+            Some(template) => crate::output::markdown::file_stem(
+                &template.expand(&character),
+                output_dir,
+                &["md", "json"],
+                false,
+            ),
+            None => {
+                let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S");
+                let sanitized_name = crate::output::markdown::sanitize_filename(&character.name);
+                format!("{}_{}", sanitized_name, timestamp)
+            }
+        };
 
-        // ========== SAVE JSON ==========
-        let json_filename = format!("{}.json", base_filename);
-        let json_path = format!("output/{}", json_filename);
-        let json = serde_json::to_string_pretty(&character)?;
-        std::fs::write(&json_path, json)?;
+        // ========== SAVE MARKDOWN AND JSON ==========
+        for format in [OutputFormat::Markdown, OutputFormat::Json] {
+            let filename = format!("{}.{}", base_filename, format.extension());
+            std::fs::write(output_dir.join(filename), format.render(&character)?)?;
+        }
 
-        Ok(md_filename) // Return markdown filename for display
+        // Return markdown filename for display
+        Ok(format!(
+            "{}.{}",
+            base_filename,
+            OutputFormat::Markdown.extension()
+        ))
     }
 
     /// Apply shop purchases to character sheet
-    fn apply_shop_purchases(&self, character: &mut crate::CharacterSheet) -> Result<()> {
+    ///
+    /// Returns warnings for bought cyphers dropped over the cypher limit, or
+    /// for purchases the sheet can no longer afford (which are left off).
+    pub fn apply_shop_purchases(
+        &self,
+        character: &mut crate::CharacterSheet,
+    ) -> Result<Vec<String>> {
         let total_cost: u32 = self
             .character_builder
             .purchased_items
@@ -1555,11 +2198,14 @@ impl App {
         if character.equipment.shins >= total_cost {
             character.equipment.shins -= total_cost;
         } else {
-            // Can't afford - just return without applying purchases
-            return Ok(());
+            return Ok(vec![format!(
+                "Shop purchases cost {} shins but only {} are available; none were applied",
+                total_cost, character.equipment.shins
+            )]);
         }
 
         // Apply purchases by category
+        let mut dropped = Vec::new();
         for item in self.character_builder.purchased_items.iter() {
             match item.category.as_str() {
                 "Weapons" => {
@@ -1572,9 +2218,7 @@ impl App {
                         .find(|w| w.name == item.name)
                     {
                         for _ in 0..item.quantity {
-                            let weapon_string =
-                                format!("{} ({} damage)", weapon.name, weapon.damage);
-                            character.equipment.add_weapon(weapon_string);
+                            character.equipment.add_weapon(weapon.label());
                         }
                     }
                 }
@@ -1612,40 +2256,68 @@ impl App {
                         character.equipment.add_gear(item.name.clone());
                     }
                 }
+                "Cyphers" => {
+                    // Levels were rolled when the cyphers were carted
+                    for cypher in &item.cyphers {
+                        if character.add_cypher(cypher.clone()).is_err() {
+                            dropped.push(cypher.name.clone());
+                        }
+                    }
+                }
+                "Oddities" => {
+                    if let Some(oddity) =
+                        self.game_data.oddities.iter().find(|o| o.name == item.name)
+                    {
+                        for _ in 0..item.quantity {
+                            character.add_oddity(oddity.clone());
+                        }
+                    }
+                }
                 _ => {}
             }
         }
 
-        Ok(())
+        character.equipment.record_bulk(&self.game_data.equipment);
+        character.sort_equipment();
+
+        let mut warnings = Vec::new();
+        if !dropped.is_empty() {
+            warnings.push(format!(
+                "Over cypher limit, dropped: {}",
+                dropped.join(", ")
+            ));
+        }
+        Ok(warnings)
     }
     /// Load list of character files from output directory
     fn load_character_list(&mut self) -> Result<()> {
-        use std::fs;
-
         self.loader_files.clear();
+        self.loader_sheets.clear();
         self.loader_list_state = 0;
 
         // Check if output directory exists
-        if !std::path::Path::new("output").exists() {
+        let root = std::path::Path::new(&self.output_dir);
+        if !root.exists() {
             return Ok(());
         }
 
-        // Read all .json files from output directory
-        let entries = fs::read_dir("output")?;
-
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                if let Some(filename) = path.file_name().and_then(|s| s.to_str()) {
-                    self.loader_files.push(filename.to_string());
-                }
-            }
+        // Collect .json files from the output directory and its subfolders
+        let skipped = collect_json_files(root, root, 0, &mut self.loader_files);
+        if skipped > 0 {
+            self.status_message = Some(format!("Skipped {} unreadable folder(s)", skipped));
         }
 
         // Sort files by name (most recent first due to timestamp)
         self.loader_files.sort();
         self.loader_files.reverse();
 
+        // Parse each file once so the list can show stats without reloading
+        self.loader_sheets = self
+            .loader_files
+            .iter()
+            .map(|filename| self.load_character_from_file(filename).ok())
+            .collect();
+
         Ok(())
     }
 
@@ -1653,9 +2325,10 @@ impl App {
     fn load_character_from_file(&self, filename: &str) -> Result<CharacterSheet> {
         use std::fs;
 
-        let path = format!("output/{}", filename);
+        let path = std::path::Path::new(&self.output_dir).join(filename);
         let json_content = fs::read_to_string(&path)?;
-        let character: CharacterSheet = serde_json::from_str(&json_content)?;
+        let mut character: CharacterSheet = serde_json::from_str(&json_content)?;
+        character.update_damage_track();
 
         Ok(character)
     }
@@ -1686,7 +2359,7 @@ impl App {
         if let Some(character) = &self.editing_character {
             if let Some(filename) = &self.edit_original_filename {
                 // Update the JSON file
-                let path = format!("output/{}", filename);
+                let path = std::path::Path::new(&self.output_dir).join(filename);
                 let json = serde_json::to_string_pretty(character)?;
                 std::fs::write(&path, json)?;
                 
@@ -1704,6 +2377,38 @@ impl App {
     }
 }
 
+/// How many folders deep the loader looks below the output directory
+const LOADER_MAX_DEPTH: usize = 3;
+
+/// Add .json files under `dir` to `files` as paths relative to `root`
+///
+/// Returns how many folders could not be read; they are skipped, not fatal.
+fn collect_json_files(
+    root: &std::path::Path,
+    dir: &std::path::Path,
+    depth: usize,
+    files: &mut Vec<String>,
+) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 1;
+    };
+
+    let mut skipped = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if depth < LOADER_MAX_DEPTH {
+                skipped += collect_json_files(root, &path, depth + 1, files);
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            if let Ok(relative) = path.strip_prefix(root) {
+                files.push(relative.to_string_lossy().to_string());
+            }
+        }
+    }
+    skipped
+}
+
 impl CharacterBuilder {
     pub fn new() -> Self {
         Self {
@@ -1718,15 +2423,125 @@ impl CharacterBuilder {
             bonus_speed: 0,
             bonus_intellect: 0,
             selected_abilities: Vec::new(),
+            ability_tier: 1,
+            higher_tier_abilities: Vec::new(),
+            equipment_option: 0,
             selected_cyphers: Vec::new(),
             selected_artifacts: Vec::new(),
             selected_oddities: Vec::new(),
+            cypher_sources: HashMap::new(),
+            artifact_sources: HashMap::new(),
             purchased_items: Vec::new(),
+            tags: Vec::new(),
             list_state: 0,
             scroll_offset: 0,
+            build_seed: rand::random(),
         }
     }
 
+    /// Byte offset of the name character at `index`, or the name's length past the end
+    fn name_byte_offset(&self, index: usize) -> usize {
+        self.name
+            .char_indices()
+            .nth(index)
+            .map_or(self.name.len(), |(offset, _)| offset)
+    }
+
+    /// Type a character at the name cursor
+    pub fn insert_name_char(&mut self, c: char) {
+        let offset = self.name_byte_offset(self.name_input_cursor);
+        self.name.insert(offset, c);
+        self.name_input_cursor += 1;
+    }
+
+    /// Delete the character before the name cursor (Backspace)
+    pub fn delete_name_char(&mut self) {
+        if self.name_input_cursor > 0 {
+            self.name_input_cursor -= 1;
+            let offset = self.name_byte_offset(self.name_input_cursor);
+            self.name.remove(offset);
+        }
+    }
+
+    /// Move the name cursor by `delta` characters, staying within the name
+    pub fn move_name_cursor(&mut self, delta: isize) {
+        self.name_input_cursor = self
+            .name_input_cursor
+            .saturating_add_signed(delta)
+            .min(self.name.chars().count());
+    }
+
+    /// Library builder for the tier 1 choices made so far
+    ///
+    /// Cyphers, tier advances and shop purchases are left to `App::build_selected_character`,
+    /// since cyphers must be added after advancing for the starting tier's limit to apply.
+    pub fn to_builder(&self, game_data: &GameData) -> Result<crate::character::CharacterBuilder> {
+        let type_name = self
+            .character_type
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No character type selected"))?;
+        let character_type = find_type(&game_data.types, type_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown character type '{}'", type_name))?;
+        let focus_name = self
+            .focus
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No focus selected"))?;
+        let focus = crate::data::find_focus(&game_data.foci, focus_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown focus '{}'", focus_name))?;
+        let descriptor_name = self
+            .descriptor_or_species
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No descriptor/species selected"))?;
+
+        let mut builder = crate::character::CharacterBuilder::new()
+            .with_name(self.name.clone())
+            .with_gender(self.gender.clone())
+            .with_type(character_type.clone())
+            .with_focus(focus.clone())
+            .with_bonus_points(self.bonus_might, self.bonus_speed, self.bonus_intellect)
+            .with_artifacts(self.selected_artifacts.clone())
+            .with_oddities(self.selected_oddities.clone());
+        builder = if self.is_species {
+            let species = find_species(&game_data.species, descriptor_name)
+                .ok_or_else(|| anyhow::anyhow!("Unknown species '{}'", descriptor_name))?;
+            builder.with_species(species.clone())
+        } else {
+            let descriptor = find_descriptor(&game_data.descriptors, descriptor_name)
+                .ok_or_else(|| anyhow::anyhow!("Unknown descriptor '{}'", descriptor_name))?;
+            builder.with_descriptor(descriptor.clone())
+        };
+        if !character_type.equipment.options.is_empty() {
+            builder = builder.with_equipment_option(self.equipment_option);
+        }
+        for ability in &self.selected_abilities {
+            builder = builder.add_ability(ability.clone());
+        }
+        Ok(builder)
+    }
+
+    /// Abilities picked for the tier currently shown on the ability screen
+    pub fn current_tier_selection(&self) -> &[String] {
+        match self.ability_tier {
+            0 | 1 => &self.selected_abilities,
+            tier => self
+                .higher_tier_abilities
+                .get(tier as usize - 2)
+                .map(|v| v.as_slice())
+                .unwrap_or(&[]),
+        }
+    }
+
+    fn current_tier_selection_mut(&mut self) -> &mut Vec<String> {
+        let index = self.ability_tier.saturating_sub(2) as usize;
+        if self.ability_tier <= 1 {
+            return &mut self.selected_abilities;
+        }
+        if self.higher_tier_abilities.len() <= index {
+            self.higher_tier_abilities.resize(index + 1, Vec::new());
+        }
+        &mut self.higher_tier_abilities[index]
+    }
+
     pub fn reset_list_state(&mut self) {
         self.list_state = 0;
         self.scroll_offset = 0;
@@ -1750,3 +2565,536 @@ impl Default for CharacterBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_character_respects_output_dir() {
+        let dir = std::env::temp_dir().join(format!("chargen-tui-save-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut app = App::new(GameData::new());
+        app.output_dir = dir.to_string_lossy().to_string();
+        app.generated_character = Some(CharacterSheet::new("Saved Hero".to_string()));
+
+        let md_filename = app.save_character().unwrap();
+        assert!(dir.join(&md_filename).exists());
+
+        app.load_character_list().unwrap();
+        assert_eq!(app.loader_files.len(), 1);
+        let loaded = app.load_character_from_file(&app.loader_files[0]).unwrap();
+        assert_eq!(loaded.name, "Saved Hero");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_character_list_scans_subfolders() {
+        let dir = std::env::temp_dir().join(format!("chargen-tui-scan-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let deep = (0..=LOADER_MAX_DEPTH).fold(dir.join("party"), |d, i| d.join(i.to_string()));
+        std::fs::create_dir_all(&deep).unwrap();
+
+        let json = serde_json::to_string(&CharacterSheet::new("Nested Hero".to_string())).unwrap();
+        std::fs::write(dir.join("party").join("nested.json"), &json).unwrap();
+        std::fs::write(dir.join("party").join("notes.md"), "").unwrap();
+        std::fs::write(deep.join("too_deep.json"), &json).unwrap();
+
+        let mut app = App::new(GameData::new());
+        app.output_dir = dir.to_string_lossy().to_string();
+        app.load_character_list().unwrap();
+
+        let expected = std::path::Path::new("party").join("nested.json");
+        assert_eq!(
+            app.loader_files,
+            vec![expected.to_string_lossy().to_string()]
+        );
+        let loaded = app.load_character_from_file(&app.loader_files[0]).unwrap();
+        assert_eq!(loaded.name, "Nested Hero");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_loader_caches_sheets_and_toggles_info() {
+        let dir = std::env::temp_dir().join(format!("chargen-tui-info-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut sheet = CharacterSheet::new("Scout".to_string());
+        sheet.character_type = "Jack".to_string();
+        sheet.tier = 2;
+        sheet.pools = CharacterPools::new(Pools::new(10, 12, 9));
+        let json = serde_json::to_string(&sheet).unwrap();
+        std::fs::write(dir.join("b_scout.json"), json).unwrap();
+        std::fs::write(dir.join("a_broken.json"), "{ not json").unwrap();
+
+        let mut app = App::new(GameData::new());
+        app.output_dir = dir.to_string_lossy().to_string();
+        app.current_screen = Screen::CharacterLoader;
+        app.load_character_list().unwrap();
+
+        assert_eq!(app.loader_files, vec!["b_scout.json", "a_broken.json"]);
+        assert!(app.loader_sheets[1].is_none());
+        let scout = app.loader_sheets[0].as_ref().unwrap();
+        assert_eq!(
+            crate::tui::screens::character_loader::stat_line(scout),
+            "Tier 2 · Jack · M 10 / S 12 / I 9"
+        );
+
+        assert!(app.loader_show_info);
+        let key = KeyEvent::from(KeyCode::Char('i'));
+        app.handle_character_loader_keys(key).unwrap();
+        assert!(!app.loader_show_info);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_peek_preview_keeps_scroll() {
+        let mut app = App::new(GameData::new());
+        app.character_builder.character_type = Some("Glaive".to_string());
+        app.character_builder.descriptor_or_species = Some("Charming".to_string());
+        app.character_builder.focus = Some("Masters Weaponry".to_string());
+        app.current_screen = Screen::CypherSelect;
+
+        app.peek_preview();
+        assert_eq!(app.current_screen, Screen::CharacterPreview);
+        app.preview_left_scroll = 4;
+        app.preview_right_scroll = 7;
+        app.handle_preview_keys(KeyEvent::from(KeyCode::F(2))).unwrap();
+        assert_eq!(app.current_screen, Screen::CypherSelect);
+
+        // Other screens may reset the live offsets; the next peek restores them
+        app.preview_left_scroll = 0;
+        app.preview_right_scroll = 0;
+        app.peek_preview();
+        assert_eq!((app.preview_left_scroll, app.preview_right_scroll), (4, 7));
+    }
+
+    #[test]
+    fn test_remove_from_cart_decrements_highlighted_item() {
+        let item = |name: &str, quantity: u32| ShopItem {
+            name: name.to_string(),
+            cost: 1,
+            category: "Gear".to_string(),
+            quantity,
+            cyphers: Vec::new(),
+        };
+        let mut app = App::new(GameData::new());
+        app.shop_cart = vec![item("Rope", 1), item("Torch", 2)];
+        app.shop_focus = ShopFocus::Cart;
+        app.shop_cart_selected = 1;
+
+        assert!(app.remove_from_cart(1));
+        assert_eq!(app.shop_cart[1].quantity, 1);
+        assert!(app.remove_from_cart(1));
+        assert_eq!(app.shop_cart.len(), 1);
+        assert_eq!(app.shop_cart[0].name, "Rope");
+        assert_eq!(app.shop_cart_selected, 0);
+        assert!(!app.remove_from_cart(3));
+
+        assert!(app.remove_from_cart(0));
+        assert!(app.shop_cart.is_empty());
+        assert_eq!(app.shop_focus, ShopFocus::Items);
+    }
+
+    /// Leave the shop with `key` after an earlier visit bought some rope
+    fn sheet_after_leaving_shop(key: KeyCode) -> CharacterSheet {
+        let mut app = App::new(GameData::new());
+        app.character_builder.purchased_items = vec![ShopItem {
+            name: "Rope".to_string(),
+            cost: 1,
+            category: "Gear".to_string(),
+            quantity: 1,
+            cyphers: Vec::new(),
+        }];
+        app.current_screen = Screen::EquipmentShop;
+
+        app.handle_equipment_shop_keys(KeyEvent::from(key)).unwrap();
+        assert_eq!(app.current_screen, Screen::CharacterPreview);
+
+        let mut sheet = CharacterSheet::new("Hero".to_string());
+        sheet.equipment.gear = vec!["Torch".to_string()];
+        sheet.equipment.shins = 10;
+        app.apply_shop_purchases(&mut sheet).unwrap();
+        sheet
+    }
+
+    #[test]
+    fn test_skip_shop_matches_empty_checkout() {
+        let skipped = sheet_after_leaving_shop(KeyCode::Esc);
+        let checked_out = sheet_after_leaving_shop(KeyCode::Enter);
+
+        assert_eq!(
+            serde_json::to_value(&skipped).unwrap(),
+            serde_json::to_value(&checked_out).unwrap()
+        );
+        assert_eq!(skipped.equipment.gear, vec!["Torch"]);
+        assert_eq!(skipped.equipment.shins, 10);
+    }
+
+    #[test]
+    fn test_same_named_cyphers_toggle_independently() {
+        let cypher = crate::data::Cypher {
+            name: "Detonation".to_string(),
+            level_formula: "1d6".to_string(),
+            cypher_type: "Manifest".to_string(),
+            category: "Attack".to_string(),
+            effect: String::new(),
+            form: String::new(),
+            duration: String::new(),
+        };
+        let mut game_data = GameData::new();
+        game_data.cyphers = vec![cypher.clone(), cypher];
+
+        let mut app = App::new(game_data);
+        app.current_screen = Screen::CypherSelect;
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+
+        app.handle_cypher_select_keys(space).unwrap();
+        assert_eq!(app.chosen_cypher(0), Some(0));
+        assert_eq!(app.chosen_cypher(1), None);
+
+        app.handle_cypher_select_keys(down).unwrap();
+        app.handle_cypher_select_keys(space).unwrap();
+        assert_eq!(app.chosen_cypher(1), Some(1));
+        assert_ne!(
+            app.character_builder.selected_cyphers[0].instance_id,
+            app.character_builder.selected_cyphers[1].instance_id
+        );
+
+        // Deselecting the second entry leaves the first one's instance alone
+        app.handle_cypher_select_keys(space).unwrap();
+        assert_eq!(app.character_builder.selected_cyphers.len(), 1);
+        assert_eq!(app.chosen_cypher(0), Some(0));
+        assert_eq!(app.chosen_cypher(1), None);
+    }
+
+    #[test]
+    fn test_favorite_focus_is_pinned_and_saved() {
+        let dir = std::env::temp_dir().join(format!("chargen-tui-fav-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("favorites.toml");
+
+        let mut game_data = GameData::new();
+        game_data.foci = ["Carries a Quiver", "Crafts Illusions", "Leads"]
+            .iter()
+            .map(|name| Focus {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect();
+
+        let mut app = App::new(game_data);
+        app.favorites_path = Some(path.clone());
+        app.current_screen = Screen::FocusSelect;
+        app.character_builder.list_state = 2;
+
+        let key = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        app.handle_focus_select_keys(key).unwrap();
+
+        let names: Vec<&str> = app.visible_foci().iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["Leads", "Carries a Quiver", "Crafts Illusions"]);
+        assert_eq!(app.character_builder.list_state, 0);
+        let saved = crate::data::load_favorites(&path).unwrap();
+        assert_eq!(saved.foci, vec!["Leads"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// An app on the shipped data with a complete tier-1 Glaive named Kestrel in the builder
+    fn glaive_app() -> App {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let game_data = crate::data::load_all_data_from_dir(&dir, |_| {}).unwrap();
+        let glaive = find_type(&game_data.types, "Glaive").unwrap().clone();
+        let focus = crate::data::get_suitable_foci(&game_data.foci, "Glaive")[0]
+            .name
+            .clone();
+
+        let mut app = App::new(game_data);
+        let builder = &mut app.character_builder;
+        builder.name = "Kestrel".to_string();
+        builder.character_type = Some("Glaive".to_string());
+        builder.descriptor_or_species = Some(app.game_data.descriptors[0].name.clone());
+        builder.focus = Some(focus);
+        builder.bonus_might = 6;
+        builder.selected_abilities = crate::character::tier_ability_choices(&glaive, 1)
+            .iter()
+            .take(crate::character::tier_ability_count(&glaive, 1))
+            .map(|a| a.name.clone())
+            .collect();
+        app
+    }
+
+    #[test]
+    fn test_stat_allocation_blocks_negative_projected_pool() {
+        let mut app = glaive_app();
+        app.game_data.descriptors[0].stat_modifiers.intellect = -10;
+        app.current_screen = Screen::StatAllocation;
+
+        // Glaive Intellect 7 - 10 leaves the pool at -3
+        assert_eq!(app.projected_pools().unwrap().intellect, -3);
+        assert!(app.pool_hint(0).is_none());
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.handle_stat_allocation_keys(enter).unwrap();
+        assert_eq!(app.current_screen, Screen::StatAllocation);
+        assert_eq!(app.character_builder.list_state, 2);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Intellect would be -3 — consider allocating here")
+        );
+
+        app.character_builder.bonus_might = 0;
+        app.character_builder.bonus_intellect = 6;
+        app.handle_stat_allocation_keys(enter).unwrap();
+        assert_eq!(app.current_screen, Screen::AbilitySelect);
+    }
+
+    #[test]
+    fn test_build_selected_character_uses_library_builder() {
+        let mut app = glaive_app();
+        let glaive = find_type(&app.game_data.types, "Glaive").unwrap().clone();
+        let oddity = app.game_data.oddities[0].clone();
+        app.character_builder.gender = Gender::Male;
+        app.character_builder.selected_oddities = vec![oddity.clone()];
+
+        let (sheet, _) = app.build_selected_character().unwrap();
+        assert_eq!(sheet.name, "Kestrel");
+        assert!(matches!(sheet.gender, Gender::Male));
+        assert_eq!(sheet.pools.maximum.might, glaive.stat_pools.might as i32 + 6);
+        assert!(sheet.oddities.iter().any(|o| o.name == oddity.name));
+        assert_eq!(
+            sheet.type_ability_names(),
+            app.character_builder.selected_abilities
+        );
+
+        app.character_builder.focus = None;
+        assert!(app.build_selected_character().is_err());
+    }
+
+    #[test]
+    fn test_name_input_handles_multibyte_characters() {
+        let mut app = App::new(GameData::new());
+        app.current_screen = Screen::NameInput;
+        let press = |app: &mut App, code| {
+            app.handle_name_input_keys(KeyEvent::from(code)).unwrap();
+        };
+
+        for c in "Zoé李白".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert_eq!(app.character_builder.name_input_cursor, 5);
+
+        // Insert and delete in the middle, between multi-byte characters
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Char('-'));
+        assert_eq!(app.character_builder.name, "Zoé-李白");
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.character_builder.name, "Zo李白");
+
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.character_builder.name_input_cursor, 4);
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.character_builder.name, "Zo李");
+    }
+
+    #[test]
+    fn test_preview_tag_entry() {
+        let mut app = App::new(GameData::new());
+        app.current_screen = Screen::CharacterPreview;
+        app.generated_character = Some(CharacterSheet::new("Guard".to_string()));
+        app.last_saved_file = Some("Guard.md".to_string());
+        let press = |app: &mut App, code| {
+            app.handle_preview_keys(KeyEvent::from(code)).unwrap();
+        };
+
+        press(&mut app, KeyCode::Char('t'));
+        for c in "town-guardx".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Enter);
+
+        let character = app.generated_character.as_ref().unwrap();
+        assert_eq!(character.tags, vec!["town-guard"]);
+        assert!(app.tag_input.is_none());
+        assert!(app.last_saved_file.is_none());
+
+        // Esc abandons the tag; typing 'q' while tagging doesn't quit
+        press(&mut app, KeyCode::Char('T'));
+        press(&mut app, KeyCode::Char('q'));
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.should_quit);
+        assert_eq!(app.generated_character.unwrap().tags.len(), 1);
+    }
+
+    #[test]
+    fn test_shop_sells_cyphers_within_budget_and_limit() {
+        let mut app = glaive_app();
+        let cypher = app.game_data.cyphers[0].clone();
+        app.character_builder.selected_cyphers = vec![crate::data::create_cypher_instance(&cypher)];
+
+        app.shop_category = ShopCategory::Cyphers;
+        app.shop_list_state = 0;
+        app.add_selected_item_to_cart();
+        assert_eq!(app.cart_total(), CYPHER_SHOP_PRICE);
+        assert!(app.cart_total() <= app.available_shins());
+
+        // The chosen cypher and the one in the cart fill the limit
+        app.add_selected_item_to_cart();
+        assert_eq!(app.shop_cart[0].quantity, 1);
+        assert!(app
+            .status_message
+            .as_deref()
+            .is_some_and(|m| m.contains("Cypher limit reached")));
+
+        assert!(app.checkout_cart());
+        let (sheet, warnings) = app.build_selected_character().unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(sheet.cyphers.len(), 2);
+        assert!(sheet.cyphers.iter().all(|c| c.name == cypher.name));
+        assert_eq!(
+            sheet.equipment.shins,
+            app.available_shins() - CYPHER_SHOP_PRICE
+        );
+    }
+
+    #[test]
+    fn test_repeated_builds_draw_the_same_oddities() {
+        let app = glaive_app();
+        let (first, _) = app.build_selected_character().unwrap();
+        let (second, _) = app.build_selected_character().unwrap();
+        assert!(!first.oddities.is_empty());
+        let names = |sheet: &CharacterSheet| -> Vec<String> {
+            sheet.oddities.iter().map(|o| o.name.clone()).collect()
+        };
+        assert_eq!(names(&first), names(&second));
+    }
+
+    #[test]
+    fn test_shop_cyphers_count_granted_and_keep_their_level() {
+        let cypher = crate::data::Cypher {
+            name: "Detonation".to_string(),
+            level_formula: "1d6".to_string(),
+            cypher_type: "Manifest".to_string(),
+            category: "Attack".to_string(),
+            effect: String::new(),
+            form: String::new(),
+            duration: String::new(),
+        };
+        let mut game_data = GameData::new();
+        game_data.cyphers.push(cypher);
+        game_data.foci.push(Focus {
+            name: "Carries a Quiver".to_string(),
+            cyphers: vec!["detonation".to_string()],
+            ..Default::default()
+        });
+
+        let mut app = App::new(game_data);
+        app.character_builder.focus = Some("Carries a Quiver".to_string());
+        app.shop_category = ShopCategory::Cyphers;
+        app.shop_list_state = 0;
+
+        // The focus's cypher and one bought fill the default limit of two
+        app.add_selected_item_to_cart();
+        app.add_selected_item_to_cart();
+        assert_eq!(app.shop_cart[0].quantity, 1);
+        let level = app.shop_cart[0].cyphers[0].level;
+
+        app.character_builder.purchased_items = app.shop_cart.clone();
+        for _ in 0..5 {
+            let mut sheet = CharacterSheet::new("Archer".to_string());
+            sheet.equipment.shins = 100;
+            assert!(app.apply_shop_purchases(&mut sheet).unwrap().is_empty());
+            assert_eq!(sheet.cyphers[0].level, level);
+        }
+
+        // A sheet already at its limit drops the bought cypher with a warning
+        let mut full = CharacterSheet::new("Archer".to_string());
+        full.equipment.shins = 100;
+        full.cypher_limit = 0;
+        let warnings = app.apply_shop_purchases(&mut full).unwrap();
+        assert_eq!(warnings, vec!["Over cypher limit, dropped: Detonation"]);
+    }
+
+    #[test]
+    fn test_checkout_over_budget_keeps_cart() {
+        let mut app = App::new(GameData::new());
+        app.current_screen = Screen::EquipmentShop;
+        app.shop_cart = vec![ShopItem {
+            name: "Rope".to_string(),
+            cost: 5,
+            category: "Gear".to_string(),
+            quantity: 1,
+            cyphers: Vec::new(),
+        }];
+
+        app.handle_equipment_shop_keys(KeyEvent::from(KeyCode::Enter))
+            .unwrap();
+
+        assert_eq!(app.current_screen, Screen::EquipmentShop);
+        assert_eq!(app.shop_cart.len(), 1);
+        assert!(app.character_builder.purchased_items.is_empty());
+        assert!(app
+            .status_message
+            .as_deref()
+            .is_some_and(|m| m.contains("only 0 are available")));
+    }
+
+    #[test]
+    fn test_edit_oddity_replaces_only_the_starting_one() {
+        let mut game_data = GameData::new();
+        game_data.oddities = ["Glowing Cube", "Singing Shell", "Warm Stone", "Bent Key"]
+            .iter()
+            .map(|name| Oddity {
+                id: name.to_lowercase(),
+                name: name.to_string(),
+                category: "curio".to_string(),
+                theme: "curio".to_string(),
+                value_shins: 1,
+                tags: vec![],
+                source: "Discovery".to_string(),
+                description: String::new(),
+                table_number: None,
+            })
+            .collect();
+        game_data.foci.push(crate::data::Focus {
+            name: "Carries a Quiver".to_string(),
+            oddities: vec!["Glowing Cube".to_string()],
+            ..Default::default()
+        });
+
+        // Granted, starting and bought oddities
+        let mut sheet = CharacterSheet::new("Hero".to_string());
+        sheet.focus = "Carries a Quiver".to_string();
+        sheet.oddities = game_data.oddities[..3].to_vec();
+        let mut app = App::new(game_data);
+        app.editing_character = Some(sheet);
+        app.handle_edit_menu_keys(KeyEvent::from(KeyCode::Char('5'))).unwrap();
+        assert_eq!(app.character_builder.selected_oddities.len(), 1);
+        assert_eq!(app.character_builder.selected_oddities[0].name, "Singing Shell");
+
+        // Enter with nothing selected is refused with a reason
+        app.handle_edit_oddity_keys(KeyEvent::from(KeyCode::Char('c'))).unwrap();
+        app.handle_edit_oddity_keys(KeyEvent::from(KeyCode::Enter)).unwrap();
+        assert_eq!(app.current_screen, Screen::EditOddity);
+        assert!(app.status_message.is_some());
+
+        app.character_builder.list_state = 3;
+        app.handle_edit_oddity_keys(KeyEvent::from(KeyCode::Char(' '))).unwrap();
+        app.handle_edit_oddity_keys(KeyEvent::from(KeyCode::Enter)).unwrap();
+        assert_eq!(app.current_screen, Screen::EditMenu);
+        let sheet = app.editing_character.as_ref().unwrap();
+        let names: Vec<&str> = sheet.oddities.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, ["Glowing Cube", "Bent Key", "Warm Stone"]);
+    }
+}
@@ -18,7 +18,12 @@ impl EventHandler {
         }
     }
 
-    /// Get the next event (keyboard, mouse, resize)
+    /// Block until the next event arrives
+    pub fn next_event(&mut self) -> Result<Event> {
+        Ok(event::read()?)
+    }
+
+    /// Get the next event (keyboard, mouse, resize), or None after one tick
     pub fn poll_event(&mut self) -> Result<Option<Event>> {
         // Poll for events with timeout
         if event::poll(self.tick_rate)? {
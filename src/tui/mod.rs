@@ -14,14 +14,77 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::path::PathBuf;
 
 use crate::data::GameData;
+use crate::output::NameTemplate;
 use app::App;
 use events::EventHandler;
 use ui::render;
 
 /// Run the TUI application
 pub fn run(game_data: &GameData) -> Result<()> {
+    run_with_start_tier(game_data, 1)
+}
+
+/// Run the TUI application, creating characters that start at `start_tier`
+pub fn run_with_start_tier(game_data: &GameData, start_tier: u32) -> Result<()> {
+    run_with_options(
+        game_data,
+        &TuiOptions {
+            start_tier,
+            ..TuiOptions::default()
+        },
+    )
+}
+
+/// Settings for a TUI session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TuiOptions {
+    /// Tier new characters start at
+    pub start_tier: u32,
+    /// Show cosmetic animations such as cypher roll reveals
+    pub animations: bool,
+    /// Directory characters are saved to and loaded from
+    pub output_dir: String,
+    /// Pair colour-coded status with text tags and symbols
+    pub accessible: bool,
+    /// favorites.toml to pin entries from and rewrite on toggle; unset keeps favorites in memory
+    pub favorites_path: Option<PathBuf>,
+    /// Template saved files are named from; unset uses the name and a timestamp
+    pub name_template: Option<NameTemplate>,
+}
+
+impl Default for TuiOptions {
+    fn default() -> Self {
+        Self {
+            start_tier: 1,
+            animations: true,
+            output_dir: "output".to_string(),
+            accessible: false,
+            favorites_path: None,
+            name_template: None,
+        }
+    }
+}
+
+/// Run the TUI application with the given options
+pub fn run_with_options(game_data: &GameData, options: &TuiOptions) -> Result<()> {
+    let start_tier = options.start_tier;
+    if !(1..=crate::character::MAX_TIER).contains(&start_tier) {
+        anyhow::bail!(
+            "Starting tier must be between 1 and {}",
+            crate::character::MAX_TIER
+        );
+    }
+
+    // A missing favorites file just means nothing is pinned yet
+    let favorites = match &options.favorites_path {
+        Some(path) => crate::data::load_favorites(path)?,
+        None => crate::data::Favorites::default(),
+    };
+    let favorite_warnings = crate::data::validate_favorites(&favorites, game_data);
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -31,6 +94,16 @@ pub fn run(game_data: &GameData) -> Result<()> {
 
     // Create app state
     let mut app = App::new(game_data.clone());
+    app.start_tier = start_tier;
+    app.animations = options.animations;
+    app.output_dir = options.output_dir.clone();
+    app.accessible = options.accessible;
+    app.favorites = favorites;
+    app.favorites_path = options.favorites_path.clone();
+    app.name_template = options.name_template.clone();
+    if !favorite_warnings.is_empty() {
+        app.status_message = Some(favorite_warnings.join("; "));
+    }
     let event_handler = EventHandler::new(250);
 
     // Run the main loop
@@ -55,11 +128,20 @@ fn run_app<B: ratatui::backend::Backend>(
     mut event_handler: EventHandler,
 ) -> Result<()> {
     loop {
-        // Draw UI
-        terminal.draw(|f| render(f, app))?;
+        // Draw UI only when something changed
+        if app.needs_redraw {
+            terminal.draw(|f| render(f, app))?;
+            app.needs_redraw = false;
+        }
 
-        // Handle events
-        if let Some(event) = event_handler.poll_event()? {
+        // Block for input unless an animation needs ticks
+        if app.wants_ticks() {
+            match event_handler.poll_event()? {
+                Some(event) => app.handle_event(event)?,
+                None => app.on_tick(),
+            }
+        } else {
+            let event = event_handler.next_event()?;
             app.handle_event(event)?;
         }
 
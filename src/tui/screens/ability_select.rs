@@ -5,11 +5,15 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::data::Ability;
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Step 5: Select Type Abilities");
@@ -19,23 +23,16 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         .constraints([
             Constraint::Length(3), // Instructions
             Constraint::Min(0),    // Ability list
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
-    // Get character type and tier 1 abilities
-    let char_type_name = app.character_builder.character_type.as_ref();
-    let char_type = char_type_name.and_then(|name| {
-        app.game_data
-            .types
-            .iter()
-            .find(|t| t.name.eq_ignore_ascii_case(name))
-    });
-
-    let tier_1 = char_type.and_then(|t| t.tier_abilities.iter().find(|ta| ta.tier == 1));
+    // Get the abilities offered for the tier being picked
+    let tier = app.character_builder.ability_tier;
+    let selection = app.character_builder.current_tier_selection();
 
-    if let Some(tier_abilities) = tier_1 {
-        let required = tier_abilities.count as usize;
-        let selected_count = app.character_builder.selected_abilities.len();
+    if let Some((abilities, required)) = app.current_tier_ability_choices() {
+        let selected_count = selection.len();
 
         // Instructions with dynamic status
         let status_text = if selected_count == required {
@@ -46,7 +43,14 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         
         let instructions = vec![
             Line::from(Span::styled(
-                format!("Choose {} Tier 1 abilities for your type", required),
+                if tier == 1 {
+                    format!("Choose {} Tier 1 abilities for your type", required)
+                } else {
+                    format!(
+                        "Choose {} Tier {} abilities (or lower-tier ones) - tier {} of {}",
+                        required, tier, tier, app.start_tier
+                    )
+                },
                 Style::default().fg(Color::Gray),
             )),
             Line::from(Span::styled(
@@ -63,20 +67,29 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
 
         let instruction_text = Paragraph::new(instructions).alignment(Alignment::Center);
 
+        // Split body: ability list on the left, highlighted ability details on the right
+        let body_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(50), // Ability list
+                Constraint::Percentage(50), // Detail pane
+            ])
+            .split(chunks[1]);
+
         // Build ability list with scrolling
         let mut lines = vec![Line::from("")];
         let current_index = app.character_builder.list_state;
-        let total_abilities = tier_abilities.abilities.len();
+        let total_abilities = abilities.len();
 
-        // Calculate visible range - each ability takes ~5 lines
-        let visible_items = (chunks[1].height as usize / 5).max(2);
+        // Calculate visible range - each ability takes 2 lines
+        let visible_items = (body_chunks[0].height as usize / 2).max(2);
         let scroll_offset = if current_index > visible_items / 2 {
             (current_index - visible_items / 2).min(total_abilities.saturating_sub(visible_items))
         } else {
             0
         };
 
-        for (i, ability) in tier_abilities.abilities.iter().enumerate() {
+        for (i, ability) in abilities.iter().enumerate() {
             // Skip items above viewport
             if i < scroll_offset {
                 continue;
@@ -87,10 +100,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             }
 
             let is_selected = i == current_index;
-            let is_checked = app
-                .character_builder
-                .selected_abilities
-                .contains(&ability.name);
+            let is_checked = selection.contains(&ability.name);
 
             // Checkbox and selection indicator
             let checkbox = if is_checked { "[✓]" } else { "[ ]" };
@@ -136,12 +146,6 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
                 ),
             ]));
 
-            // Description
-            lines.push(Line::from(Span::styled(
-                format!("    {}", ability.description),
-                Style::default().fg(Color::Gray),
-            )));
-
             lines.push(Line::from(""));
         }
 
@@ -168,9 +172,13 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
 
         let list = Paragraph::new(lines);
 
+        let highlighted = abilities.get(current_index);
+        let is_checked = highlighted.is_some_and(|a| selection.contains(&a.name));
+
         f.render_widget(block, area);
         f.render_widget(instruction_text, chunks[0]);
-        f.render_widget(list, chunks[1]);
+        f.render_widget(list, body_chunks[0]);
+        render_detail_pane(f, body_chunks[1], highlighted, is_checked);
     } else {
         // Fallback if no abilities found
         let error = Paragraph::new("No abilities found for this character type")
@@ -180,4 +188,65 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         f.render_widget(block, area);
         f.render_widget(error, chunks[0]);
     }
-}
\ No newline at end of file
+
+    render_nav_footer(f, chunks[2], &app.current_screen);
+}
+
+/// Render the full text of the highlighted ability
+fn render_detail_pane(f: &mut Frame, area: Rect, ability: Option<&Ability>, is_checked: bool) {
+    let mut lines = vec![Line::from("")];
+
+    if let Some(ability) = ability {
+        lines.push(Line::from(Span::styled(
+            ability.name.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+
+        let cost = if ability.cost.is_empty() {
+            "—".to_string()
+        } else {
+            ability.cost.clone()
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Cost: ", Style::default().fg(Color::Gray)),
+            Span::styled(cost, Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("Type: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                ability.ability_type.clone(),
+                Style::default().fg(Color::Magenta),
+            ),
+        ]));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            ability.description.clone(),
+            Style::default().fg(Color::White),
+        )));
+        lines.push(Line::from(""));
+
+        let (status, status_color) = if is_checked {
+            ("✓ Selected", Color::Green)
+        } else {
+            ("Not selected (Space to toggle)", Color::DarkGray)
+        };
+        lines.push(Line::from(Span::styled(
+            status,
+            Style::default().fg(status_color),
+        )));
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(Span::styled(" Details ", Style::default().fg(Color::Cyan))),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(detail, area);
+}
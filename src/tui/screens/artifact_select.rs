@@ -9,7 +9,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Step 9: Select Artifacts (Optional)");
@@ -20,6 +23,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(4), // Instructions
             Constraint::Min(0),    // List
             Constraint::Length(3), // Selection summary
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -62,11 +66,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         }
 
         let is_selected = i == selected_state;
-        let is_chosen = app
-            .character_builder
-            .selected_artifacts
-            .iter()
-            .any(|a| a.name == artifact.name);
+        let is_chosen = app.chosen_artifact(i).is_some();
 
         let prefix = if is_chosen { "[✓] " } else { "[ ] " };
         let style = if is_selected {
@@ -138,4 +138,5 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(instructions_widget, chunks[0]);
     f.render_widget(list, chunks[1]);
     f.render_widget(summary, chunks[2]);
-}
\ No newline at end of file
+    render_nav_footer(f, chunks[3], &app.current_screen);
+}
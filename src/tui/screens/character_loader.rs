@@ -5,10 +5,11 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+use crate::character::CharacterSheet;
 use crate::tui::app::App;
 use crate::tui::ui::centered_block;
 
@@ -25,18 +26,18 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         ])
         .split(block.inner(area));
 
-    render_header(f, chunks[0]);
+    render_header(f, chunks[0], app);
     render_file_list(f, chunks[1], app);
     render_footer(f, chunks[2], app);
 
     f.render_widget(block, area);
 }
 
-fn render_header(f: &mut Frame, area: Rect) {
+fn render_header(f: &mut Frame, area: Rect, app: &App) {
     let header = Paragraph::new(vec![
         Line::from(""),
         Line::from(Span::styled(
-            "Select a character to load:",
+            format!("Select a character to load from {}:", app.output_dir),
             Style::default().fg(Color::Cyan),
         )),
     ])
@@ -90,10 +91,22 @@ fn render_file_list(f: &mut Frame, area: Rect, app: &App) {
 
                 let prefix = if is_selected { "> " } else { "  " };
 
-                ListItem::new(Line::from(vec![
+                let mut lines = vec![Line::from(vec![
                     Span::styled(prefix, style),
                     Span::styled(display_name, style),
-                ]))
+                ])];
+                if app.loader_show_info {
+                    let info = match app.loader_sheets.get(i).and_then(Option::as_ref) {
+                        Some(sheet) => stat_line(sheet),
+                        None => "(could not be read)".to_string(),
+                    };
+                    lines.push(Line::from(Span::styled(
+                        format!("    {}", info),
+                        Style::default().fg(Color::Gray),
+                    )));
+                }
+
+                ListItem::new(lines)
             })
             .collect();
 
@@ -107,15 +120,26 @@ fn render_file_list(f: &mut Frame, area: Rect, app: &App) {
                 )),
         );
 
-        f.render_widget(list, area);
+        // The list scrolls to keep the selection visible, whatever the row height
+        let mut state = ListState::default().with_selected(Some(app.loader_list_state));
+        f.render_stateful_widget(list, area, &mut state);
     }
 }
 
+/// Tier, type and maximum pools, for picking a character without opening it
+pub fn stat_line(sheet: &CharacterSheet) -> String {
+    let pools = &sheet.pools.maximum;
+    format!(
+        "Tier {} · {} · M {} / S {} / I {}",
+        sheet.tier, sheet.character_type, pools.might, pools.speed, pools.intellect
+    )
+}
+
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     let footer_text = if app.loader_files.is_empty() {
         "[ESC] Back to Menu"
     } else {
-        "[↑↓] Navigate  |  [Enter] Load Character  |  [ESC] Back to Menu"
+        "[↑↓] Navigate  |  [Enter] Load Character  |  [I] Toggle Info  |  [ESC] Back to Menu"
     };
 
     let footer = Paragraph::new(footer_text)
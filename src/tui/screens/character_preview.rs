@@ -10,7 +10,9 @@ use ratatui::{
 };
 
 use crate::tui::app::{App, PreviewPanel};
-use crate::tui::ui::centered_block;
+use crate::tui::ui::{
+    centered_block, damage_track_style, damage_track_symbol, pool_style, pool_tag,
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Character Complete!");
@@ -49,134 +51,17 @@ fn get_character(app: &App) -> Option<crate::CharacterSheet> {
         return Some(character.clone());
     }
 
-    // ========== VALIDATION BEFORE BUILDING ==========
-    // Make sure all required fields are present
-    let character_type = app.character_builder.character_type.as_deref()?;
-    let descriptor_or_species = app.character_builder.descriptor_or_species.as_deref()?;
-    let focus = app.character_builder.focus.as_deref()?;
+    // Otherwise, build from the creation choices (manual creation)
+    let (mut character, _) = app.build_selected_character().ok()?;
 
     // Name can be empty for preview, use placeholder
-    let name = if app.character_builder.name.is_empty() {
-        "Unnamed Character".to_string()
-    } else {
-        app.character_builder.name.clone()
-    };
-    // ================================================
-
-    // Otherwise, build from builder (manual creation)
-    let mut character = crate::character::build_character(
-        &app.game_data,
-        name,
-        character_type,
-        descriptor_or_species,
-        focus,
-        app.character_builder.bonus_might,
-        app.character_builder.bonus_speed,
-        app.character_builder.bonus_intellect,
-        app.character_builder.selected_abilities.clone(),
-    )
-    .ok()?;
-
-    // Set gender
-    character.gender = app.character_builder.gender.clone();
-
-    // Add cyphers
-    for cypher in app.character_builder.selected_cyphers.iter() {
-        let _ = character.add_cypher(cypher.clone());
+    if character.name.is_empty() {
+        character.name = "Unnamed Character".to_string();
     }
 
-    // Add artifacts
-    for artifact in app.character_builder.selected_artifacts.iter() {
-        character.add_artifact(artifact.clone());
-    }
-
-    // Add oddities
-    for oddity in app.character_builder.selected_oddities.iter() {
-        character.add_oddity(oddity.clone());
-    }
-
-    // ========== APPLY SHOP PURCHASES ==========
-    if !app.character_builder.purchased_items.is_empty() {
-        let _ = apply_shop_purchases_to_preview(&mut character, app);
-    }
-    // ===========================================
-
     Some(character)
 }
 
-/// Apply shop purchases for preview (helper function)
-fn apply_shop_purchases_to_preview(
-    character: &mut crate::CharacterSheet,
-    app: &App,
-) -> anyhow::Result<()> {
-    let total_cost: u32 = app
-        .character_builder
-        .purchased_items
-        .iter()
-        .map(|item| item.cost * item.quantity)
-        .sum();
-
-    // Deduct shins
-    if character.equipment.shins >= total_cost {
-        character.equipment.shins -= total_cost;
-    }
-
-    // Apply purchases
-    for item in &app.character_builder.purchased_items {
-        match item.category.as_str() {
-            "Weapons" => {
-                if let Some(weapon) = app
-                    .game_data
-                    .equipment
-                    .weapons
-                    .iter()
-                    .find(|w| w.name == item.name)
-                {
-                    for _ in 0..item.quantity {
-                        character
-                            .equipment
-                            .add_weapon(format!("{} ({} damage)", weapon.name, weapon.damage));
-                    }
-                }
-            }
-            "Armor" => {
-                if let Some(armor) = app
-                    .game_data
-                    .equipment
-                    .armor
-                    .iter()
-                    .find(|a| a.name == item.name)
-                {
-                    character.equipment.armor = Some(format!(
-                        "{} (+{} Armor, Speed Effort +{})",
-                        armor.name, armor.armor_bonus, armor.speed_effort_cost
-                    ));
-                    character.armor = armor.armor_bonus;
-                }
-            }
-            "Shields" => {
-                if let Some(shield) = app
-                    .game_data
-                    .equipment
-                    .shields
-                    .iter()
-                    .find(|s| s.name == item.name)
-                {
-                    character.equipment.shield = Some(shield.name.clone());
-                }
-            }
-            "Gear" | "Consumables" | "Clothing" => {
-                for _ in 0..item.quantity {
-                    character.equipment.add_gear(item.name.clone());
-                }
-            }
-            _ => {}
-        }
-    }
-
-    Ok(())
-}
-
 // ==========================================
 // HEADER SECTION (TOP 6 LINES)
 // ==========================================
@@ -286,14 +171,23 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
         Span::raw("  Might:     "),
         Span::styled(
             format!("{:2}", character.pools.current.might),
-            get_pool_color(character.pools.current.might, character.pools.maximum.might)
-                .add_modifier(Modifier::BOLD),
+            pool_style(
+                character.pools.current.might,
+                character.pools.maximum.might,
+                &app.theme,
+            )
+            .add_modifier(Modifier::BOLD),
         ),
         Span::styled(" / ", Style::default().fg(Color::DarkGray)),
         Span::styled(
             format!("{:2}", character.pools.maximum.might),
             Style::default().fg(Color::Red),
         ),
+        pool_tag_span(
+            character.pools.current.might,
+            character.pools.maximum.might,
+            app,
+        ),
     ]));
 
     // Speed
@@ -301,14 +195,23 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
         Span::raw("  Speed:     "),
         Span::styled(
             format!("{:2}", character.pools.current.speed),
-            get_pool_color(character.pools.current.speed, character.pools.maximum.speed)
-                .add_modifier(Modifier::BOLD),
+            pool_style(
+                character.pools.current.speed,
+                character.pools.maximum.speed,
+                &app.theme,
+            )
+            .add_modifier(Modifier::BOLD),
         ),
         Span::styled(" / ", Style::default().fg(Color::DarkGray)),
         Span::styled(
             format!("{:2}", character.pools.maximum.speed),
             Style::default().fg(Color::Green),
         ),
+        pool_tag_span(
+            character.pools.current.speed,
+            character.pools.maximum.speed,
+            app,
+        ),
     ]));
 
     // Intellect
@@ -316,9 +219,10 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
         Span::raw("  Intellect: "),
         Span::styled(
             format!("{:2}", character.pools.current.intellect),
-            get_pool_color(
+            pool_style(
                 character.pools.current.intellect,
                 character.pools.maximum.intellect,
+                &app.theme,
             )
             .add_modifier(Modifier::BOLD),
         ),
@@ -327,6 +231,11 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
             format!("{:2}", character.pools.maximum.intellect),
             Style::default().fg(Color::Blue),
         ),
+        pool_tag_span(
+            character.pools.current.intellect,
+            character.pools.maximum.intellect,
+            app,
+        ),
     ]));
 
     lines.push(Line::from(""));
@@ -363,6 +272,47 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
+    lines.push(Line::from(vec![
+        Span::raw("  Recovery: "),
+        Span::styled(
+            character.recovery().to_string(),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(
+            " per action, 10 min, 1 hr, 10 hrs",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+    let track_style = damage_track_style(character.damage_track, &app.theme);
+    let track_text = if app.accessible {
+        format!(
+            "{} {}",
+            damage_track_symbol(character.damage_track),
+            character.damage_track
+        )
+    } else {
+        character.damage_track.to_string()
+    };
+    lines.push(Line::from(vec![
+        Span::raw("  Status: "),
+        Span::styled(track_text, track_style.add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!(" - {}", character.damage_track.description()),
+            Style::default().fg(Color::Gray),
+        ),
+    ]));
+    if let Some(description) = &character.physical_description {
+        lines.push(Line::from(vec![
+            Span::raw("  Appearance: "),
+            Span::styled(description.to_string(), Style::default().fg(Color::White)),
+        ]));
+    }
+    if !character.tags.is_empty() {
+        lines.push(Line::from(vec![
+            Span::raw("  Tags: "),
+            Span::styled(character.tags.join(", "), Style::default().fg(Color::Cyan)),
+        ]));
+    }
 
     // ═══ SKILLS ═══
     lines.push(Line::from(""));
@@ -414,10 +364,21 @@ fn render_left_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShee
             Style::default().fg(Color::Gray),
         )));
         for ability in &character.type_abilities {
+            let heading = if ability.cost.is_empty() {
+                format!("  • {}", ability.name)
+            } else {
+                format!("  • {} ({})", ability.name, ability.cost)
+            };
             lines.push(Line::from(Span::styled(
-                format!("  • {}", ability),
+                heading,
                 Style::default().fg(Color::Yellow),
             )));
+            if !ability.description.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", ability.description),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
         }
     }
 
@@ -528,7 +489,7 @@ fn render_right_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShe
             "Gear:",
             Style::default().fg(Color::Gray),
         )));
-        for gear_item in &character.equipment.gear {
+        for gear_item in character.gear_display() {
             lines.push(Line::from(Span::styled(
                 format!("  • {}", gear_item),
                 Style::default().fg(Color::White),
@@ -729,11 +690,38 @@ fn render_right_panel(f: &mut Frame, area: Rect, character: &crate::CharacterShe
     f.render_widget(right_panel, area);
 }
 
+/// Text tag after a pool in accessible mode; empty otherwise
+fn pool_tag_span(current: i32, maximum: i32, app: &App) -> Span<'static> {
+    if !app.accessible {
+        return Span::raw("");
+    }
+    Span::styled(
+        format!(" {}", pool_tag(current, maximum)),
+        pool_style(current, maximum, &app.theme),
+    )
+}
+
 // ==========================================
 // FOOTER SECTION (ACTIONS)
 // ==========================================
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    if let Some(input) = &app.tag_input {
+        let prompt = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Tag: ", Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{}_", input), Style::default().fg(Color::White)),
+                Span::raw("  |  "),
+                Span::styled("[Enter] Add", Style::default().fg(Color::Green)),
+                Span::raw("  |  "),
+                Span::styled("[Esc] Cancel", Style::default().fg(Color::Gray)),
+            ]),
+        ];
+        f.render_widget(Paragraph::new(prompt).alignment(Alignment::Center), area);
+        return;
+    }
+
     let save_text = if app.last_saved_file.is_some() {
         "[S] Save Another"
     } else {
@@ -748,6 +736,21 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     };
     // =========================================================
 
+    // Manually built characters can switch between the type's equipment options
+    let equipment_option = if app.generated_character.is_none() && app.equipment_options().len() > 1
+    {
+        "  |  [W] Weapon Choice"
+    } else {
+        ""
+    };
+
+    // Generated and loaded characters can redraw their random cyphers and oddities
+    let reroll_option = if app.generated_character.is_some() {
+        "  |  [R] Reroll Numenera"
+    } else {
+        ""
+    };
+
     let actions = vec![
         Line::from(""),
         Line::from(vec![
@@ -760,6 +763,10 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
             Span::raw("  |  "),
             Span::styled("[N] New Character", Style::default().fg(Color::Cyan)),
             Span::styled(edit_option, Style::default().fg(Color::Yellow)),  // ← ADD THIS
+            Span::styled(equipment_option, Style::default().fg(Color::Yellow)),
+            Span::styled(reroll_option, Style::default().fg(Color::Yellow)),
+            Span::raw("  |  "),
+            Span::styled("[T] Tag", Style::default().fg(Color::Yellow)),
             Span::raw("  |  "),
             Span::styled("[Tab] Switch Panel", Style::default().fg(Color::Yellow)),
             Span::raw("  |  "),
@@ -794,24 +801,3 @@ fn render_error(f: &mut Frame, area: Rect) {
     let error = Paragraph::new(error_text).alignment(Alignment::Center);
     f.render_widget(error, area);
 }
-
-// ==========================================
-// HELPER FUNCTIONS
-// ==========================================
-
-/// Get color for pool based on current/max ratio
-fn get_pool_color(current: i32, maximum: i32) -> Style {
-    let ratio = if maximum > 0 {
-        current as f32 / maximum as f32
-    } else {
-        1.0
-    };
-
-    if ratio > 0.7 {
-        Style::default().fg(Color::Green)
-    } else if ratio > 0.3 {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::Red)
-    }
-}
\ No newline at end of file
@@ -9,7 +9,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Step 8: Select Cyphers");
@@ -20,6 +23,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(4), // Instructions
             Constraint::Min(0),    // List
             Constraint::Length(3), // Selection summary
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -29,7 +33,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             .types
             .iter()
             .find(|t| t.name.eq_ignore_ascii_case(type_name))
-            .map(|t| t.starting_tier.cypher_limit as usize)
+            .map(|t| crate::character::cypher_limit_at_tier(t, app.start_tier) as usize)
             .unwrap_or(2)
     } else {
         2
@@ -72,11 +76,8 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         }
 
         let is_selected = i == selected_state;
-        let is_chosen = app
-            .character_builder
-            .selected_cyphers
-            .iter()
-            .any(|c| c.name == cypher.name);
+        let chosen = app.chosen_cypher(i);
+        let is_chosen = chosen.is_some();
 
         let prefix = if is_chosen { "[✓] " } else { "[ ] " };
         let style = if is_selected {
@@ -89,13 +90,27 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Style::default().fg(Color::White)
         };
 
-        lines.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 if is_selected { "> " } else { "  " },
                 Style::default().fg(Color::Yellow),
             ),
             Span::styled(format!("{}{}", prefix, cypher.name), style),
-        ]));
+        ];
+
+        // Briefly show the level that was just rolled
+        if let Some(instance) = chosen.map(|pos| &app.character_builder.selected_cyphers[pos]) {
+            if app.roll_flash() == Some(instance.instance_id) {
+                spans.push(Span::styled(
+                    format!("  🎲 Level {}", instance.level),
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+        }
+
+        lines.push(Line::from(spans));
         lines.push(Line::from(Span::styled(
             format!("    {} | {}", cypher.level_formula, cypher.cypher_type),
             Style::default().fg(Color::Gray),
@@ -145,4 +160,5 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(instructions_widget, chunks[0]);
     f.render_widget(list, chunks[1]);
     f.render_widget(summary, chunks[2]);
-}
\ No newline at end of file
+    render_nav_footer(f, chunks[3], &app.current_screen);
+}
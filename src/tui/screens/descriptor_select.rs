@@ -9,9 +9,10 @@ use ratatui::{
     Frame,
 };
 
+use crate::data::FavoriteKind;
 use crate::tui::{
     app::App,
-    ui::{centered_block, highlighted_item},
+    ui::{centered_block, favorite_label, highlighted_item, render_nav_footer},
 };
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
@@ -22,11 +23,12 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         .constraints([
             Constraint::Length(2), // Instructions
             Constraint::Min(0),    // Scrollable list
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
     // Instructions
-    let instructions = Paragraph::new("Choose a descriptor (adjective) or species:")
+    let instructions = Paragraph::new("Choose a descriptor (adjective), or a species in its place:")
         .style(Style::default().fg(Color::Gray))
         .alignment(Alignment::Center);
 
@@ -79,7 +81,8 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     }
 
     // Render descriptors in visible range
-    for (i, descriptor) in app.game_data.descriptors.iter().enumerate() {
+    let order = app.descriptor_order();
+    for (i, descriptor) in order.iter().map(|&d| &app.game_data.descriptors[d]).enumerate() {
         if i < scroll_offset || i >= scroll_end {
             continue;
         }
@@ -88,7 +91,13 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         }
 
         let is_selected = i == selected;
-        lines.push(highlighted_item(&descriptor.name, is_selected));
+        let is_favorite = app
+            .favorites
+            .contains(FavoriteKind::Descriptor, &descriptor.name);
+        lines.push(highlighted_item(
+            favorite_label(&descriptor.name, is_favorite),
+            is_selected,
+        ));
         
         // Tagline
         lines.push(Line::from(Span::styled(
@@ -156,18 +165,24 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             lines.push(Line::from(""));
         }
 
+        // Header rows are display-only; list_state indexes descriptors then species
         lines.push(Line::from(Span::styled(
-            "── Species (replaces descriptor) ──",
+            "── Species (replace descriptor) ──",
             Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
         )));
         lines.push(Line::from(""));
     }
 
     // Render species in visible range
-    for (i, species) in app.game_data.species.iter().enumerate() {
+    let order = app.species_order();
+    for (i, species) in order.iter().map(|&s| &app.game_data.species[s]).enumerate() {
         let idx = descriptor_count + i;
+        let is_favorite = app
+            .favorites
+            .contains(FavoriteKind::Descriptor, &species.name);
+        let label = favorite_label(&format!("[Species] {}", species.name), is_favorite);
 
         if idx < scroll_offset || idx >= scroll_end {
             continue;
@@ -183,7 +198,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!("[Species] {}", species.name),
+                    label,
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -192,10 +207,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         } else {
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled(
-                    format!("[Species] {}", species.name),
-                    Style::default().fg(Color::White),
-                ),
+                Span::styled(label, Style::default().fg(Color::LightMagenta)),
             ])
         };
 
@@ -261,6 +273,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
     f.render_widget(instructions, chunks[0]);
     f.render_widget(list, chunks[1]);
+    render_nav_footer(f, chunks[2], &app.current_screen);
 }
 
 fn format_stat_modifiers(might: i32, speed: i32, intellect: i32) -> String {
@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use crate::tui::app::App;
-use crate::tui::ui::centered_block;
+use crate::tui::ui::{centered_block, damage_track_style};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Edit Current Stat Pools");
@@ -62,7 +62,7 @@ fn render_stats(f: &mut Frame, area: Rect, app: &App) {
     let mut lines = vec![
         Line::from(""),
         Line::from(Span::styled(
-            "Use ↑↓ to select stat, ←→ or +/- to adjust",
+            "Use ↑↓ to select stat, ← to take damage, → to recover",
             Style::default().fg(Color::Gray),
         )),
         Line::from(""),
@@ -125,6 +125,23 @@ fn render_stats(f: &mut Frame, area: Rect, app: &App) {
         ),
     ]));
 
+    // Damage track updates live as pools change
+    if let Some(character) = character {
+        let track_style = damage_track_style(character.damage_track, &app.theme);
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Damage Track: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                character.damage_track.to_string(),
+                track_style.add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::from(Span::styled(
+            character.damage_track.description().to_string(),
+            track_style,
+        )));
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "Note: Maximum pools cannot be changed",
@@ -145,6 +162,12 @@ fn render_footer(f: &mut Frame, area: Rect) {
             Span::styled("[Enter] ", Style::default().fg(Color::Green)),
             Span::styled("Save Changes", Style::default().fg(Color::White)),
             Span::raw("  |  "),
+            Span::styled("[R] ", Style::default().fg(Color::Cyan)),
+            Span::styled("Full Rest", Style::default().fg(Color::White)),
+            Span::raw("  |  "),
+            Span::styled("[V] ", Style::default().fg(Color::Cyan)),
+            Span::styled("Recovery Roll", Style::default().fg(Color::White)),
+            Span::raw("  |  "),
             Span::styled("[ESC] ", Style::default().fg(Color::Red)),
             Span::styled("Cancel", Style::default().fg(Color::White)),
         ]),
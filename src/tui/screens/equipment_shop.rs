@@ -9,7 +9,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::app::{App, ShopCategory};
+use crate::tui::app::{App, ShopCategory, ShopFocus};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let main_block = Block::default()
@@ -61,7 +61,11 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
             Span::raw("  |  "),
             Span::styled("Remaining: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{} shins", remaining),
+                format!(
+                    "{} shins{}",
+                    remaining,
+                    affordability_tag(app, spent > available)
+                ),
                 if spent > available {
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
                 } else {
@@ -77,6 +81,15 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(header, area);
 }
 
+/// Text cue for the red/green remaining total in accessible mode
+fn affordability_tag(app: &App, over_budget: bool) -> &'static str {
+    match (app.accessible, over_budget) {
+        (false, _) => "",
+        (true, false) => " [OK]",
+        (true, true) => " [OVER]",
+    }
+}
+
 fn render_body(f: &mut Frame, area: Rect, app: &App) {
     let body_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -112,6 +125,8 @@ fn render_left_panel(f: &mut Frame, area: Rect, app: &App) {
             ShopCategory::Gear => app.game_data.equipment.gear.len(),
             ShopCategory::Consumables => app.game_data.equipment.consumables.len(),
             ShopCategory::Clothing => app.game_data.equipment.clothing.len(),
+            ShopCategory::Cyphers => app.game_data.cyphers.len(),
+            ShopCategory::Oddities => app.game_data.oddities.len(),
         };
 
         let style = if is_selected {
@@ -155,7 +170,8 @@ fn render_left_panel(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::ITALIC),
         )));
     } else {
-        for item in &app.shop_cart {
+        let cart_focused = app.shop_focus == ShopFocus::Cart;
+        for (i, item) in app.shop_cart.iter().enumerate() {
             let item_cost = item.cost * item.quantity;
             let qty_text = if item.quantity > 1 {
                 format!(" x{}", item.quantity)
@@ -163,9 +179,20 @@ fn render_left_panel(f: &mut Frame, area: Rect, app: &App) {
                 String::new()
             };
 
+            let is_selected = cart_focused && i == app.shop_cart_selected;
+            let (marker, style) = if is_selected {
+                (
+                    "> ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("• ", Style::default().fg(Color::Green))
+            };
             cart_lines.push(Line::from(Span::styled(
-                format!("• {}{} - {} shins", item.name, qty_text, item_cost),
-                Style::default().fg(Color::Green),
+                format!("{}{}{} - {} shins", marker, item.name, qty_text, item_cost),
+                style,
             )));
         }
 
@@ -181,11 +208,16 @@ fn render_left_panel(f: &mut Frame, area: Rect, app: &App) {
         ]));
     }
 
+    let cart_border = if app.shop_focus == ShopFocus::Cart {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
     let cart_widget = Paragraph::new(cart_lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Green))
+                .border_style(Style::default().fg(cart_border))
                 .title(Span::styled(
                     format!(" Cart ({}) ", app.shop_cart.len()),
                     Style::default().fg(Color::Green),
@@ -201,7 +233,14 @@ fn render_right_panel(f: &mut Frame, area: Rect, app: &App) {
     let mut all_lines = vec![Line::from("")];
 
     all_lines.push(Line::from(Span::styled(
-        format!("═══ {} ═══", app.shop_category.name().to_uppercase()),
+        match (&app.shop_category, &app.shop_range_filter) {
+            (ShopCategory::Weapons, Some(range)) => format!(
+                "═══ {} ({} range) ═══",
+                app.shop_category.name().to_uppercase(),
+                range
+            ),
+            _ => format!("═══ {} ═══", app.shop_category.name().to_uppercase()),
+        },
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD),
@@ -297,9 +336,21 @@ fn render_right_panel(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(items_widget, area);
 }
 
-fn render_footer(f: &mut Frame, area: Rect, _app: &App) {
+fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    let checkout_label = if app.shop_cart.is_empty() {
+        "[Enter] Done, no purchases"
+    } else {
+        "[Enter] Checkout"
+    };
+    let remove_label = match app.shop_focus {
+        ShopFocus::Items => "[R] Remove Last",
+        ShopFocus::Cart => "[R] Remove One",
+    };
     let footer_text = vec![
-        Line::from(""),
+        Line::from(Span::styled(
+            "Starting gear is kept whether you buy nothing or skip",
+            Style::default().fg(Color::DarkGray),
+        )),
         Line::from(vec![
             Span::styled("[←→/h/l] Category", Style::default().fg(Color::Gray)),
             Span::raw("  |  "),
@@ -307,12 +358,14 @@ fn render_footer(f: &mut Frame, area: Rect, _app: &App) {
             Span::raw("  |  "),
             Span::styled("[Space] Add", Style::default().fg(Color::Green)),
             Span::raw("  |  "),
-            Span::styled("[R] Remove Last", Style::default().fg(Color::Yellow)),
+            Span::styled("[Tab] Cart", Style::default().fg(Color::Gray)),
+            Span::raw("  |  "),
+            Span::styled(remove_label, Style::default().fg(Color::Yellow)),
             Span::raw("  |  "),
             Span::styled("[C] Clear", Style::default().fg(Color::Red)),
             Span::raw("  |  "),
             Span::styled(
-                "[Enter] Checkout",
+                checkout_label,
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
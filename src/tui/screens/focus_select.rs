@@ -9,9 +9,10 @@ use ratatui::{
     Frame,
 };
 
+use crate::data::FavoriteKind;
 use crate::tui::{
     app::App,
-    ui::{centered_block, highlighted_item},
+    ui::{centered_block, favorite_label, highlighted_item, render_nav_footer},
 };
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
@@ -20,26 +21,15 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Instructions
+            Constraint::Length(4), // Instructions
             Constraint::Min(0),    // Scrollable list
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
-    // Get suitable foci for selected type
+    // Get suitable foci for selected type, narrowed by the theme filter
     let character_type = app.character_builder.character_type.as_ref();
-    let suitable_foci: Vec<&crate::data::models::Focus> = if let Some(char_type) = character_type {
-        app.game_data
-            .foci
-            .iter()
-            .filter(|f| {
-                f.suitable_types
-                    .iter()
-                    .any(|t| t.eq_ignore_ascii_case(char_type))
-            })
-            .collect()
-    } else {
-        app.game_data.foci.iter().collect()
-    };
+    let suitable_foci = app.visible_foci();
 
     // Instructions
     let type_name = match character_type {
@@ -59,6 +49,13 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             ),
             Style::default().fg(Color::DarkGray),
         )),
+        Line::from(Span::styled(
+            match &app.focus_theme_filter {
+                Some(theme) => format!("Theme: {} (T: next theme)", theme),
+                None => "Theme: all (T: filter by theme)".to_string(),
+            },
+            Style::default().fg(Color::DarkGray),
+        )),
     ];
 
     let instruction_text = Paragraph::new(instructions).alignment(Alignment::Center);
@@ -88,7 +85,11 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         let is_selected = i == selected;
         
         // Focus name
-        lines.push(highlighted_item(&focus.name, is_selected));
+        let is_favorite = app.favorites.contains(FavoriteKind::Focus, &focus.name);
+        lines.push(highlighted_item(
+            favorite_label(&focus.name, is_favorite),
+            is_selected,
+        ));
         
         // Theme
         lines.push(Line::from(Span::styled(
@@ -191,6 +192,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
     f.render_widget(instruction_text, chunks[0]);
     f.render_widget(list, chunks[1]);
+    render_nav_footer(f, chunks[2], &app.current_screen);
 }
 
 fn format_stat_modifiers(might: i32, speed: i32, intellect: i32) -> String {
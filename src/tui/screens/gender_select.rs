@@ -9,9 +9,12 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
-pub fn render(f: &mut Frame, area: Rect, _app: &App) {
+pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Select Gender");
 
     let chunks = Layout::default()
@@ -19,6 +22,7 @@ pub fn render(f: &mut Frame, area: Rect, _app: &App) {
         .constraints([
             Constraint::Length(3), // Instructions
             Constraint::Min(0),    // Options
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -75,4 +79,5 @@ pub fn render(f: &mut Frame, area: Rect, _app: &App) {
     f.render_widget(block, area);
     f.render_widget(instructions, chunks[0]);
     f.render_widget(options_text, chunks[1]);
+    render_nav_footer(f, chunks[2], &app.current_screen);
 }
@@ -9,7 +9,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Step 1: Character Name");
@@ -20,6 +23,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(3), // Instructions
             Constraint::Length(3), // Input box
             Constraint::Min(0),    // Spacer
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -53,7 +57,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         }
 
         // Cursor at end
-        if cursor_pos >= name.len() {
+        if cursor_pos >= name.chars().count() {
             spans.push(Span::styled(
                 "█",
                 Style::default()
@@ -76,4 +80,5 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
     f.render_widget(instructions, chunks[0]);
     f.render_widget(input, chunks[1]);
+    render_nav_footer(f, chunks[3], &app.current_screen);
 }
@@ -9,10 +9,13 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{app::App, ui::centered_block};
+use crate::tui::{
+    app::App,
+    ui::{centered_block, render_nav_footer},
+};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
-    let block = centered_block("Step 10: Select Oddities (Optional)");
+    let block = centered_block("Step 10: Select Oddities");
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -20,16 +23,17 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(4), // Instructions
             Constraint::Min(0),    // List
             Constraint::Length(3), // Selection summary
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
-    let max_oddities = 1;
+    let max_oddities = crate::character::STARTING_ODDITIES;
 
     // Instructions
     let instructions = vec![
         Line::from(Span::styled(
             format!(
-                "Select up to {} oddities (Space to toggle, R for random, C to clear)",
+                "Select {} oddities (Space to toggle, R for random, C to clear)",
                 max_oddities
             ),
             Style::default().fg(Color::Gray),
@@ -131,4 +135,5 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(instructions_widget, chunks[0]);
     f.render_widget(list, chunks[1]);
     f.render_widget(summary, chunks[2]);
-}
\ No newline at end of file
+    render_nav_footer(f, chunks[3], &app.current_screen);
+}
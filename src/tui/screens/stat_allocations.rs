@@ -9,7 +9,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::{tui::{app::App, ui::centered_block}};
+use crate::{tui::{app::App, ui::{centered_block, render_nav_footer}}};
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
     let block = centered_block("Step 6: Allocate Bonus Points");
@@ -26,6 +26,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(5),  // Intellect
             Constraint::Length(3),  // Legend
             Constraint::Min(0),     // Spacer
+            Constraint::Length(1),  // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -90,6 +91,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         final_might,
         Color::Red,
         selected_stat == 0,
+        app.pool_hint(0),
     );
     
     render_stat_breakdown(
@@ -103,6 +105,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         final_speed,
         Color::Green,
         selected_stat == 1,
+        app.pool_hint(1),
     );
     
     render_stat_breakdown(
@@ -116,6 +119,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         final_intellect,
         Color::Blue,
         selected_stat == 2,
+        app.pool_hint(2),
     );
 
     // Legend
@@ -136,6 +140,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(instructions, chunks[0]);
     f.render_widget(remaining_text, chunks[2]);
     f.render_widget(legend, chunks[7]);
+    render_nav_footer(f, chunks[9], &app.current_screen);
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -150,6 +155,7 @@ fn render_stat_breakdown(
     final_value: i32,
     color: Color,
     is_selected: bool,
+    hint: Option<String>,
 ) {
     // Split area into label and breakdown
     let chunks = Layout::default()
@@ -198,10 +204,13 @@ fn render_stat_breakdown(
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(Span::styled(
-            if is_selected { "← → to adjust" } else { "" },
-            Style::default().fg(Color::DarkGray),
-        )),
+        match hint {
+            Some(hint) => Line::from(Span::styled(hint, Style::default().fg(Color::Yellow))),
+            None => Line::from(Span::styled(
+                if is_selected { "← → to adjust" } else { "" },
+                Style::default().fg(Color::DarkGray),
+            )),
+        },
     ];
 
     let border_style = if is_selected {
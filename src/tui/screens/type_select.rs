@@ -9,9 +9,10 @@ use ratatui::{
     Frame,
 };
 
+use crate::data::FavoriteKind;
 use crate::tui::{
     app::App,
-    ui::{centered_block, highlighted_item},
+    ui::{centered_block, favorite_label, highlighted_item, render_nav_footer},
 };
 
 pub fn render(f: &mut Frame, area: Rect, app: &App) {
@@ -22,6 +23,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         .constraints([
             Constraint::Length(2), // Instructions
             Constraint::Min(0),    // List
+            Constraint::Length(1), // Navigation keys
         ])
         .split(block.inner(area));
 
@@ -43,7 +45,8 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         0
     };
 
-    for (i, char_type) in app.game_data.types.iter().enumerate() {
+    let order = app.type_order();
+    for (i, char_type) in order.iter().map(|&t| &app.game_data.types[t]).enumerate() {
         if i < scroll_offset {
             continue;
         }
@@ -54,7 +57,11 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         let is_selected = i == selected;
 
         // Type name
-        lines.push(highlighted_item(&char_type.name, is_selected));
+        let is_favorite = app.favorites.contains(FavoriteKind::Type, &char_type.name);
+        lines.push(highlighted_item(
+            favorite_label(&char_type.name, is_favorite),
+            is_selected,
+        ));
         
         // Tagline
         lines.push(Line::from(Span::styled(
@@ -142,6 +149,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
         
         // Starting Equipment
         if !char_type.equipment.weapons.is_empty() 
+            || !char_type.equipment.options.is_empty()
             || char_type.equipment.armor.is_some() 
             || char_type.equipment.explorer_pack 
             || char_type.equipment.shins > 0 
@@ -159,6 +167,17 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
                 )));
             }
             
+            // Mutually exclusive options
+            if !char_type.equipment.options.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "      Choose one: {}",
+                        char_type.equipment.option_labels().join(" | ")
+                    ),
+                    Style::default().fg(Color::LightGreen),
+                )));
+            }
+
             // Armor
             if let Some(armor) = &char_type.equipment.armor {
                 lines.push(Line::from(Span::styled(
@@ -223,4 +242,5 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
     f.render_widget(instructions, chunks[0]);
     f.render_widget(list, chunks[1]);
+    render_nav_footer(f, chunks[2], &app.current_screen);
 }
\ No newline at end of file
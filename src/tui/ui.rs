@@ -9,8 +9,11 @@ use ratatui::{
     Frame,
 };
 
+use std::borrow::Cow;
+
 use super::app::{App, Screen};
 use super::screens;
+use crate::character::stats::{DamageTrack, PoolLevel};
 
 /// Main render function - routes to appropriate screen
 pub fn render(f: &mut Frame, app: &App) {
@@ -32,10 +35,10 @@ pub fn render(f: &mut Frame, app: &App) {
         Screen::CharacterLoader => screens::character_loader::render(f, chunks[1], app),
         Screen::EditMenu => screens::edit_menu::render(f, chunks[1], app),
         Screen::EditName => screens::name_input::render(f, chunks[1], app),
-        Screen::EditGender => screens::gender_select::render(f, chunks[1], app), 
+        Screen::EditGender => screens::gender_select::render(f, chunks[1], app),
         Screen::EditStats => screens::edit_stats::render(f, chunks[1], app),
         Screen::EditCyphers => screens::cypher_select::render(f, chunks[1], app),
-        Screen::EditOddity => screens::oddity_select::render(f, chunks[1], app), 
+        Screen::EditOddity => screens::oddity_select::render(f, chunks[1], app),
         Screen::NameInput => screens::name_input::render(f, chunks[1], app),
         Screen::GenderSelect => screens::gender_select::render(f, chunks[1], app),
         Screen::TypeSelect => screens::type_select::render(f, chunks[1], app),
@@ -80,25 +83,32 @@ fn render_help_bar(f: &mut Frame, area: Rect, app: &App) {
         Screen::EditMenu => "1-5: Select | S: Save | ESC: Cancel",
         Screen::EditName => "Type name | Enter: Save | ESC: Cancel",
         Screen::EditGender => "1: Male | 2: Female | 3: Other | ESC: Cancel",
-        Screen::EditStats => "↑↓: Select | ←→/+/-: Adjust | Enter: Save | ESC: Cancel",
+        Screen::EditStats => "↑↓: Select | ←→/+/-: Adjust | R: Full Rest | Enter: Save | ESC: Cancel",
         Screen::EditOddity => "↑↓: Navigate | Space: Select | R: Random | Enter: Save | ESC: Cancel",
         Screen::EditCyphers => "↑↓: Navigate | Space: Toggle | R: Random | Enter: Save | ESC: Cancel",
         Screen::NameInput => "Type name | Enter: Continue | ESC: Back",
         Screen::GenderSelect => "1: Male | 2: Female | 3: Other | ESC: Back",
-        Screen::TypeSelect => "↑↓/j/k: Navigate | Enter: Select | ESC: Back",
-        Screen::DescriptorSelect => "↑↓/j/k: Navigate | Enter: Select | ESC: Back",
-        Screen::FocusSelect => "↑↓/j/k: Navigate | Enter: Select | ESC: Back",
+        Screen::TypeSelect => "↑↓/j/k: Navigate | F: Favorite | Enter: Select | ESC: Back",
+        Screen::DescriptorSelect => "↑↓/j/k: Navigate | F: Favorite | Enter: Select | ESC: Back",
+        Screen::FocusSelect => "↑↓/j/k: Navigate | T: Theme | F: Favorite | Enter: Select | ESC: Back",
         Screen::StatAllocation => "+/-: Adjust | Tab: Next stat | Enter: Continue | ESC: Back",
         Screen::AbilitySelect => "↑↓: Navigate | Space: Toggle | Enter: Continue | ESC: Back",
         Screen::CypherSelect => "↑↓: Navigate | Space: Toggle | R: Random | C: Clear | Enter: Next | ESC: Back",
         Screen::ArtifactSelect => "↑↓: Navigate | Space: Toggle | R: Random | C: Clear | Enter: Next | ESC: Back",
         Screen::OdditySelect => "↑↓: Navigate | Space: Toggle | R: Random | C: Clear | Enter: Finish | ESC: Back",
-        Screen::EquipmentShop => "←→: Category | ↑↓: Item | Space: Add | R: Remove | C: Clear | Enter: Buy | ESC: Skip",  // NEW
-        Screen::CharacterPreview => "↑↓/j/k: Scroll | Tab: Switch Panel | PgUp/PgDn: Fast Scroll | S: Save | N: New | Q: Quit",
+        Screen::EquipmentShop => "←→: Category | ↑↓: Item | Tab: Cart | Space: Add | R: Remove | C: Clear | F: Range | Enter: Buy | ESC: Skip",  // NEW
+        Screen::CharacterPreview if app.previous_screen.is_some() => "↑↓/j/k: Scroll | Tab: Switch Panel | PgUp/PgDn: Fast Scroll | F2/ESC: Back | Q: Quit",
+        Screen::CharacterPreview => "↑↓/j/k: Scroll | Tab: Switch Panel | PgUp/PgDn: Fast Scroll | W: Weapon Choice | R: Reroll Numenera | S: Save | N: New | Q: Quit",
+    };
+
+    // Errors take over the help bar until the next key press
+    let (help_text, help_color) = match &app.status_message {
+        Some(message) => (message.as_str(), Color::Red),
+        None => (help_text, Color::Gray),
     };
 
     let help = Paragraph::new(help_text)
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(help_color))
         .alignment(Alignment::Center)
         .block(
             Block::default()
@@ -123,7 +133,7 @@ pub fn centered_block(title: &str) -> Block<'_> {
 }
 
 /// Helper to create a highlighted list item
-pub fn highlighted_item<'a>(text: &'a str, is_selected: bool) -> Line<'a> {
+pub fn highlighted_item<'a>(text: impl Into<Cow<'a, str>>, is_selected: bool) -> Line<'a> {
     if is_selected {
         Line::from(vec![
             Span::styled(
@@ -147,6 +157,107 @@ pub fn highlighted_item<'a>(text: &'a str, is_selected: bool) -> Line<'a> {
     }
 }
 
+/// List label for a selectable entry, starred when it is a favorite
+pub fn favorite_label(name: &str, is_favorite: bool) -> String {
+    if is_favorite {
+        format!("★ {}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Colours shared across screens
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub pool_high: Color,
+    pub pool_medium: Color,
+    pub pool_low: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            pool_high: Color::Green,
+            pool_medium: Color::Yellow,
+            pool_low: Color::Red,
+        }
+    }
+}
+
+/// Style for a pool's current value based on how full it is
+pub fn pool_style(current: i32, maximum: i32, theme: &Theme) -> Style {
+    let color = match PoolLevel::from_values(current, maximum) {
+        PoolLevel::High => theme.pool_high,
+        PoolLevel::Medium => theme.pool_medium,
+        PoolLevel::Low => theme.pool_low,
+    };
+    Style::default().fg(color)
+}
+
+/// Style for a damage track state, sharing the pool colours
+pub fn damage_track_style(track: DamageTrack, theme: &Theme) -> Style {
+    let color = match track {
+        DamageTrack::Hale => theme.pool_high,
+        DamageTrack::Impaired => theme.pool_medium,
+        DamageTrack::Debilitated => theme.pool_low,
+        DamageTrack::Dead => Color::DarkGray,
+    };
+    Style::default().fg(color)
+}
+
+/// Text tag for a pool's fill level, for readers who can't rely on colour
+pub fn pool_tag(current: i32, maximum: i32) -> &'static str {
+    match PoolLevel::from_values(current, maximum) {
+        PoolLevel::High => "[OK]",
+        PoolLevel::Medium => "[LOW]",
+        PoolLevel::Low => "[CRIT]",
+    }
+}
+
+/// Distinctly shaped symbol for each damage track state
+pub fn damage_track_symbol(track: DamageTrack) -> &'static str {
+    match track {
+        DamageTrack::Hale => "●",
+        DamageTrack::Impaired => "◐",
+        DamageTrack::Debilitated => "○",
+        DamageTrack::Dead => "✕",
+    }
+}
+
+/// Standard navigation keys every creation and edit screen advertises
+pub fn nav_keys(screen: &Screen) -> &'static str {
+    match screen {
+        Screen::NameInput => "Enter: Continue | Esc: Back",
+        Screen::EditName => "Enter: Save | Esc: Cancel",
+        Screen::GenderSelect => "1-3: Choose | Esc: Back",
+        Screen::EditGender => "1-3: Choose | Esc: Cancel",
+        Screen::StatAllocation => "Tab: Next stat | Enter: Continue | Esc: Back",
+        Screen::TypeSelect | Screen::DescriptorSelect | Screen::FocusSelect => {
+            "↑↓/j/k: Navigate | Enter: Select | Esc: Back"
+        }
+        Screen::AbilitySelect
+        | Screen::CypherSelect
+        | Screen::ArtifactSelect
+        | Screen::OdditySelect => "↑↓/j/k: Navigate | Space: Toggle | Enter: Continue | Esc: Back",
+        Screen::EditCyphers | Screen::EditOddity => {
+            "↑↓/j/k: Navigate | Space: Toggle | Enter: Save | Esc: Cancel"
+        }
+        _ => "Esc: Back",
+    }
+}
+
+/// Render the standard navigation keys on a screen's bottom line
+///
+/// Screen-specific keys stay in the help bar; this line only covers moving
+/// between steps, so it reads the same everywhere.
+pub fn render_nav_footer(f: &mut Frame, area: Rect, screen: &Screen) {
+    let footer = Paragraph::new(nav_keys(screen))
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    f.render_widget(footer, area);
+}
+
 /// Helper to create a dimmed description line
 pub fn description_line(text: &str) -> Line<'static> {
     Line::from(Span::styled(
@@ -154,3 +265,49 @@ pub fn description_line(text: &str) -> Line<'static> {
         Style::default().fg(Color::Gray),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_style_thresholds() {
+        let theme = Theme::default();
+        let color = |current| pool_style(current, 100, &theme).fg;
+
+        assert_eq!(color(71), Some(Color::Green));
+        assert_eq!(color(70), Some(Color::Yellow));
+        assert_eq!(color(31), Some(Color::Yellow));
+        assert_eq!(color(30), Some(Color::Red));
+        assert_eq!(pool_style(0, 0, &theme).fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_pool_tag_matches_style_levels() {
+        assert_eq!(pool_tag(71, 100), "[OK]");
+        assert_eq!(pool_tag(70, 100), "[LOW]");
+        assert_eq!(pool_tag(30, 100), "[CRIT]");
+        assert_eq!(pool_tag(0, 0), "[OK]");
+    }
+
+    #[test]
+    fn test_nav_keys_cover_creation_screens() {
+        let screens = [
+            Screen::NameInput,
+            Screen::GenderSelect,
+            Screen::TypeSelect,
+            Screen::DescriptorSelect,
+            Screen::FocusSelect,
+            Screen::StatAllocation,
+            Screen::AbilitySelect,
+            Screen::CypherSelect,
+            Screen::ArtifactSelect,
+            Screen::OdditySelect,
+        ];
+        for screen in &screens {
+            assert!(nav_keys(screen).ends_with("Esc: Back"), "{:?}", screen);
+        }
+        assert!(nav_keys(&Screen::TypeSelect).contains("Enter: Select"));
+        assert!(nav_keys(&Screen::EditCyphers).ends_with("Esc: Cancel"));
+    }
+}
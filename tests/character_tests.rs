@@ -148,3 +148,18 @@ fn test_can_spend_pool() {
     // Cost 12, Edge 1 = effective cost 11 (exceeds pool)
     assert!(!sheet.can_spend("might", 12));
 }
+
+#[test]
+fn test_required_sources_discovery_type_destiny_focus() {
+    let data = numenera_chargen::data::load_all_data().unwrap();
+    let mut sheet = CharacterSheet::new("Test".to_string());
+    sheet.character_type = "Glaive".to_string();
+    sheet.descriptor = Some("Charming".to_string());
+    sheet.focus = "Absorbs Energy".to_string();
+
+    assert_eq!(sheet.required_sources(&data), vec!["Discovery", "Destiny"]);
+
+    sheet.sources = sheet.required_sources(&data);
+    let markdown = numenera_chargen::output::markdown::format_character_sheet(&sheet);
+    assert!(markdown.contains("**Requires:** Discovery, Destiny"));
+}
@@ -1,3 +1,4 @@
+use numenera_chargen::data::{load_all_data_from_dir, load_all_data_with_progress};
 use numenera_chargen::prelude::*;
 
 #[cfg(test)]
@@ -16,6 +17,109 @@ mod tests {
         assert!(!data.species.is_empty(), "No species loaded");
     }
 
+    #[test]
+    fn test_load_all_data_reports_progress() {
+        let mut files = Vec::new();
+        let data = load_all_data_with_progress(|file| files.push(file.to_string())).unwrap();
+
+        assert_eq!(files.len(), 9, "Each data file should be reported once");
+        assert_eq!(files[0], "types.toml");
+        assert!(files.contains(&"species.toml".to_string()));
+        assert_eq!(data.types.len(), load_all_data().unwrap().types.len());
+    }
+
+    #[test]
+    fn test_load_all_data_from_dir() {
+        let data = load_all_data_from_dir(std::path::Path::new("data"), |_| {}).unwrap();
+        assert_eq!(data.counts(), load_all_data().unwrap().counts());
+
+        let missing = load_all_data_from_dir(std::path::Path::new("no-such-dir"), |_| {});
+        assert!(missing.is_err());
+    }
+
+    #[cfg(feature = "embed-data")]
+    #[test]
+    fn test_embedded_data_matches_files() {
+        let embedded = numenera_chargen::data::load_all_data_embedded().unwrap();
+        assert_eq!(embedded.counts(), load_all_data().unwrap().counts());
+
+        let presets = numenera_chargen::data::embedded_file("presets.toml").unwrap();
+        assert_eq!(
+            numenera_chargen::generator::parse_presets(presets).unwrap(),
+            numenera_chargen::generator::load_presets(std::path::Path::new("data/presets.toml"))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_focus_themes_dedup_case_insensitively() {
+        let focus = |name: &str, theme: &str| numenera_chargen::data::Focus {
+            name: name.to_string(),
+            theme: theme.to_string(),
+            ..Default::default()
+        };
+        let mut data = GameData::new();
+        data.foci = vec![
+            focus("Bears a Halo of Fire", "Fire"),
+            focus("Leads", "Leadership"),
+            focus("Shepherds the Flame", " fire "),
+            focus("Commands", "LEADERSHIP"),
+            focus("Exists Partially Out of Phase", ""),
+        ];
+
+        assert_eq!(data.focus_themes(), vec!["Fire", "Leadership"]);
+        assert!(data.foci[2].matches_theme("FIRE"));
+        assert!(!data.foci[1].matches_theme("fire"));
+    }
+
+    #[test]
+    fn test_all_names_covers_every_category() {
+        let data = load_all_data().unwrap();
+        let names = data.all_names();
+
+        for category in numenera_chargen::data::NAME_CATEGORIES {
+            assert!(
+                names.iter().any(|(c, _)| c == category),
+                "no names for category {}",
+                category
+            );
+        }
+        assert!(names.contains(&("type", "Glaive")));
+        assert_eq!(data.names_in("focus").len(), data.foci.len());
+        assert!(data.names_in("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        let data = load_all_data().unwrap();
+        let again = load_all_data().unwrap();
+        assert_eq!(data.content_hash(), again.content_hash());
+        assert_eq!(data.content_hash().len(), 12);
+
+        let mut changed = data.clone();
+        changed.types[0].tagline.push('!');
+        assert_ne!(data.content_hash(), changed.content_hash());
+    }
+
+    #[test]
+    fn test_library_stats() {
+        let data = load_all_data().unwrap();
+        let stats = numenera_chargen::data::library_stats(&data);
+
+        assert_eq!(stats.types.len(), data.types.len());
+        assert!(stats.types.iter().all(|t| t.foci > 0));
+        assert_eq!(
+            stats.cyphers_by_category.values().sum::<usize>(),
+            data.cyphers.len()
+        );
+        assert_eq!(
+            stats.artifact_level_formulas.values().sum::<usize>(),
+            data.artifacts.len()
+        );
+        let range = stats.oddity_values.unwrap();
+        assert!(range.min <= range.max);
+    }
+
     #[test]
     fn test_expected_counts() {
         let data = load_all_data().unwrap();
@@ -159,6 +159,32 @@ fn test_effort_valid() {
     assert_eq!(character.effort.max_effort, 1);
 }
 
+#[test]
+fn test_random_character_gets_intrusion_note() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    for char_type in data
+        .types
+        .iter()
+        .filter(|t| !t.intrusions.examples.is_empty())
+    {
+        let character = generate_random_with_type(&data, &char_type.name).unwrap();
+        let note = character.intrusion_note.expect("intrusion note");
+        assert!(
+            char_type
+                .intrusions
+                .examples
+                .iter()
+                .any(|example| note.starts_with(example.as_str())),
+            "{} note '{}' is not one of its examples",
+            char_type.name,
+            note
+        );
+        assert!(note.ends_with(&format!("({})", char_type.intrusions.cost)));
+    }
+}
+
 #[test]
 fn test_abilities_selected() {
     numenera_chargen::init().unwrap();
@@ -172,6 +198,13 @@ fn test_abilities_selected() {
             !character.type_abilities.is_empty(),
             "Character should have at least one type ability"
         );
+        for ability in &character.type_abilities {
+            assert!(
+                !ability.description.is_empty(),
+                "Type ability '{}' should keep its description",
+                ability.name
+            );
+        }
 
         // Should have focus ability
         assert!(
@@ -194,3 +227,488 @@ fn test_character_pools_structure() {
     // No pool should be zero for a newly created character
     assert!(!character.pools.has_zero_pool());
 }
+
+#[test]
+fn test_seeded_batch_is_reproducible() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let first = numenera_chargen::generator::generate_batch_seeded(&data, 5, 42);
+    let second = numenera_chargen::generator::generate_batch_seeded(&data, 5, 42);
+
+    for (a, b) in first.iter().zip(second.iter()) {
+        let a = serde_json::to_string(a.as_ref().unwrap()).unwrap();
+        let b = serde_json::to_string(b.as_ref().unwrap()).unwrap();
+        assert_eq!(a, b);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_batch_matches_sequential() {
+    use numenera_chargen::generator::{generate_batch_parallel_seeded, generate_batch_seeded};
+    use std::time::Instant;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let count = 500;
+
+    let start = Instant::now();
+    let sequential = generate_batch_seeded(&data, count, 7);
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = generate_batch_parallel_seeded(&data, count, 7);
+    let parallel_time = start.elapsed();
+
+    println!(
+        "sequential: {:?}, parallel: {:?} ({} characters)",
+        sequential_time, parallel_time, count
+    );
+
+    assert_eq!(sequential.len(), parallel.len());
+    for (a, b) in sequential.iter().zip(parallel.iter()) {
+        let a = serde_json::to_string(a.as_ref().unwrap()).unwrap();
+        let b = serde_json::to_string(b.as_ref().unwrap()).unwrap();
+        assert_eq!(a, b, "Parallel results must match sequential index order");
+    }
+}
+
+#[test]
+fn test_generate_random_with_focus() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let focus = &data.foci[0];
+
+    let character =
+        numenera_chargen::generator::generate_random_with_focus(&data, &focus.name).unwrap();
+    assert_eq!(character.focus, focus.name);
+
+    if !focus.suitable_types.is_empty() {
+        assert!(focus
+            .suitable_types
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&character.character_type)));
+    }
+}
+
+#[test]
+fn test_generate_random_with_type_and_focus() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let focus = &data.foci[0];
+
+    let character = numenera_chargen::generator::generate_random_with_type_and_focus(
+        &data,
+        "Glaive",
+        &focus.name,
+    )
+    .unwrap();
+    assert_eq!(character.character_type, "Glaive");
+    assert_eq!(character.focus, focus.name);
+}
+
+#[test]
+fn test_generate_random_with_unknown_focus() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let result = numenera_chargen::generator::generate_random_with_focus(&data, "Juggles Teacups");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_lattimor_starts_in_default_mode() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let mut character = numenera_chargen::generator::generate_random_with_type_and_descriptor(
+        &data, "Glaive", "Lattimor",
+    )
+    .unwrap();
+
+    assert_eq!(character.species_modes.len(), 3);
+    assert_eq!(character.species_mode.as_deref(), Some("Fugue State"));
+    assert_eq!(character.favored_pool(), None);
+
+    character.set_species_mode("Neem State").unwrap();
+    assert_eq!(character.favored_pool(), Some("Intellect"));
+}
+
+#[test]
+fn test_cypher_variety_respects_limit() {
+    use numenera_chargen::generator::{generate_with_options, GenerationOptions};
+    use rand::SeedableRng;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let options = GenerationOptions {
+        cypher_variety: true,
+        ..Default::default()
+    };
+
+    for seed in 0..20 {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let character = generate_with_options(&data, &mut rng, &options).unwrap();
+
+        assert!(character.cyphers.len() <= character.cypher_limit as usize);
+        let mut names: Vec<&str> = character.cyphers.iter().map(|c| c.name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), character.cyphers.len());
+    }
+}
+
+#[test]
+fn test_start_tier_requires_tier_data() {
+    use numenera_chargen::character::validate_start_tier;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    for character_type in &data.types {
+        assert!(validate_start_tier(character_type, 1).is_ok());
+        assert!(validate_start_tier(character_type, 7).is_err());
+
+        // Shipped data only lists tier 1 abilities
+        let has_tier_2 = character_type.tier_abilities.iter().any(|ta| ta.tier == 2);
+        assert_eq!(validate_start_tier(character_type, 2).is_ok(), has_tier_2);
+    }
+}
+
+#[test]
+fn test_generation_seed_recorded_and_reproducible() {
+    use numenera_chargen::generator::{generate_from_seed, GenerationOptions};
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let options = GenerationOptions::default();
+
+    let first = generate_from_seed(&data, &options, 1234).unwrap();
+    let second = generate_from_seed(&data, &options, 1234).unwrap();
+
+    assert_eq!(first.generation_seed, Some(1234));
+    assert_eq!(
+        serde_json::to_string(&first).unwrap(),
+        serde_json::to_string(&second).unwrap()
+    );
+    assert!(generate_random(&data).unwrap().generation_seed.is_some());
+}
+
+#[test]
+fn test_built_character_has_no_seed() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let character = build_character(
+        &data,
+        "Test".to_string(),
+        "Glaive",
+        "Strong",
+        "Bears a Halo of Fire",
+        2,
+        2,
+        2,
+        vec![],
+    )
+    .unwrap();
+    assert!(character.generation_seed.is_none());
+}
+
+#[test]
+fn test_parse_sentence() {
+    use numenera_chargen::generator::parse_sentence;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let (character_type, descriptor, focus) =
+        parse_sentence(&data, "I am a charming glaive who masters weaponry").unwrap();
+    assert_eq!(character_type, "Glaive");
+    assert_eq!(descriptor, "Charming");
+    assert_eq!(focus, "Masters Weaponry");
+
+    let (_, species, _) =
+        parse_sentence(&data, "Varjellen Nano that Bears a Halo of Fire").unwrap();
+    assert_eq!(species, "Varjellen");
+
+    let err = parse_sentence(&data, "Charming Glaive who Juggles Teacups")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("focus 'juggles teacups'"));
+}
+
+#[test]
+fn test_generate_random_with_type_weights() {
+    use numenera_chargen::generator::generate_random_with_type_weights;
+    use std::collections::HashMap;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    // Zero out every type but Arkus
+    let weights: HashMap<String, f64> = data
+        .types
+        .iter()
+        .map(|t| (t.name.clone(), if t.name == "Arkus" { 1.0 } else { 0.0 }))
+        .collect();
+
+    for _ in 0..5 {
+        let character = generate_random_with_type_weights(&data, &weights).unwrap();
+        assert_eq!(character.character_type, "Arkus");
+    }
+}
+
+#[test]
+fn test_random_cyphers_bounded_by_limit() {
+    use numenera_chargen::generator::{generate_from_seed, GenerationOptions};
+
+    numenera_chargen::init().unwrap();
+    let mut data = load_all_data().unwrap();
+    for char_type in &mut data.types {
+        char_type.starting_tier.cypher_limit = 2;
+    }
+    let options = GenerationOptions {
+        character_type: Some("Nano".to_string()),
+        ..Default::default()
+    };
+
+    for seed in 0..30 {
+        let character = generate_from_seed(&data, &options, seed).unwrap();
+        assert_eq!(character.cypher_limit, 2);
+        assert!(character.cyphers.len() <= 2);
+        assert!(!character.cyphers.is_empty());
+    }
+}
+
+#[test]
+fn test_preset_constrains_generation() {
+    use numenera_chargen::generator::{find_preset, GenerationOptions};
+    use numenera_chargen::generator::{generate_from_seed, load_presets, validate_preset};
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let presets = load_presets(std::path::Path::new("data/presets.toml")).unwrap();
+    for preset in &presets {
+        validate_preset(preset, &data).unwrap();
+    }
+
+    let tank = find_preset(&presets, "Frontline Tank").unwrap();
+    let mut options = GenerationOptions::default();
+    tank.apply(&mut options);
+
+    for seed in 0..20 {
+        let character = generate_from_seed(&data, &options, seed).unwrap();
+        assert_eq!(character.character_type, "Glaive");
+        assert!(tank
+            .descriptors
+            .contains(character.descriptor.as_ref().unwrap()));
+        assert!(tank.foci.contains(&character.focus));
+    }
+}
+
+#[test]
+fn test_preset_with_unknown_focus_is_rejected() {
+    use numenera_chargen::generator::{parse_presets, validate_preset};
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let presets = parse_presets(
+        r#"
+        [[preset]]
+        name = "Typo"
+        type = "Glaive"
+        foci = ["Masters Wepaonry"]
+        "#,
+    )
+    .unwrap();
+
+    let err = validate_preset(&presets[0], &data).unwrap_err();
+    assert!(err.to_string().contains("focus 'Masters Wepaonry'"));
+}
+
+#[test]
+fn test_interactive_steps_read_piped_input() {
+    use numenera_chargen::generator::interactive::{select_type, select_type_abilities};
+    use numenera_chargen::ChargenError;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let mut input = std::io::Cursor::new("2\n");
+    assert_eq!(select_type(&data, &mut input).unwrap(), data.types[1].name);
+
+    let glaive = data.types.iter().find(|t| t.name == "Glaive").unwrap();
+    let tier_1 = glaive.tier_abilities.iter().find(|t| t.tier == 1).unwrap();
+    let picks: String = (1..=tier_1.count).map(|i| format!("{}\n", i)).collect();
+    let abilities = select_type_abilities(&data, "Glaive", &mut picks.as_bytes()).unwrap();
+    assert_eq!(abilities.len(), tier_1.count as usize);
+    assert_eq!(abilities[0], tier_1.abilities[0].name);
+
+    // Running out of input cancels the step
+    let err = select_type(&data, &mut std::io::Cursor::new("")).unwrap_err();
+    assert!(matches!(err.downcast_ref(), Some(ChargenError::Cancelled)));
+}
+
+#[test]
+fn test_type_equipment_option_chosen_once() {
+    use numenera_chargen::generator::interactive::select_equipment_option;
+    use numenera_chargen::generator::{generate_from_seed, GenerationOptions};
+
+    numenera_chargen::init().unwrap();
+    let mut data = load_all_data().unwrap();
+    let glaive = data.types.iter_mut().find(|t| t.name == "Glaive").unwrap();
+    glaive.equipment.options = vec![
+        vec!["Heirloom Spear".to_string()],
+        vec!["Hook Blade".to_string(), "Hook Blade".to_string()],
+    ];
+    let options = GenerationOptions {
+        character_type: Some("Glaive".to_string()),
+        ..Default::default()
+    };
+
+    let mut seen = [false, false];
+    for seed in 0..30 {
+        let character = generate_from_seed(&data, &options, seed).unwrap();
+        let gear = &character.equipment.gear;
+        let spears = gear.iter().filter(|g| *g == "Heirloom Spear").count();
+        let hooks = gear.iter().filter(|g| *g == "Hook Blade").count();
+        assert!(
+            (spears, hooks) == (1, 0) || (spears, hooks) == (0, 2),
+            "seed {} got {:?}",
+            seed,
+            gear
+        );
+        seen[usize::from(hooks > 0)] = true;
+    }
+    assert_eq!(seen, [true, true]);
+
+    let glaive = data.types.iter().find(|t| t.name == "Glaive").unwrap();
+    let mut input = std::io::Cursor::new("2\n");
+    assert_eq!(select_equipment_option(glaive, &mut input).unwrap(), 1);
+}
+
+#[test]
+fn test_examples_cover_every_type_reproducibly() {
+    use numenera_chargen::generator::generate_examples;
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let first = generate_examples(&data).unwrap();
+    let second = generate_examples(&data).unwrap();
+
+    let types: Vec<&str> = first.iter().map(|c| c.character_type.as_str()).collect();
+    let expected: Vec<&str> = data.types.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(types, expected);
+    assert_eq!(
+        serde_json::to_value(&first).unwrap(),
+        serde_json::to_value(&second).unwrap()
+    );
+}
+
+#[test]
+fn test_generate_random_valid_respects_attempt_budget() {
+    use numenera_chargen::generator::{generate_random_valid, sheet_warnings, GenerationOptions};
+
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    // Ordinary characters pass on some attempt, leaving no warnings
+    let (sheet, warnings) =
+        generate_random_valid(&data, &GenerationOptions::default(), 50).unwrap();
+    assert!(warnings.is_empty());
+    assert!(sheet.generation_seed.is_some());
+
+    // A tier mismatch can never be rerolled away, so the budget ends the search
+    let options = GenerationOptions {
+        starting_advances: [
+            "Increase Capabilities: Might",
+            "Move Toward Perfection: Speed",
+            "Extra Effort",
+            "Skill Training: Climbing",
+        ]
+        .iter()
+        .map(|a| a.parse().unwrap())
+        .collect(),
+        ..Default::default()
+    };
+    let (sheet, warnings) = generate_random_valid(&data, &options, 3).unwrap();
+    assert!(!warnings.is_empty());
+    assert_eq!(warnings, sheet_warnings(&sheet));
+}
+
+#[test]
+fn test_every_type_focus_combination_builds() {
+    use numenera_chargen::generator::audit_combinations;
+
+    numenera_chargen::init().unwrap();
+    let mut data = load_all_data().unwrap();
+
+    let report = audit_combinations(&data);
+    assert!(report.checked >= data.types.len());
+    assert!(
+        report.is_clean(),
+        "Failing combinations: {:?}",
+        report.failures
+    );
+
+    // A type with no tier 1 abilities is reported once, without trying its foci
+    data.types[0].tier_abilities.clear();
+    let report = audit_combinations(&data);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].character_type, data.types[0].name);
+    assert!(report.failures[0].focus.is_empty());
+}
+
+#[test]
+fn test_with_description_sets_plausible_age() {
+    use numenera_chargen::generator::{age_range, generate_from_seed, GenerationOptions};
+
+    let game_data = load_all_data().expect("Failed to load game data");
+    let options = GenerationOptions {
+        descriptor: Some("Varjellen".to_string()),
+        with_description: true,
+        ..Default::default()
+    };
+
+    let character = generate_from_seed(&game_data, &options, 11).unwrap();
+    let description = character.physical_description.expect("description rolled");
+    let (youngest, oldest) = age_range(250);
+    assert!((youngest..=oldest).contains(&description.age));
+
+    let plain = generate_from_seed(&game_data, &GenerationOptions::default(), 11).unwrap();
+    assert!(plain.physical_description.is_none());
+}
+
+#[test]
+fn test_balanced_allocation_spreads_six_points_evenly() {
+    use numenera_chargen::data::{find_descriptor, find_type};
+    use numenera_chargen::generator::{generate_from_seed, Allocation, GenerationOptions};
+
+    let game_data = load_all_data().expect("Failed to load game data");
+    let options = GenerationOptions {
+        character_type: Some("Glaive".to_string()),
+        descriptor: Some("Strong".to_string()),
+        allocation: Some(Allocation::Balanced),
+        ..Default::default()
+    };
+    let glaive = find_type(&game_data.types, "Glaive").unwrap();
+    let strong = find_descriptor(&game_data.descriptors, "Strong").unwrap();
+    assert_eq!(glaive.stat_pools.bonus_points, 6);
+
+    let character = generate_from_seed(&game_data, &options, 3).unwrap();
+    let pools = &character.pools.maximum;
+    assert_eq!(
+        pools.might,
+        glaive.stat_pools.might as i32 + strong.stat_modifiers.might + 2
+    );
+    assert_eq!(
+        pools.speed,
+        glaive.stat_pools.speed as i32 + strong.stat_modifiers.speed + 2
+    );
+    assert_eq!(
+        pools.intellect,
+        glaive.stat_pools.intellect as i32 + strong.stat_modifiers.intellect + 2
+    );
+}
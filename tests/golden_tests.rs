@@ -0,0 +1,58 @@
+use numenera_chargen::data::data_summary;
+use numenera_chargen::prelude::*;
+use numenera_chargen::validate_all_comprehensive;
+
+#[test]
+fn test_shipped_data_has_no_validation_errors() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let report = validate_all_comprehensive(&data).unwrap();
+    assert!(
+        !report.has_errors(),
+        "Shipped data has validation errors:\n{}",
+        report.errors.join("\n")
+    );
+}
+
+#[test]
+fn test_shipped_data_counts_snapshot() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+
+    let expected = "Loaded Game Data:\n\
+                    - 6 character types\n\
+                    - 45 descriptors\n\
+                    - 55 foci\n\
+                    - 30 weapons\n\
+                    - 13 armor pieces\n\
+                    - 51 cyphers\n\
+                    - 70 artifacts\n\
+                    - 11 oddities\n\
+                    - 13 discoveries\n\
+                    - 3 species options";
+
+    assert_eq!(
+        data_summary(&data),
+        expected,
+        "Content counts changed; update the snapshot if this was intentional"
+    );
+}
+
+#[test]
+fn test_shipped_data_counts_fields() {
+    numenera_chargen::init().unwrap();
+    let data = load_all_data().unwrap();
+    let counts = data.counts();
+
+    assert_eq!(counts.types, 6);
+    assert_eq!(counts.descriptors, 45);
+    assert_eq!(counts.foci, 55);
+    assert_eq!(counts.weapons, 30);
+    assert_eq!(counts.armor, 13);
+    assert_eq!(counts.cyphers, 51);
+    assert_eq!(counts.artifacts, 70);
+    assert_eq!(counts.oddities, 11);
+    assert_eq!(counts.discoveries, 13);
+    assert_eq!(counts.species, 3);
+}